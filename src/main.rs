@@ -4,7 +4,10 @@ mod sessions;
 mod tests;
 
 use crate::install::populate_data_dir;
-use sessions::{assert_session, assert_session_ne, get_active_session, list_sessions};
+use sessions::{
+    assert_session, assert_session_ne, get_active_session, kill_all_sessions, kill_session,
+    list_sessions, query_session, rename_session, send_action_to_session, session_exists,
+};
 use std::convert::TryFrom;
 use std::process;
 use zellij_client::{os_input_output::get_client_os_input, start_client, ClientInfo};
@@ -13,7 +16,9 @@ use zellij_utils::{
     cli::{CliArgs, Command, Sessions},
     consts::{ZELLIJ_TMP_DIR, ZELLIJ_TMP_LOG_DIR},
     input::{config::Config, layout::Layout, options::Options},
+    ipc::ClientType,
     logging::*,
+    resurrection::SessionSnapshot,
     setup::{find_default_config_dir, get_default_data_dir, get_layout_dir, Setup},
     structopt::StructOpt,
 };
@@ -21,8 +26,41 @@ use zellij_utils::{
 pub fn main() {
     let opts = CliArgs::from_args();
 
-    if let Some(Command::Sessions(Sessions::ListSessions)) = opts.command {
-        list_sessions();
+    if let Some(Command::Sessions(Sessions::ListSessions { json })) = opts.command {
+        list_sessions(json);
+    }
+
+    if let Some(Command::Sessions(Sessions::KillSession { ref name })) = opts.command {
+        kill_session(name);
+    }
+
+    if let Some(Command::Sessions(Sessions::KillAllSessions { yes })) = opts.command {
+        kill_all_sessions(yes);
+    }
+
+    if let Some(Command::Sessions(Sessions::RenameSession { ref new_name })) = opts.command {
+        let session_name = opts
+            .session
+            .clone()
+            .or_else(|| std::env::var("ZELLIJ_SESSION_NAME").ok())
+            .unwrap_or_else(get_active_session);
+        rename_session(&session_name, new_name);
+    }
+
+    if let Some(Command::Action(cli_action)) = opts.command.clone() {
+        let session_name = opts
+            .session
+            .clone()
+            .or_else(|| std::env::var("ZELLIJ_SESSION_NAME").ok())
+            .unwrap_or_else(get_active_session);
+        send_action_to_session(&session_name, cli_action);
+    }
+
+    if let Some(Command::Sessions(Sessions::Query { session_name, json })) = opts.command.clone() {
+        let session_name = session_name
+            .or_else(|| std::env::var("ZELLIJ_SESSION_NAME").ok())
+            .unwrap_or_else(get_active_session);
+        query_session(&session_name, json);
     }
 
     let config = match Config::try_from(&opts) {
@@ -35,6 +73,11 @@ pub fn main() {
     let config_options = Options::from_cli(&config.options, opts.command.clone());
 
     if let Some(Command::Setup(ref setup)) = opts.command {
+        // the plugins of the layout to check might be the built-in ones
+        #[cfg(not(disable_automatic_asset_installation))]
+        if setup.check_layout.is_some() {
+            populate_data_dir(&opts.data_dir.clone().unwrap_or_else(get_default_data_dir));
+        }
         Setup::from_cli(setup, &opts, &config_options).expect("Failed to print to stdout");
     }
 
@@ -57,10 +100,51 @@ pub fn main() {
                 process::exit(1);
             }
         };
-        if let Some(Command::Sessions(Sessions::Attach {
+        // a session that is still running is attached to as usual,
+        // one that is not can be resurrected or created instead
+        let (session_to_resurrect, session_to_create) = match opts.command {
+            Some(Command::Sessions(Sessions::Attach {
+                session_name: Some(ref session_name),
+                resurrect,
+                create,
+                ..
+            })) if !session_exists(session_name) => {
+                if resurrect {
+                    (Some(session_name.clone()), None)
+                } else if create || config_options.create_on_attach {
+                    (None, Some(session_name.clone()))
+                } else {
+                    (None, None)
+                }
+            }
+            _ => (None, None),
+        };
+        if let Some(session_name) = session_to_resurrect {
+            // Determine and initialize the data directory
+            let data_dir = opts.data_dir.clone().unwrap_or_else(get_default_data_dir);
+            let snapshot = match SessionSnapshot::load(&data_dir, &session_name) {
+                Ok(snapshot) => snapshot,
+                Err(e) => {
+                    eprintln!("Could not resurrect session {:?}:\n{}", session_name, e);
+                    process::exit(1);
+                }
+            };
+            #[cfg(not(disable_automatic_asset_installation))]
+            populate_data_dir(&data_dir);
+
+            start_client(
+                Box::new(os_input),
+                opts,
+                config,
+                ClientInfo::Resurrect(session_name, snapshot),
+                None,
+            );
+        } else if let Some(Command::Sessions(Sessions::Attach {
             mut session_name,
             force,
-        })) = opts.command.clone()
+            read_only,
+            ..
+        })) = opts.command.clone().filter(|_| session_to_create.is_none())
         {
             if let Some(session) = session_name.as_ref() {
                 assert_session(session);
@@ -68,17 +152,21 @@ pub fn main() {
                 session_name = Some(get_active_session());
             }
 
+            let client_type = if read_only {
+                ClientType::Reader
+            } else {
+                ClientType::Writer
+            };
             start_client(
                 Box::new(os_input),
                 opts,
                 config,
-                ClientInfo::Attach(session_name.unwrap(), force, config_options),
+                ClientInfo::Attach(session_name.unwrap(), force, client_type, config_options),
                 None,
             );
         } else {
-            let session_name = opts
-                .session
-                .clone()
+            let session_name = session_to_create
+                .or_else(|| opts.session.clone())
                 .unwrap_or_else(|| names::Generator::default().next().unwrap());
             assert_session_ne(&session_name);
 
@@ -94,6 +182,7 @@ pub fn main() {
                 opts.layout.as_ref(),
                 opts.layout_path.as_ref(),
                 layout_dir,
+                &opts.layout_args,
             );
 
             start_client(
@@ -1,27 +1,45 @@
+use std::io::Write;
 use std::os::unix::fs::FileTypeExt;
+use std::time::{SystemTime, UNIX_EPOCH};
 use std::{fs, io, process};
 use zellij_utils::{
+    cli::CliAction,
     consts::ZELLIJ_SOCK_DIR,
+    input::actions::Action,
     interprocess::local_socket::LocalSocketStream,
-    ipc::{ClientToServerMsg, IpcSenderWithContext},
+    ipc::{
+        ClientId, ClientToServerMsg, ExitReason, IpcSenderWithContext, ServerToClientMsg,
+        SessionInfo,
+    },
 };
 
 fn get_sessions() -> Result<Vec<String>, io::ErrorKind> {
+    get_sessions_and_stale_sockets().map(|(sessions, _)| sessions)
+}
+
+/// Returns the names of the live sessions, along with those of the dead sessions
+/// whose sockets were left behind.
+fn get_sessions_and_stale_sockets() -> Result<(Vec<String>, Vec<String>), io::ErrorKind> {
     match fs::read_dir(&*ZELLIJ_SOCK_DIR) {
         Ok(files) => {
             let mut sessions = Vec::new();
+            let mut stale_sockets = Vec::new();
             files.for_each(|file| {
                 let file = file.unwrap();
                 let file_name = file.file_name().into_string().unwrap();
-                if file.file_type().unwrap().is_socket() && assert_socket(&file_name) {
-                    sessions.push(file_name);
+                if file.file_type().unwrap().is_socket() {
+                    if assert_socket(&file_name) {
+                        sessions.push(file_name);
+                    } else {
+                        stale_sockets.push(file_name);
+                    }
                 }
             });
-            Ok(sessions)
+            Ok((sessions, stale_sockets))
         }
         Err(err) => {
             if let io::ErrorKind::NotFound = err.kind() {
-                Ok(Vec::with_capacity(0))
+                Ok((Vec::with_capacity(0), Vec::with_capacity(0)))
             } else {
                 Err(err.kind())
             }
@@ -29,24 +47,27 @@ fn get_sessions() -> Result<Vec<String>, io::ErrorKind> {
     }
 }
 
+/// Whether a server is listening on the socket of session `name`.
 fn assert_socket(name: &str) -> bool {
-    let path = &*ZELLIJ_SOCK_DIR.join(name);
-    match LocalSocketStream::connect(path) {
+    match LocalSocketStream::connect(&*ZELLIJ_SOCK_DIR.join(name)) {
         Ok(stream) => {
             IpcSenderWithContext::new(stream).send(ClientToServerMsg::ClientExited);
             true
         }
-        Err(e) => {
-            if e.kind() == io::ErrorKind::ConnectionRefused {
-                drop(fs::remove_file(path));
-                false
-            } else {
-                true
-            }
-        }
+        Err(e) => e.kind() != io::ErrorKind::ConnectionRefused,
     }
 }
 
+/// Removes the sockets of the dead sessions `names`, returning those that were removed.
+/// Only `list-sessions` cleans them up, so that it can report it, and a new session
+/// replaces the socket of a dead one with the same name anyway.
+fn remove_stale_sockets(names: Vec<String>) -> Vec<String> {
+    names
+        .into_iter()
+        .filter(|name| fs::remove_file(ZELLIJ_SOCK_DIR.join(name)).is_ok())
+        .collect()
+}
+
 fn print_sessions(sessions: Vec<String>) {
     let curr_session = std::env::var("ZELLIJ_SESSION_NAME").unwrap_or_else(|_| "".into());
     sessions.iter().for_each(|session| {
@@ -77,13 +98,82 @@ pub(crate) fn get_active_session() -> String {
     process::exit(1);
 }
 
-pub(crate) fn list_sessions() {
-    let exit_code = match get_sessions() {
-        Ok(sessions) => {
-            if sessions.is_empty() {
-                println!("No active zellij sessions found.");
+fn format_age(created: u64) -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|since_epoch| since_epoch.as_secs())
+        .unwrap_or_default();
+    let age = now.saturating_sub(created);
+    match age {
+        0..=59 => format!("{}s", age),
+        60..=3599 => format!("{}m", age / 60),
+        3600..=86399 => format!("{}h {}m", age / 3600, age % 3600 / 60),
+        _ => format!("{}d {}h", age / 86400, age % 86400 / 3600),
+    }
+}
+
+fn print_session_infos(session_infos: &[SessionInfo]) {
+    let curr_session = std::env::var("ZELLIJ_SESSION_NAME").unwrap_or_else(|_| "".into());
+    for session_info in session_infos {
+        let mut line = session_info.name.clone();
+        // sessions that did not answer are only listed by name
+        if session_info.created != 0 {
+            line.push_str(&format!(
+                " [Created {} ago] ({} clients, {} tabs",
+                format_age(session_info.created),
+                session_info.connected_clients.len(),
+                session_info.tabs.len()
+            ));
+            if let Some(layout) = session_info.layout.as_ref() {
+                line.push_str(&format!(", layout: {}", layout));
+            }
+            if let Some(cwd) = session_info.cwd.as_ref() {
+                line.push_str(&format!(", cwd: {}", cwd.display()));
+            }
+            line.push(')');
+        }
+        if curr_session == session_info.name {
+            line.push_str(" (current)");
+        }
+        println!("{}", line);
+    }
+}
+
+/// Lists the active sessions along with their metadata, as JSON if `json` is set,
+/// and reports the sockets of dead sessions that were removed on the way.
+pub(crate) fn list_sessions(json: bool) {
+    let exit_code = match get_sessions_and_stale_sockets() {
+        Ok((sessions, stale_sockets)) => {
+            let stale_sockets = remove_stale_sockets(stale_sockets);
+            let session_infos: Vec<SessionInfo> = sessions
+                .into_iter()
+                .map(|name| {
+                    LocalSocketStream::connect(&*ZELLIJ_SOCK_DIR.join(&name))
+                        .ok()
+                        .and_then(|stream| {
+                            request_session_info(IpcSenderWithContext::new(stream)).ok()
+                        })
+                        .unwrap_or(SessionInfo {
+                            name,
+                            ..Default::default()
+                        })
+                })
+                .collect();
+            if json {
+                let output = serde_json::json!({
+                    "sessions": session_infos,
+                    "removed_stale_sockets": stale_sockets,
+                });
+                println!("{}", serde_json::to_string_pretty(&output).unwrap());
             } else {
-                print_sessions(sessions);
+                if session_infos.is_empty() {
+                    println!("No active zellij sessions found.");
+                } else {
+                    print_session_infos(&session_infos);
+                }
+                for stale_socket in stale_sockets {
+                    eprintln!("Removed the socket of dead session {:?}.", stale_socket);
+                }
             }
             0
         }
@@ -95,6 +185,92 @@ pub(crate) fn list_sessions() {
     process::exit(exit_code);
 }
 
+/// Sends `msg` to the session called `name`, which answers by letting the sender exit.
+fn send_session_command(name: &str, msg: ClientToServerMsg) -> Result<(), String> {
+    let stream = LocalSocketStream::connect(&*ZELLIJ_SOCK_DIR.join(name))
+        .map_err(|e| format!("Could not connect to session {:?}: {}", name, e))?;
+    let mut sender = IpcSenderWithContext::new(stream);
+    let mut receiver = sender.get_receiver::<ServerToClientMsg>();
+    sender.send(msg);
+    let result = match receiver.recv() {
+        (ServerToClientMsg::Exit(ExitReason::Normal), _) => Ok(()),
+        (ServerToClientMsg::Exit(reason), _) => Err(reason.to_string()),
+        (other, _) => Err(format!("Unexpected answer from the server: {:?}", other)),
+    };
+    sender.send(ClientToServerMsg::ClientExited);
+    result
+}
+
+fn send_kill_session(name: &str) -> Result<(), String> {
+    send_session_command(name, ClientToServerMsg::KillSession)
+}
+
+pub(crate) fn kill_session(name: &str) {
+    assert_session(name);
+    match send_kill_session(name) {
+        Ok(()) => process::exit(0),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
+
+pub(crate) fn rename_session(name: &str, new_name: &str) {
+    assert_session(name);
+    assert_session_ne(new_name);
+    match send_session_command(name, ClientToServerMsg::RenameSession(new_name.into())) {
+        Ok(()) => process::exit(0),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
+
+pub(crate) fn kill_all_sessions(yes: bool) {
+    let sessions = match get_sessions() {
+        Ok(sessions) => sessions,
+        Err(e) => {
+            eprintln!("Error occured: {:?}", e);
+            process::exit(1);
+        }
+    };
+    if sessions.is_empty() {
+        println!("No active zellij sessions found.");
+        process::exit(1);
+    }
+    if !yes {
+        print!(
+            "WARNING: this will kill {} session(s): {}. Continue? (y/N) ",
+            sessions.len(),
+            sessions.join(", ")
+        );
+        let _ = io::stdout().flush();
+        let mut answer = String::new();
+        let _ = io::stdin().read_line(&mut answer);
+        if !matches!(answer.trim(), "y" | "Y" | "yes") {
+            println!("Abort.");
+            process::exit(1);
+        }
+    }
+    let mut exit_code = 0;
+    for name in sessions {
+        if let Err(e) = send_kill_session(&name) {
+            eprintln!("{}", e);
+            exit_code = 1;
+        }
+    }
+    process::exit(exit_code);
+}
+
+/// Whether a live session called `name` exists.
+pub(crate) fn session_exists(name: &str) -> bool {
+    get_sessions()
+        .map(|sessions| sessions.iter().any(|s| s == name))
+        .unwrap_or(false)
+}
+
 pub(crate) fn assert_session(name: &str) {
     match get_sessions() {
         Ok(sessions) => {
@@ -120,3 +296,122 @@ pub(crate) fn assert_session_ne(name: &str) {
     };
     process::exit(1);
 }
+
+fn connect_to_session(name: &str) -> IpcSenderWithContext<ClientToServerMsg> {
+    assert_session(name);
+    match LocalSocketStream::connect(&*ZELLIJ_SOCK_DIR.join(name)) {
+        Ok(stream) => IpcSenderWithContext::new(stream),
+        Err(e) => {
+            eprintln!("Error occured: {:?}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Performs `cli_action` in the session called `name`, exiting with an error if
+/// the session could not be reached or an action failed.
+pub(crate) fn send_action_to_session(name: &str, cli_action: CliAction) {
+    let mut sender = connect_to_session(name);
+    let mut receiver = sender.get_receiver::<ServerToClientMsg>();
+    let actions: Vec<Action> = cli_action.into();
+    for action in actions {
+        sender.send(ClientToServerMsg::Action(action));
+        // the server answers once the action was routed
+        match receiver.recv() {
+            (ServerToClientMsg::Exit(reason), _) => {
+                eprintln!("{}", reason);
+                sender.send(ClientToServerMsg::ClientExited);
+                process::exit(1);
+            }
+            (ServerToClientMsg::Layout(layout), _) => print!("{}", layout),
+            _ => {}
+        }
+    }
+    sender.send(ClientToServerMsg::ClientExited);
+    process::exit(0);
+}
+
+fn print_session_info(session_info: &SessionInfo) {
+    let join_ids = |ids: &[ClientId]| {
+        ids.iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
+    };
+    println!(
+        "{} (clients: {})",
+        session_info.name,
+        join_ids(&session_info.connected_clients)
+    );
+    for tab in &session_info.tabs {
+        let mut flags = String::new();
+        if !tab.active_clients.is_empty() {
+            flags.push_str(&format!(" (viewed by {})", join_ids(&tab.active_clients)));
+        }
+        if tab.sync_panes {
+            flags.push_str(" [sync]");
+        }
+        if tab.fullscreen {
+            flags.push_str(" [fullscreen]");
+        }
+        println!("{}: {:?}{}", tab.position + 1, tab.name, flags);
+        for pane in &tab.panes {
+            let kind = if pane.is_plugin { "plugin" } else { "terminal" };
+            let mut description = format!(
+                "{} {}: {}x{} at {},{}",
+                kind, pane.id, pane.cols, pane.rows, pane.x, pane.y
+            );
+            if let Some(name) = pane.name.as_ref() {
+                description.push_str(&format!(" {:?}", name));
+            }
+            if let Some(child_pid) = pane.child_pid {
+                description.push_str(&format!(" pid {}", child_pid));
+            }
+            if let Some(command) = pane.command.as_ref() {
+                description.push_str(&format!(" `{}`", command.join(" ")));
+            }
+            if let Some(cwd) = pane.cwd.as_ref() {
+                description.push_str(&format!(" in {}", cwd.display()));
+            }
+            if !pane.focused_clients.is_empty() {
+                description.push_str(&format!(
+                    " (focused by {})",
+                    join_ids(&pane.focused_clients)
+                ));
+            }
+            println!("    {}", description);
+        }
+    }
+}
+
+fn request_session_info(
+    mut sender: IpcSenderWithContext<ClientToServerMsg>,
+) -> Result<SessionInfo, String> {
+    let mut receiver = sender.get_receiver::<ServerToClientMsg>();
+    sender.send(ClientToServerMsg::QuerySession);
+    let session_info = match receiver.recv() {
+        (ServerToClientMsg::SessionInfo(session_info), _) => Ok(session_info),
+        (ServerToClientMsg::Exit(reason), _) => Err(reason.to_string()),
+        (other, _) => Err(format!("Unexpected answer from the server: {:?}", other)),
+    };
+    sender.send(ClientToServerMsg::ClientExited);
+    session_info
+}
+
+/// Prints the tabs and panes of the session called `name`, as JSON if `json` is set.
+pub(crate) fn query_session(name: &str, json: bool) {
+    match request_session_info(connect_to_session(name)) {
+        Ok(session_info) => {
+            if json {
+                println!("{}", serde_json::to_string_pretty(&session_info).unwrap());
+            } else {
+                print_session_info(&session_info);
+            }
+            process::exit(0);
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
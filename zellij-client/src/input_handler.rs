@@ -2,7 +2,7 @@
 
 use zellij_utils::{
     input::{
-        mouse::{MouseButton, MouseEvent},
+        mouse::{MouseButton, MouseEvent, MouseModifiers},
         options::Options,
     },
     termion, zellij_tile,
@@ -81,7 +81,7 @@ impl InputHandler {
                         }
                         termion::event::Event::Mouse(me) => {
                             let mouse_event = zellij_utils::input::mouse::MouseEvent::from(me);
-                            self.handle_mouse_event(&mouse_event);
+                            self.handle_mouse_event(&mouse_event, MouseModifiers::default());
                         }
                         termion::event::Event::Unsupported(unsupported_key) => {
                             // we have to do this because of a bug in termion
@@ -91,8 +91,15 @@ impl InputHandler {
                                 self.handle_key(&key, raw_bytes);
                             } else if unsupported_key == bracketed_paste_start {
                                 self.pasting = true;
+                                self.handle_paste_marker(raw_bytes);
                             } else if unsupported_key == bracketed_paste_end {
+                                self.handle_paste_marker(raw_bytes);
                                 self.pasting = false;
+                            } else if let Some((mouse_event, modifiers)) =
+                                MouseEvent::from_sgr_report(&unsupported_key)
+                            {
+                                // termion does not support mouse events with modifiers
+                                self.handle_mouse_event(&mouse_event, modifiers);
                             } else {
                                 // this is a hack because termion doesn't recognize certain keys
                                 // in this case we just forward it to the terminal
@@ -111,6 +118,13 @@ impl InputHandler {
             self.dispatch_action(action);
         }
     }
+    /// Forwards the start or end of a paste, for the panes that asked to tell pasted text
+    /// from typed text to re-wrap it
+    fn handle_paste_marker(&mut self, raw_bytes: Vec<u8>) {
+        if self.mode == InputMode::Normal || self.mode == InputMode::Locked {
+            self.dispatch_action(Action::Write(raw_bytes));
+        }
+    }
     fn handle_key(&mut self, key: &Key, raw_bytes: Vec<u8>) {
         let keybinds = &self.config.keybinds;
         if self.pasting {
@@ -130,7 +144,17 @@ impl InputHandler {
             }
         }
     }
-    fn handle_mouse_event(&mut self, mouse_event: &MouseEvent) {
+    /// Mouse events are reported to the programs of the panes that track the mouse,
+    /// except while Alt is held, which lets Zellij select and scroll anyway.
+    fn handle_mouse_event(&mut self, mouse_event: &MouseEvent, modifiers: MouseModifiers) {
+        if !modifiers.alt {
+            let action = Action::MouseEvent(*mouse_event, modifiers);
+            self.dispatch_action(action.clone());
+            if let MouseEvent::Hold(_) = mouse_event {
+                self.os_input.start_action_repeater(action);
+            }
+            return;
+        }
         match *mouse_event {
             MouseEvent::Press(button, point) => match button {
                 MouseButton::WheelUp => {
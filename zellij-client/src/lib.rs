@@ -19,7 +19,8 @@ use zellij_utils::{
     consts::{SESSION_NAME, ZELLIJ_IPC_PIPE},
     errors::{ClientContext, ContextType, ErrorInstruction},
     input::{actions::Action, config::Config, layout::Layout, options::Options},
-    ipc::{ClientAttributes, ClientToServerMsg, ExitReason, ServerToClientMsg},
+    ipc::{ClientAttributes, ClientToServerMsg, ClientType, ExitReason, ServerToClientMsg},
+    resurrection::SessionSnapshot,
 };
 
 /// Instructions related to the client-side application
@@ -37,6 +38,8 @@ impl From<ServerToClientMsg> for ClientInstruction {
             ServerToClientMsg::Exit(e) => ClientInstruction::Exit(e),
             ServerToClientMsg::Render(buffer) => ClientInstruction::Render(buffer),
             ServerToClientMsg::UnblockInputThread => ClientInstruction::UnblockInputThread,
+            // only sent in answer to queries, which attached clients never make
+            ServerToClientMsg::SessionInfo(_) | ServerToClientMsg::Layout(_) => unreachable!(),
         }
     }
 }
@@ -77,8 +80,10 @@ fn spawn_server(socket_path: &Path) -> io::Result<()> {
 
 #[derive(Debug, Clone)]
 pub enum ClientInfo {
-    Attach(String, bool, Options),
+    Attach(String, bool, ClientType, Options),
     New(String),
+    /// Start a new session from the snapshot of a session that is gone
+    Resurrect(String, SessionSnapshot),
 }
 
 pub fn start_client(
@@ -116,14 +121,20 @@ pub fn start_client(
     let client_attributes = ClientAttributes {
         position_and_size: full_screen_ws,
         palette,
+        character_cell_size: os_input.get_character_cell_size_using_fd(0),
     };
 
+    // a resurrected session is started like a new one, from its snapshot instead of a layout
+    let (info, snapshot) = match info {
+        ClientInfo::Resurrect(name, snapshot) => (ClientInfo::New(name), Some(snapshot)),
+        info => (info, None),
+    };
     let first_msg = match info {
-        ClientInfo::Attach(name, force, config_options) => {
+        ClientInfo::Attach(name, force, client_type, config_options) => {
             SESSION_NAME.set(name).unwrap();
             std::env::set_var(&"ZELLIJ_SESSION_NAME", SESSION_NAME.get().unwrap());
 
-            ClientToServerMsg::AttachClient(client_attributes, force, config_options)
+            ClientToServerMsg::AttachClient(client_attributes, force, client_type, config_options)
         }
         ClientInfo::New(name) => {
             SESSION_NAME.set(name).unwrap();
@@ -136,8 +147,10 @@ pub fn start_client(
                 Box::new(opts),
                 Box::new(config_options.clone()),
                 layout,
+                snapshot,
             )
         }
+        ClientInfo::Resurrect(..) => unreachable!(),
     };
 
     os_input.connect_to_server(&*ZELLIJ_IPC_PIPE);
@@ -196,6 +209,7 @@ pub fn start_client(
                         move || {
                             os_api.send_to_server(ClientToServerMsg::TerminalResize(
                                 os_api.get_terminal_size_using_fd(0),
+                                os_api.get_character_cell_size_using_fd(0),
                             ));
                         }
                     }),
@@ -15,7 +15,7 @@ use zellij_tile::data::Palette;
 use zellij_utils::{
     errors::ErrorContext,
     ipc::{ClientToServerMsg, IpcReceiverWithContext, IpcSenderWithContext, ServerToClientMsg},
-    pane_size::PositionAndSize,
+    pane_size::{PositionAndSize, SizeInPixels},
     shared::default_palette,
 };
 
@@ -35,7 +35,7 @@ fn unset_raw_mode(pid: RawFd, orig_termios: termios::Termios) {
     };
 }
 
-pub(crate) fn get_terminal_size_using_fd(fd: RawFd) -> PositionAndSize {
+fn get_winsize_using_fd(fd: RawFd) -> Winsize {
     // TODO: do this with the nix ioctl
     use libc::ioctl;
     use libc::TIOCGWINSZ;
@@ -54,7 +54,11 @@ pub(crate) fn get_terminal_size_using_fd(fd: RawFd) -> PositionAndSize {
     unsafe {
         ioctl(fd, TIOCGWINSZ.into(), &mut winsize)
     };
-    PositionAndSize::from(winsize)
+    winsize
+}
+
+pub(crate) fn get_terminal_size_using_fd(fd: RawFd) -> PositionAndSize {
+    PositionAndSize::from(get_winsize_using_fd(fd))
 }
 
 #[derive(Clone)]
@@ -70,6 +74,9 @@ pub struct ClientOsInputOutput {
 pub trait ClientOsApi: Send + Sync {
     /// Returns the size of the terminal associated to file descriptor `fd`.
     fn get_terminal_size_using_fd(&self, fd: RawFd) -> PositionAndSize;
+    /// Returns the size of the character cells of the terminal associated to file descriptor
+    /// `fd`, if it reports it.
+    fn get_character_cell_size_using_fd(&self, fd: RawFd) -> Option<SizeInPixels>;
     /// Set the terminal associated to file descriptor `fd` to
     /// [raw mode](https://en.wikipedia.org/wiki/Terminal_mode).
     fn set_raw_mode(&mut self, fd: RawFd);
@@ -101,6 +108,9 @@ impl ClientOsApi for ClientOsInputOutput {
     fn get_terminal_size_using_fd(&self, fd: RawFd) -> PositionAndSize {
         get_terminal_size_using_fd(fd)
     }
+    fn get_character_cell_size_using_fd(&self, fd: RawFd) -> Option<SizeInPixels> {
+        SizeInPixels::character_cell_size(get_winsize_using_fd(fd))
+    }
     fn set_raw_mode(&mut self, fd: RawFd) {
         into_raw_mode(fd);
     }
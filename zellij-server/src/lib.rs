@@ -11,9 +11,11 @@ mod wasm_vm;
 
 use zellij_utils::zellij_tile;
 
-use std::path::PathBuf;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex, RwLock};
 use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use wasmer::Store;
 use zellij_tile::data::{Event, Palette, PluginCapabilities};
 
@@ -33,32 +35,77 @@ use zellij_utils::{
         command::{RunCommand, TerminalAction},
         get_mode_info,
         layout::Layout,
-        options::Options,
+        options::{ClipboardAccess, Options, SessionSizePolicy},
     },
-    ipc::{ClientAttributes, ClientToServerMsg, ExitReason, ServerToClientMsg},
-    setup::get_default_data_dir,
+    ipc::{
+        ClientAttributes, ClientToServerMsg, ClientType, ExitReason, ServerToClientMsg, SessionInfo,
+    },
+    pane_size::PositionAndSize,
+    resurrection::SessionSnapshot,
+    setup::{find_default_config_dir, get_default_data_dir, get_layout_dir},
 };
 
+pub use zellij_utils::ipc::ClientId;
+
+/// The client the actions from the command line are performed as while no client is
+/// attached to the session. Connections are never given this id.
+pub(crate) const DETACHED_CLIENT_ID: ClientId = 0;
+
+/// How often the session is serialized for resurrection by default, in seconds
+const DEFAULT_SERIALIZATION_INTERVAL: u64 = 60;
+/// How often the commands that title the panes without a title are looked up
+const FOREGROUND_COMMANDS_UPDATE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Who asked for a [`SessionInfo`], which determines what is done with it.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum SessionInfoRequester {
+    /// A client querying the session, which is sent the answer
+    Client(ClientId),
+    /// The session itself, serializing its layout so that it can be resurrected,
+    /// along with the last `scrollback_lines` lines of each terminal pane
+    Resurrection { scrollback_lines: usize },
+    /// A client dumping the layout of the tab it views, which is copied to its clipboard,
+    /// or sent to `requester` when the action comes from the command line
+    DumpLayout {
+        client_id: ClientId,
+        requester: Option<ClientId>,
+    },
+}
+
 /// Instructions related to server-side application
 #[derive(Debug, Clone)]
 pub(crate) enum ServerInstruction {
-    NewClient(ClientAttributes, Box<CliArgs>, Box<Options>, Option<Layout>),
-    Render(Option<String>),
+    NewClient(
+        ClientAttributes,
+        Box<CliArgs>,
+        Box<Options>,
+        Option<Layout>,
+        Option<SessionSnapshot>,
+        ClientId,
+    ),
+    Render(Option<HashMap<ClientId, String>>),
     UnblockInputThread,
     ClientExit,
+    RemoveClient(ClientId),
     Error(String),
-    DetachSession,
-    AttachClient(ClientAttributes, bool, Options),
+    DetachSession(ClientId),
+    AttachClient(ClientAttributes, bool, ClientType, Options, ClientId),
+    SessionInfo(SessionInfo, SessionInfoRequester),
+    KillSession(ClientId),
+    /// Renames the session, answering the client that asked for it (if any)
+    RenameSession(String, Option<ClientId>),
 }
 
-impl From<ClientToServerMsg> for ServerInstruction {
-    fn from(instruction: ClientToServerMsg) -> Self {
+impl From<(ClientToServerMsg, ClientId)> for ServerInstruction {
+    fn from((instruction, client_id): (ClientToServerMsg, ClientId)) -> Self {
         match instruction {
-            ClientToServerMsg::NewClient(attrs, opts, options, layout) => {
-                ServerInstruction::NewClient(attrs, opts, options, layout)
+            ClientToServerMsg::NewClient(attrs, opts, options, layout, snapshot) => {
+                ServerInstruction::NewClient(attrs, opts, options, layout, snapshot, client_id)
             }
-            ClientToServerMsg::AttachClient(attrs, force, options) => {
-                ServerInstruction::AttachClient(attrs, force, options)
+            ClientToServerMsg::AttachClient(attrs, force, client_type, options) => {
+                // a read-only client cannot make room for itself by detaching the others
+                let force = force && client_type == ClientType::Writer;
+                ServerInstruction::AttachClient(attrs, force, client_type, options, client_id)
             }
             _ => unreachable!(),
         }
@@ -72,9 +119,13 @@ impl From<&ServerInstruction> for ServerContext {
             ServerInstruction::Render(_) => ServerContext::Render,
             ServerInstruction::UnblockInputThread => ServerContext::UnblockInputThread,
             ServerInstruction::ClientExit => ServerContext::ClientExit,
+            ServerInstruction::RemoveClient(_) => ServerContext::RemoveClient,
             ServerInstruction::Error(_) => ServerContext::Error,
-            ServerInstruction::DetachSession => ServerContext::DetachSession,
+            ServerInstruction::DetachSession(_) => ServerContext::DetachSession,
             ServerInstruction::AttachClient(..) => ServerContext::AttachClient,
+            ServerInstruction::SessionInfo(..) => ServerContext::SessionInfo,
+            ServerInstruction::KillSession(_) => ServerContext::KillSession,
+            ServerInstruction::RenameSession(..) => ServerContext::RenameSession,
         }
     }
 }
@@ -90,6 +141,11 @@ pub(crate) struct SessionMetaData {
     pub capabilities: PluginCapabilities,
     pub palette: Palette,
     pub default_shell: Option<TerminalAction>,
+    pub created: SystemTime,
+    pub layout_name: Option<String>,
+    /// Where the layouts opened in new tabs are looked up
+    pub layout_dir: Option<PathBuf>,
+    pub data_dir: PathBuf,
     screen_thread: Option<thread::JoinHandle<()>>,
     pty_thread: Option<thread::JoinHandle<()>>,
     wasm_thread: Option<thread::JoinHandle<()>>,
@@ -106,14 +162,112 @@ impl Drop for SessionMetaData {
     }
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub(crate) enum SessionState {
-    Attached,
-    Detached,
-    Uninitialized,
+/// Tracks the clients attached to a session and the size each of them reported.
+/// A session without any clients is detached and does not need to render.
+/// Read-only clients are shown the session, but do not take part in sizing it.
+/// It also holds the session's clipboard, the text copied last by a selection or a
+/// program, and whether programs can copy to the clipboard of the clients or read it.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SessionState {
+    clients: BTreeMap<ClientId, PositionAndSize>,
+    readers: BTreeSet<ClientId>,
+    size_policy: SessionSizePolicy,
+    // the client whose size should be used with `SessionSizePolicy::Latest`
+    latest_client: Option<ClientId>,
+    clipboard_access: ClipboardAccess,
+    allow_clipboard_read: bool,
+    // base64 encoded, as OSC 52 carries it
+    clipboard: Option<String>,
+}
+
+impl SessionState {
+    pub fn set_size_policy(&mut self, size_policy: SessionSizePolicy) {
+        self.size_policy = size_policy;
+    }
+    pub fn set_clipboard_access(&mut self, clipboard_access: ClipboardAccess) {
+        self.clipboard_access = clipboard_access;
+    }
+    pub fn clipboard_access(&self) -> ClipboardAccess {
+        self.clipboard_access
+    }
+    pub fn set_allow_clipboard_read(&mut self, allow_clipboard_read: bool) {
+        self.allow_clipboard_read = allow_clipboard_read;
+    }
+    pub fn allow_clipboard_read(&self) -> bool {
+        self.allow_clipboard_read
+    }
+    pub fn set_clipboard(&mut self, base64_text: String) {
+        self.clipboard = Some(base64_text);
+    }
+    pub fn clipboard(&self) -> Option<&str> {
+        self.clipboard.as_deref()
+    }
+    pub fn add_client(&mut self, client_id: ClientId, size: PositionAndSize) {
+        self.clients.insert(client_id, size);
+        self.latest_client = Some(client_id);
+    }
+    pub fn add_reader(&mut self, client_id: ClientId, size: PositionAndSize) {
+        self.clients.insert(client_id, size);
+        self.readers.insert(client_id);
+    }
+    pub fn set_client_size(&mut self, client_id: ClientId, size: PositionAndSize) {
+        if let Some(client_size) = self.clients.get_mut(&client_id) {
+            *client_size = size;
+            if !self.readers.contains(&client_id) {
+                self.latest_client = Some(client_id);
+            }
+        }
+    }
+    pub fn remove_client(&mut self, client_id: ClientId) {
+        self.clients.remove(&client_id);
+        self.readers.remove(&client_id);
+        if self.latest_client == Some(client_id) {
+            self.latest_client = self.writer_sizes().map(|(id, _)| *id).last();
+        }
+    }
+    pub fn has_client(&self, client_id: ClientId) -> bool {
+        self.clients.contains_key(&client_id)
+    }
+    pub fn latest_client(&self) -> Option<ClientId> {
+        self.latest_client
+    }
+    pub fn is_attached(&self) -> bool {
+        !self.clients.is_empty()
+    }
+    pub fn client_ids(&self) -> Vec<ClientId> {
+        self.clients.keys().copied().collect()
+    }
+    /// The size the screen should have in order to be displayed on the attached clients,
+    /// according to the session's [`SessionSizePolicy`].
+    pub fn session_size(&self) -> Option<PositionAndSize> {
+        match self.size_policy {
+            SessionSizePolicy::Smallest => self.writer_sizes().map(|(_, size)| size).fold(
+                None,
+                |smallest: Option<PositionAndSize>, size| {
+                    Some(match smallest {
+                        Some(smallest) => PositionAndSize {
+                            rows: smallest.rows.min(size.rows),
+                            cols: smallest.cols.min(size.cols),
+                            ..smallest
+                        },
+                        None => *size,
+                    })
+                },
+            ),
+            SessionSizePolicy::Latest => self
+                .latest_client
+                .and_then(|client_id| self.clients.get(&client_id).copied()),
+        }
+    }
+    // read-only clients are cropped to the session instead of resizing it
+    fn writer_sizes(&self) -> impl Iterator<Item = (&ClientId, &PositionAndSize)> {
+        self.clients
+            .iter()
+            .filter(move |(client_id, _)| !self.readers.contains(client_id))
+    }
 }
 
-pub fn start_server(os_input: Box<dyn ServerOsApi>, socket_path: PathBuf) {
+pub fn start_server(os_input: Box<dyn ServerOsApi>, mut socket_path: PathBuf) {
     daemonize::Daemonize::new()
         .working_directory(std::env::current_dir().unwrap())
         .umask(0o077)
@@ -127,7 +281,7 @@ pub fn start_server(os_input: Box<dyn ServerOsApi>, socket_path: PathBuf) {
     let (to_server, server_receiver): ChannelWithContext<ServerInstruction> = channels::bounded(50);
     let to_server = SenderWithContext::new(to_server);
     let session_data: Arc<RwLock<Option<SessionMetaData>>> = Arc::new(RwLock::new(None));
-    let session_state = Arc::new(RwLock::new(SessionState::Uninitialized));
+    let session_state = Arc::new(RwLock::new(SessionState::default()));
 
     std::panic::set_hook({
         use zellij_utils::errors::handle_panic;
@@ -156,30 +310,38 @@ pub fn start_server(os_input: Box<dyn ServerOsApi>, socket_path: PathBuf) {
                 drop(std::fs::remove_file(&socket_path));
                 let listener = LocalSocketListener::bind(&*socket_path).unwrap();
                 set_permissions(&socket_path).unwrap();
+                let connected_client_ids = Arc::new(Mutex::new(BTreeSet::new()));
                 for stream in listener.incoming() {
                     match stream {
                         Ok(stream) => {
-                            let mut os_input = os_input.clone();
-                            os_input.update_receiver(stream);
+                            let connected_client = match ConnectedClient::new(
+                                &connected_client_ids,
+                                &*os_input,
+                                &session_state.read().unwrap(),
+                            ) {
+                                Some(connected_client) => connected_client,
+                                // every id is taken, the connection is dropped
+                                None => continue,
+                            };
+                            let client_id = connected_client.client_id;
+                            let os_input = os_input.clone();
+                            let receiver = os_input.new_client(client_id, stream);
                             let session_data = session_data.clone();
                             let session_state = session_state.clone();
                             let to_server = to_server.clone();
                             thread_handles.lock().unwrap().push(
                                 thread::Builder::new()
                                     .name("server_router".to_string())
-                                    .spawn({
-                                        let session_data = session_data.clone();
-                                        let os_input = os_input.clone();
-                                        let to_server = to_server.clone();
-
-                                        move || {
-                                            route_thread_main(
-                                                session_data,
-                                                session_state,
-                                                os_input,
-                                                to_server,
-                                            )
-                                        }
+                                    .spawn(move || {
+                                        route_thread_main(
+                                            session_data,
+                                            session_state,
+                                            os_input,
+                                            to_server,
+                                            receiver,
+                                            client_id,
+                                        );
+                                        drop(connected_client);
                                     })
                                     .unwrap(),
                             );
@@ -196,7 +358,23 @@ pub fn start_server(os_input: Box<dyn ServerOsApi>, socket_path: PathBuf) {
         let (instruction, mut err_ctx) = server_receiver.recv().unwrap();
         err_ctx.add_call(ContextType::IPCServer((&instruction).into()));
         match instruction {
-            ServerInstruction::NewClient(client_attributes, opts, config_options, layout) => {
+            ServerInstruction::NewClient(
+                client_attributes,
+                opts,
+                config_options,
+                layout,
+                snapshot,
+                client_id,
+            ) => {
+                {
+                    let mut session_state = session_state.write().unwrap();
+                    session_state
+                        .set_size_policy(config_options.session_size_policy.unwrap_or_default());
+                    session_state
+                        .set_clipboard_access(config_options.clipboard_access.unwrap_or_default());
+                    session_state.set_allow_clipboard_read(config_options.allow_clipboard_read);
+                    session_state.add_client(client_id, client_attributes.position_and_size);
+                }
                 let session = init_session(
                     os_input.clone(),
                     opts,
@@ -207,7 +385,7 @@ pub fn start_server(os_input: Box<dyn ServerOsApi>, socket_path: PathBuf) {
                     layout,
                 );
                 *session_data.write().unwrap() = Some(session);
-                *session_state.write().unwrap() = SessionState::Attached;
+                add_client_to_session(&session_data, client_id);
 
                 let default_shell = config_options.default_shell.map(|shell| {
                     TerminalAction::RunCommand(RunCommand {
@@ -216,69 +394,235 @@ pub fn start_server(os_input: Box<dyn ServerOsApi>, socket_path: PathBuf) {
                     })
                 });
 
-                session_data
-                    .read()
-                    .unwrap()
-                    .as_ref()
-                    .unwrap()
-                    .senders
-                    .send_to_pty(PtyInstruction::NewTab(default_shell.clone()))
-                    .unwrap();
+                let rlock = session_data.read().unwrap();
+                let senders = &rlock.as_ref().unwrap().senders;
+                match snapshot {
+                    Some(snapshot) if !snapshot.tabs.is_empty() => {
+                        for tab_snapshot in snapshot.tabs {
+                            senders
+                                .send_to_pty(PtyInstruction::ResurrectTab(
+                                    Box::new(tab_snapshot),
+                                    client_id,
+                                ))
+                                .unwrap();
+                        }
+                    }
+                    _ => senders
+                        .send_to_pty(PtyInstruction::NewTab(default_shell.clone(), client_id))
+                        .unwrap(),
+                }
             }
-            ServerInstruction::AttachClient(attrs, _, options) => {
-                *session_state.write().unwrap() = SessionState::Attached;
+            ServerInstruction::AttachClient(attrs, force, client_type, options, client_id) => {
+                if force {
+                    force_detach_clients(&*os_input, &session_data, &session_state);
+                }
+                match client_type {
+                    ClientType::Writer => session_state
+                        .write()
+                        .unwrap()
+                        .add_client(client_id, attrs.position_and_size),
+                    ClientType::Reader => session_state
+                        .write()
+                        .unwrap()
+                        .add_reader(client_id, attrs.position_and_size),
+                }
+                add_client_to_session(&session_data, client_id);
                 let rlock = session_data.read().unwrap();
                 let session_data = rlock.as_ref().unwrap();
-                session_data
-                    .senders
-                    .send_to_screen(ScreenInstruction::TerminalResize(attrs.position_and_size))
-                    .unwrap();
+                if let Some(character_cell_size) = attrs.character_cell_size {
+                    session_data
+                        .senders
+                        .send_to_screen(ScreenInstruction::SetCharacterCellSize(
+                            character_cell_size,
+                        ))
+                        .unwrap();
+                }
+                if let Some(size) = session_state.read().unwrap().session_size() {
+                    session_data
+                        .senders
+                        .send_to_screen(ScreenInstruction::TerminalResize(size))
+                        .unwrap();
+                }
                 let default_mode = options.default_mode.unwrap_or_default();
                 let mode_info =
                     get_mode_info(default_mode, attrs.palette, session_data.capabilities);
                 session_data
                     .senders
-                    .send_to_screen(ScreenInstruction::ChangeMode(mode_info.clone()))
+                    .send_to_screen(ScreenInstruction::ChangeMode(mode_info.clone(), client_id))
                     .unwrap();
                 session_data
                     .senders
                     .send_to_plugin(PluginInstruction::Update(
                         None,
+                        Some(client_id),
                         Event::ModeUpdate(mode_info),
                     ))
                     .unwrap();
             }
             ServerInstruction::UnblockInputThread => {
-                if *session_state.read().unwrap() == SessionState::Attached {
-                    os_input.send_to_client(ServerToClientMsg::UnblockInputThread);
+                for client_id in session_state.read().unwrap().client_ids() {
+                    os_input.send_to_client(client_id, ServerToClientMsg::UnblockInputThread);
                 }
             }
             ServerInstruction::ClientExit => {
                 *session_data.write().unwrap() = None;
-                os_input.send_to_client(ServerToClientMsg::Exit(ExitReason::Normal));
+                for client_id in session_state.read().unwrap().client_ids() {
+                    os_input.send_to_client(client_id, ServerToClientMsg::Exit(ExitReason::Normal));
+                }
+                break;
+            }
+            ServerInstruction::KillSession(client_id) => {
+                // dropping the session tears it down like quitting does,
+                // terminating the processes of all panes
+                *session_data.write().unwrap() = None;
+                for attached_client_id in session_state.read().unwrap().client_ids() {
+                    os_input.send_to_client(
+                        attached_client_id,
+                        ServerToClientMsg::Exit(ExitReason::Killed),
+                    );
+                }
+                // the session must be gone from the socket dir once the killer is answered
+                drop(std::fs::remove_file(&socket_path));
+                os_input.send_to_client(client_id, ServerToClientMsg::Exit(ExitReason::Normal));
                 break;
             }
-            ServerInstruction::DetachSession => {
-                *session_state.write().unwrap() = SessionState::Detached;
-                os_input.send_to_client(ServerToClientMsg::Exit(ExitReason::Normal));
-                os_input.remove_client_sender();
+            ServerInstruction::RenameSession(new_name, client_id) => {
+                let new_socket_path = socket_path.with_file_name(&new_name);
+                let renamed = if !is_valid_session_name(&new_name) {
+                    Err(format!("{:?} is not a valid session name", new_name))
+                } else {
+                    rename_socket(&socket_path, &new_socket_path).map_err(|e| {
+                        if e.kind() == std::io::ErrorKind::AlreadyExists {
+                            format!("Session with name {:?} already exists", new_name)
+                        } else {
+                            e.to_string()
+                        }
+                    })
+                };
+                if renamed.is_ok() {
+                    let old_name = session_name(&socket_path);
+                    socket_path = new_socket_path;
+                    // panes opened from now on know the session by its new name
+                    std::env::set_var("ZELLIJ_SESSION_NAME", &new_name);
+                    if let Some(session) = session_data.read().unwrap().as_ref() {
+                        // the snapshot the session can be resurrected from follows its name
+                        drop(std::fs::rename(
+                            SessionSnapshot::path(&session.data_dir, &old_name),
+                            SessionSnapshot::path(&session.data_dir, &new_name),
+                        ));
+                        session
+                            .senders
+                            .send_to_plugin(PluginInstruction::Update(
+                                None,
+                                None,
+                                Event::SessionNameUpdate(new_name),
+                            ))
+                            .unwrap();
+                    }
+                }
+                if let Some(client_id) = client_id {
+                    let reason = match renamed {
+                        Ok(()) => ExitReason::Normal,
+                        Err(e) => ExitReason::Error(e),
+                    };
+                    os_input.send_to_client(client_id, ServerToClientMsg::Exit(reason));
+                }
+            }
+            ServerInstruction::RemoveClient(client_id) => {
+                let was_attached = session_state.read().unwrap().has_client(client_id);
+                remove_client(&*os_input, &session_data, &session_state, client_id);
+                if was_attached {
+                    resize_to_session_size(&session_data, &session_state);
+                }
+            }
+            ServerInstruction::DetachSession(client_id) => {
+                os_input.send_to_client(client_id, ServerToClientMsg::Exit(ExitReason::Normal));
+                remove_client(&*os_input, &session_data, &session_state, client_id);
+                resize_to_session_size(&session_data, &session_state);
+            }
+            ServerInstruction::SessionInfo(mut session_info, requester) => {
+                session_info.name = session_name(&socket_path);
+                session_info.connected_clients = session_state.read().unwrap().client_ids();
+                if let Some(session) = session_data.read().unwrap().as_ref() {
+                    session_info.created = session
+                        .created
+                        .duration_since(UNIX_EPOCH)
+                        .map(|since_epoch| since_epoch.as_secs())
+                        .unwrap_or_default();
+                    session_info.layout = session.layout_name.clone();
+                }
+                // the server runs in the directory it was started from
+                session_info.cwd = std::env::current_dir().ok();
+                match requester {
+                    SessionInfoRequester::Client(client_id) => {
+                        os_input.send_to_client(
+                            client_id,
+                            ServerToClientMsg::SessionInfo(session_info),
+                        );
+                    }
+                    SessionInfoRequester::Resurrection { .. } => {
+                        if let Some(session) = session_data.read().unwrap().as_ref() {
+                            // failing to serialize only means the session cannot be resurrected
+                            drop(
+                                SessionSnapshot::from_session_info(&session_info)
+                                    .save(&session.data_dir, &session_info.name),
+                            );
+                        }
+                    }
+                    SessionInfoRequester::DumpLayout {
+                        client_id,
+                        requester,
+                    } => {
+                        let tab = session_info
+                            .tabs
+                            .iter()
+                            .find(|tab| tab.active_clients.contains(&client_id));
+                        let layout = tab
+                            .ok_or_else(|| "The client is not viewing any tab".to_string())
+                            .and_then(|tab| {
+                                let (layout, _) = Layout::from_tab_info(tab, true);
+                                layout.to_yaml().map_err(|e| e.to_string())
+                            });
+                        match (layout, requester) {
+                            (Ok(layout), Some(requester)) => os_input
+                                .send_to_client(requester, ServerToClientMsg::Layout(layout)),
+                            (Err(e), Some(requester)) => os_input.send_to_client(
+                                requester,
+                                ServerToClientMsg::Exit(ExitReason::Error(e)),
+                            ),
+                            (Ok(layout), None) => {
+                                let output =
+                                    format!("\u{1b}]52;c;{}\u{1b}\\", base64::encode(layout));
+                                os_input
+                                    .send_to_client(client_id, ServerToClientMsg::Render(output));
+                            }
+                            (Err(_), None) => {}
+                        }
+                    }
+                }
             }
             ServerInstruction::Render(output) => {
-                if *session_state.read().unwrap() == SessionState::Attached {
-                    // Here output is of the type Option<String> sent by screen thread.
-                    // If `Some(_)`- unwrap it and forward it to the client to render.
-                    // If `None`- Send an exit instruction. This is the case when the user closes last Tab/Pane.
-                    if let Some(op) = output {
-                        os_input.send_to_client(ServerToClientMsg::Render(op));
-                    } else {
-                        os_input.send_to_client(ServerToClientMsg::Exit(ExitReason::Normal));
-                        break;
+                // Here output is of the type Option<HashMap<ClientId, String>> sent by screen thread.
+                // If `Some(_)`- unwrap it and forward each output to its client to render.
+                // If `None`- Send an exit instruction. This is the case when the user closes last Tab/Pane.
+                if let Some(client_outputs) = output {
+                    for (client_id, op) in client_outputs {
+                        os_input.send_to_client(client_id, ServerToClientMsg::Render(op));
                     }
+                } else {
+                    for client_id in session_state.read().unwrap().client_ids() {
+                        os_input
+                            .send_to_client(client_id, ServerToClientMsg::Exit(ExitReason::Normal));
+                    }
+                    break;
                 }
             }
             ServerInstruction::Error(backtrace) => {
-                if *session_state.read().unwrap() == SessionState::Attached {
-                    os_input.send_to_client(ServerToClientMsg::Exit(ExitReason::Error(backtrace)));
+                for client_id in session_state.read().unwrap().client_ids() {
+                    os_input.send_to_client(
+                        client_id,
+                        ServerToClientMsg::Exit(ExitReason::Error(backtrace.clone())),
+                    );
                 }
                 break;
             }
@@ -292,6 +636,143 @@ pub fn start_server(os_input: Box<dyn ServerOsApi>, socket_path: PathBuf) {
     drop(std::fs::remove_file(&socket_path));
 }
 
+/// The socket of a session is named after it
+fn session_name(socket_path: &Path) -> String {
+    socket_path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// Whether `name` can be used as the file name of the socket of a session
+fn is_valid_session_name(name: &str) -> bool {
+    !name.is_empty() && !name.contains('/') && name != "." && name != ".."
+}
+
+/// Moves the socket of the session to `new_socket_path`, failing with `AlreadyExists` if
+/// something is there, as another session could have taken the name since it was checked.
+/// Clients connect by path, so the renamed socket keeps listening.
+fn rename_socket(socket_path: &Path, new_socket_path: &Path) -> std::io::Result<()> {
+    // unlike renaming, linking never replaces the target
+    std::fs::hard_link(socket_path, new_socket_path)?;
+    std::fs::remove_file(socket_path).map_err(|e| {
+        drop(std::fs::remove_file(new_socket_path));
+        e
+    })
+}
+
+/// Lets the screen and plugins know about a newly attached client, so that they can track
+/// its mode and focus separately from the other clients.
+fn add_client_to_session(session_data: &Arc<RwLock<Option<SessionMetaData>>>, client_id: ClientId) {
+    if let Some(session_data) = session_data.read().unwrap().as_ref() {
+        // plugins are started for the client before the screen renders them
+        session_data
+            .senders
+            .send_to_plugin(PluginInstruction::AddClient(client_id))
+            .unwrap();
+        session_data
+            .senders
+            .send_to_screen(ScreenInstruction::AddClient(client_id))
+            .unwrap();
+        // the client starts where the actions from the command line left the session
+        session_data
+            .senders
+            .send_to_screen(ScreenInstruction::RemoveClient(DETACHED_CLIENT_ID))
+            .unwrap();
+    }
+}
+
+/// Holds the id of a connection to the server until it ends, so that ids can be reused
+/// once their connection ended, as `zellij action` connects for every action. The id stays
+/// taken until the server removed the connection too, so that removing it does not remove
+/// the newer connection that took its id.
+struct ConnectedClient {
+    client_id: ClientId,
+    connected_client_ids: Arc<Mutex<BTreeSet<ClientId>>>,
+}
+
+impl ConnectedClient {
+    /// Takes the lowest id that is neither used by another connection nor by a client the
+    /// server or session has not removed yet.
+    fn new(
+        connected_client_ids: &Arc<Mutex<BTreeSet<ClientId>>>,
+        os_input: &dyn ServerOsApi,
+        session_state: &SessionState,
+    ) -> Option<Self> {
+        let mut client_ids = connected_client_ids.lock().unwrap();
+        let client_id = (DETACHED_CLIENT_ID + 1..=ClientId::MAX).find(|id| {
+            !client_ids.contains(id) && !os_input.has_client(*id) && !session_state.has_client(*id)
+        })?;
+        client_ids.insert(client_id);
+        Some(ConnectedClient {
+            client_id,
+            connected_client_ids: connected_client_ids.clone(),
+        })
+    }
+}
+
+impl Drop for ConnectedClient {
+    // also when the router panics, eg. as the client disconnected
+    fn drop(&mut self) {
+        self.connected_client_ids
+            .lock()
+            .unwrap()
+            .remove(&self.client_id);
+    }
+}
+
+fn remove_client(
+    os_input: &dyn ServerOsApi,
+    session_data: &Arc<RwLock<Option<SessionMetaData>>>,
+    session_state: &Arc<RwLock<SessionState>>,
+    client_id: ClientId,
+) {
+    session_state.write().unwrap().remove_client(client_id);
+    os_input.remove_client(client_id);
+    if let Some(session_data) = session_data.read().unwrap().as_ref() {
+        session_data
+            .senders
+            .send_to_screen(ScreenInstruction::RemoveClient(client_id))
+            .unwrap();
+        session_data
+            .senders
+            .send_to_plugin(PluginInstruction::RemoveClient(client_id))
+            .unwrap();
+    }
+}
+
+/// Detaches every client of the session, to make room for one attaching with `--force`.
+fn force_detach_clients(
+    os_input: &dyn ServerOsApi,
+    session_data: &Arc<RwLock<Option<SessionMetaData>>>,
+    session_state: &Arc<RwLock<SessionState>>,
+) {
+    // collected first, as removing the clients needs to write the session state
+    let client_ids = session_state.read().unwrap().client_ids();
+    for client_id in client_ids {
+        os_input.send_to_client(
+            client_id,
+            ServerToClientMsg::Exit(ExitReason::ForceDetached),
+        );
+        remove_client(os_input, session_data, session_state, client_id);
+    }
+}
+
+/// Resizes the screen to fit the remaining clients, after one of them left.
+fn resize_to_session_size(
+    session_data: &Arc<RwLock<Option<SessionMetaData>>>,
+    session_state: &Arc<RwLock<SessionState>>,
+) {
+    if let Some(session_data) = session_data.read().unwrap().as_ref() {
+        if let Some(size) = session_state.read().unwrap().session_size() {
+            session_data
+                .senders
+                .send_to_screen(ScreenInstruction::TerminalResize(size))
+                .unwrap();
+        }
+    }
+}
+
 fn init_session(
     os_input: Box<dyn ServerOsApi>,
     opts: Box<CliArgs>,
@@ -313,9 +794,64 @@ fn init_session(
     let (to_pty, pty_receiver): ChannelWithContext<PtyInstruction> = channels::unbounded();
     let to_pty = SenderWithContext::new(to_pty);
 
+    // layouts are loaded by the client, so name them after the path they were loaded from
+    let layout_name = layout.as_ref().map(|_| {
+        opts.layout
+            .as_ref()
+            .or(opts.layout_path.as_ref())
+            .and_then(|path| path.file_stem())
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "default".into())
+    });
+
+    let layout_dir = config_options
+        .layout_dir
+        .clone()
+        .or_else(|| get_layout_dir(opts.config_dir.clone().or_else(find_default_config_dir)));
+
     // Determine and initialize the data directory
     let data_dir = opts.data_dir.unwrap_or_else(get_default_data_dir);
 
+    let serialization_interval = config_options
+        .serialization_interval
+        .unwrap_or(DEFAULT_SERIALIZATION_INTERVAL);
+    if serialization_interval > 0 {
+        let scrollback_lines = config_options.scrollback_lines_to_serialize.unwrap_or(0);
+        let to_screen = to_screen.clone();
+        thread::Builder::new()
+            .name("serializer".to_string())
+            .spawn(move || loop {
+                thread::sleep(Duration::from_secs(serialization_interval));
+                // the screen is gone once the session ends
+                if to_screen
+                    .send(ScreenInstruction::QuerySession(
+                        SessionInfoRequester::Resurrection { scrollback_lines },
+                    ))
+                    .is_err()
+                {
+                    break;
+                }
+            })
+            .unwrap();
+    }
+
+    thread::Builder::new()
+        .name("foreground_commands".to_string())
+        .spawn({
+            let to_screen = to_screen.clone();
+            move || loop {
+                thread::sleep(FOREGROUND_COMMANDS_UPDATE_INTERVAL);
+                // the screen is gone once the session ends
+                if to_screen
+                    .send(ScreenInstruction::UpdateForegroundCommands)
+                    .is_err()
+                {
+                    break;
+                }
+            }
+        })
+        .unwrap();
+
     let capabilities = PluginCapabilities {
         arrow_fonts: config_options.simplified_ui,
     };
@@ -384,6 +920,7 @@ fn init_session(
             );
             let store = Store::default();
 
+            let data_dir = data_dir.clone();
             move || wasm_thread_main(plugin_bus, store, data_dir)
         })
         .unwrap();
@@ -397,9 +934,21 @@ fn init_session(
         },
         capabilities,
         default_shell,
+        created: SystemTime::now(),
+        layout_name,
+        layout_dir,
+        data_dir,
         palette: client_attributes.palette,
         screen_thread: Some(screen_thread),
         pty_thread: Some(pty_thread),
         wasm_thread: Some(wasm_thread),
     }
 }
+
+#[cfg(test)]
+#[path = "./unit/session_state_tests.rs"]
+mod session_state_tests;
+
+#[cfg(test)]
+#[path = "./unit/server_tests.rs"]
+mod server_tests;
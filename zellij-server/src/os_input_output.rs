@@ -1,4 +1,6 @@
+use std::collections::HashMap;
 use std::env;
+use std::fs;
 use std::os::unix::io::RawFd;
 use std::path::PathBuf;
 use std::process::{Child, Command};
@@ -17,15 +19,13 @@ use nix::unistd::{self, ForkResult};
 use signal_hook::consts::*;
 use zellij_tile::data::Palette;
 use zellij_utils::{
-    errors::ErrorContext,
     input::command::{RunCommand, TerminalAction},
-    ipc::{
-        ClientToServerMsg, ExitReason, IpcReceiverWithContext, IpcSenderWithContext,
-        ServerToClientMsg,
-    },
+    ipc::{ClientToServerMsg, IpcReceiverWithContext, IpcSenderWithContext, ServerToClientMsg},
     shared::default_palette,
 };
 
+use crate::ClientId;
+
 use async_std::io::ReadExt;
 pub use async_trait::async_trait;
 
@@ -102,10 +102,12 @@ fn handle_terminal(cmd: RunCommand, orig_termios: termios::Termios) -> (RawFd, P
                 let pid_secondary = match fork_pty_res.fork_result {
                     ForkResult::Parent { child } => child,
                     ForkResult::Child => {
-                        let child = Command::new(cmd.command)
-                            .args(&cmd.args)
-                            .spawn()
-                            .expect("failed to spawn");
+                        let mut command = Command::new(cmd.command);
+                        command.args(&cmd.args).envs(&cmd.env);
+                        if let Some(cwd) = cmd.cwd {
+                            command.current_dir(cwd);
+                        }
+                        let child = command.spawn().expect("failed to spawn");
                         handle_command_exit(child);
                         ::std::process::exit(0);
                     }
@@ -148,14 +150,22 @@ pub fn spawn_terminal(
                 .into_os_string()
                 .into_string()
                 .expect("Not valid Utf8 Encoding")];
-            RunCommand { command, args }
+            RunCommand {
+                command,
+                args,
+                ..Default::default()
+            }
         }
         Some(TerminalAction::RunCommand(command)) => command,
         None => {
             let command =
                 PathBuf::from(env::var("SHELL").expect("Could not find the SHELL variable"));
             let args = vec![];
-            RunCommand { command, args }
+            RunCommand {
+                command,
+                args,
+                ..Default::default()
+            }
         }
     };
 
@@ -165,8 +175,7 @@ pub fn spawn_terminal(
 #[derive(Clone)]
 pub struct ServerOsInputOutput {
     orig_termios: Arc<Mutex<termios::Termios>>,
-    receive_instructions_from_client: Option<Arc<Mutex<IpcReceiverWithContext<ClientToServerMsg>>>>,
-    send_instructions_to_client: Arc<Mutex<Option<IpcSenderWithContext<ServerToClientMsg>>>>,
+    client_senders: Arc<Mutex<HashMap<ClientId, IpcSenderWithContext<ServerToClientMsg>>>>,
 }
 
 // async fn in traits is not supported by rust, so dtolnay's excellent async_trait macro is being
@@ -218,23 +227,25 @@ pub trait ServerOsApi: Send + Sync {
     fn force_kill(&self, pid: Pid) -> Result<(), nix::Error>;
     /// Returns a [`Box`] pointer to this [`ServerOsApi`] struct.
     fn box_clone(&self) -> Box<dyn ServerOsApi>;
-    /// Receives a message on server-side IPC channel
-    fn recv_from_client(&self) -> (ClientToServerMsg, ErrorContext);
-    /// Sends a message to client
-    fn send_to_client(&self, msg: ServerToClientMsg);
-    /// Adds a sender to client
-    fn add_client_sender(&self);
-    /// Send to the temporary client
-    // A temporary client is the one that hasn't been registered as a client yet.
-    // Only the corresponding router thread has access to send messages to it.
-    // This can be the case when the client cannot attach to the session,
-    // so it tries to connect and then exits, hence temporary.
-    fn send_to_temp_client(&self, msg: ServerToClientMsg);
-    /// Removes the sender to client
-    fn remove_client_sender(&self);
-    /// Update the receiver socket for the client
-    fn update_receiver(&mut self, stream: LocalSocketStream);
+    /// Sends a message to the client with id `client_id`
+    fn send_to_client(&self, client_id: ClientId, msg: ServerToClientMsg);
+    /// Registers a newly connected client, returning the receiver for its messages
+    fn new_client(
+        &self,
+        client_id: ClientId,
+        stream: LocalSocketStream,
+    ) -> IpcReceiverWithContext<ClientToServerMsg>;
+    /// Removes the sender to the client with id `client_id`
+    fn remove_client(&self, client_id: ClientId);
+    /// Whether there is a sender to the client with id `client_id`
+    fn has_client(&self, client_id: ClientId) -> bool;
     fn load_palette(&self) -> Palette;
+    /// Returns the id of the process group in the foreground of the terminal referred to by `fd`.
+    fn get_foreground_pid(&self, fd: RawFd) -> Option<Pid>;
+    /// Returns the command line the process with process ID `pid` was started with.
+    fn get_cmdline(&self, pid: Pid) -> Option<Vec<String>>;
+    /// Returns the current working directory of the process with process ID `pid`.
+    fn get_cwd(&self, pid: Pid) -> Option<PathBuf>;
 }
 
 impl ServerOsApi for ServerOsInputOutput {
@@ -269,59 +280,50 @@ impl ServerOsApi for ServerOsInputOutput {
         let _ = kill(pid, Some(Signal::SIGKILL));
         Ok(())
     }
-    fn recv_from_client(&self) -> (ClientToServerMsg, ErrorContext) {
-        self.receive_instructions_from_client
-            .as_ref()
-            .unwrap()
-            .lock()
-            .unwrap()
-            .recv()
-    }
-    fn send_to_client(&self, msg: ServerToClientMsg) {
-        self.send_instructions_to_client
-            .lock()
-            .unwrap()
-            .as_mut()
-            .unwrap()
-            .send(msg);
-    }
-    fn add_client_sender(&self) {
-        let sender = self
-            .receive_instructions_from_client
-            .as_ref()
-            .unwrap()
-            .lock()
-            .unwrap()
-            .get_sender();
-        let old_sender = self
-            .send_instructions_to_client
-            .lock()
-            .unwrap()
-            .replace(sender);
-        if let Some(mut sender) = old_sender {
-            sender.send(ServerToClientMsg::Exit(ExitReason::ForceDetached));
+    fn send_to_client(&self, client_id: ClientId, msg: ServerToClientMsg) {
+        if let Some(sender) = self.client_senders.lock().unwrap().get_mut(&client_id) {
+            sender.send(msg);
         }
     }
-    fn send_to_temp_client(&self, msg: ServerToClientMsg) {
-        self.receive_instructions_from_client
-            .as_ref()
-            .unwrap()
+    fn new_client(
+        &self,
+        client_id: ClientId,
+        stream: LocalSocketStream,
+    ) -> IpcReceiverWithContext<ClientToServerMsg> {
+        let receiver = IpcReceiverWithContext::new(stream);
+        let sender = receiver.get_sender();
+        self.client_senders
             .lock()
             .unwrap()
-            .get_sender()
-            .send(msg);
+            .insert(client_id, sender);
+        receiver
     }
-    fn remove_client_sender(&self) {
-        assert!(self.send_instructions_to_client.lock().unwrap().is_some());
-        *self.send_instructions_to_client.lock().unwrap() = None;
+    fn remove_client(&self, client_id: ClientId) {
+        self.client_senders.lock().unwrap().remove(&client_id);
     }
-    fn update_receiver(&mut self, stream: LocalSocketStream) {
-        self.receive_instructions_from_client =
-            Some(Arc::new(Mutex::new(IpcReceiverWithContext::new(stream))));
+    fn has_client(&self, client_id: ClientId) -> bool {
+        self.client_senders.lock().unwrap().contains_key(&client_id)
     }
     fn load_palette(&self) -> Palette {
         default_palette()
     }
+    fn get_foreground_pid(&self, fd: RawFd) -> Option<Pid> {
+        unistd::tcgetpgrp(fd).ok()
+    }
+    fn get_cmdline(&self, pid: Pid) -> Option<Vec<String>> {
+        // only available where procfs is mounted
+        let cmdline = fs::read(format!("/proc/{}/cmdline", pid)).ok()?;
+        Some(
+            cmdline
+                .split(|byte| *byte == 0)
+                .filter(|arg| !arg.is_empty())
+                .map(|arg| String::from_utf8_lossy(arg).into_owned())
+                .collect(),
+        )
+    }
+    fn get_cwd(&self, pid: Pid) -> Option<PathBuf> {
+        fs::read_link(format!("/proc/{}/cwd", pid)).ok()
+    }
 }
 
 impl Clone for Box<dyn ServerOsApi> {
@@ -335,7 +337,6 @@ pub fn get_server_os_input() -> Result<ServerOsInputOutput, nix::Error> {
     let orig_termios = Arc::new(Mutex::new(current_termios));
     Ok(ServerOsInputOutput {
         orig_termios,
-        receive_instructions_from_client: None,
-        send_instructions_to_client: Arc::new(Mutex::new(None)),
+        client_senders: Arc::new(Mutex::new(HashMap::new())),
     })
 }
@@ -0,0 +1,184 @@
+//! The colors the program running in a pane redefined, with OSC 4 for the indexed colors and
+//! OSC 10, 11 and 12 for the default foreground, background and cursor colors.
+
+use std::collections::HashMap;
+
+use zellij_utils::zellij_tile::data::{Palette, PaletteColor, PaletteSource};
+
+use crate::panes::{AnsiCode, CharacterStyles};
+
+type Rgb = (u8, u8, u8);
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ColorTable {
+    indices: HashMap<u8, Rgb>,
+    pub foreground: Option<Rgb>,
+    pub background: Option<Rgb>,
+    pub cursor: Option<Rgb>,
+}
+
+impl ColorTable {
+    pub fn set_index(&mut self, index: u8, color: Rgb) {
+        self.indices.insert(index, color);
+    }
+    pub fn reset_index(&mut self, index: u8) {
+        self.indices.remove(&index);
+    }
+    pub fn reset_indices(&mut self) {
+        self.indices.clear();
+    }
+
+    /// The color of `index`, as redefined, or as the palette or else xterm defines it
+    pub fn index_color(&self, index: u8, palette: &Palette) -> Rgb {
+        if let Some(&color) = self.indices.get(&index) {
+            return color;
+        }
+        // only the palettes read from the terminal describe its colors
+        if palette.source == PaletteSource::Xresources {
+            let palette_color = match index {
+                0 => Some(palette.black),
+                1 => Some(palette.red),
+                2 => Some(palette.green),
+                3 => Some(palette.yellow),
+                4 => Some(palette.blue),
+                5 => Some(palette.magenta),
+                6 => Some(palette.cyan),
+                7 => Some(palette.white),
+                _ => None,
+            };
+            if let Some(PaletteColor::Rgb(color)) = palette_color {
+                return color;
+            }
+        }
+        xterm_index_color(index)
+    }
+    pub fn foreground_color(&self, palette: &Palette) -> Rgb {
+        self.foreground.unwrap_or(match palette.fg {
+            PaletteColor::Rgb(color) => color,
+            PaletteColor::EightBit(_) => xterm_index_color(7),
+        })
+    }
+    pub fn background_color(&self, palette: &Palette) -> Rgb {
+        self.background.unwrap_or(match palette.bg {
+            PaletteColor::Rgb(color) => color,
+            PaletteColor::EightBit(_) => xterm_index_color(0),
+        })
+    }
+    pub fn cursor_color(&self, palette: &Palette) -> Rgb {
+        self.cursor
+            .unwrap_or_else(|| self.foreground_color(palette))
+    }
+
+    /// `styles`, with the colors that were redefined replaced by their new definition
+    pub fn apply(&self, mut styles: CharacterStyles) -> CharacterStyles {
+        if *self == ColorTable::default() {
+            return styles;
+        }
+        styles.foreground = self.apply_to_code(styles.foreground, self.foreground);
+        styles.background = self.apply_to_code(styles.background, self.background);
+        styles.underline_color = self.apply_to_code(styles.underline_color, None);
+        styles
+    }
+    fn apply_to_code(&self, code: Option<AnsiCode>, default: Option<Rgb>) -> Option<AnsiCode> {
+        let index = match code {
+            None | Some(AnsiCode::Reset) => return default.map(AnsiCode::RgbCode).or(code),
+            Some(AnsiCode::NamedColor(named_color)) => named_color.index(),
+            Some(AnsiCode::ColorIndex(index)) => index,
+            _ => return code,
+        };
+        self.indices
+            .get(&index)
+            .map(|&color| AnsiCode::RgbCode(color))
+            .or(code)
+    }
+}
+
+/// The color xterm gives `index` by default
+fn xterm_index_color(index: u8) -> Rgb {
+    const SYSTEM_COLORS: [Rgb; 16] = [
+        (0x00, 0x00, 0x00),
+        (0xcd, 0x00, 0x00),
+        (0x00, 0xcd, 0x00),
+        (0xcd, 0xcd, 0x00),
+        (0x00, 0x00, 0xee),
+        (0xcd, 0x00, 0xcd),
+        (0x00, 0xcd, 0xcd),
+        (0xe5, 0xe5, 0xe5),
+        (0x7f, 0x7f, 0x7f),
+        (0xff, 0x00, 0x00),
+        (0x00, 0xff, 0x00),
+        (0xff, 0xff, 0x00),
+        (0x5c, 0x5c, 0xff),
+        (0xff, 0x00, 0xff),
+        (0x00, 0xff, 0xff),
+        (0xff, 0xff, 0xff),
+    ];
+    let cube_level = |level: u8| if level == 0 { 0 } else { 55 + level * 40 };
+    match index {
+        0..=15 => SYSTEM_COLORS[index as usize],
+        16..=231 => {
+            let index = index - 16;
+            (
+                cube_level(index / 36),
+                cube_level(index / 6 % 6),
+                cube_level(index % 6),
+            )
+        }
+        _ => {
+            let gray = 8 + (index - 232) * 10;
+            (gray, gray, gray)
+        }
+    }
+}
+
+/// Parses the X11 color specifications programs use: `rgb:r/g/b`, with 1 to 4 hex digits
+/// per component, and `#rgb`, with 1 to 4 digits per component as well.
+pub fn parse_color_spec(spec: &[u8]) -> Option<Rgb> {
+    let spec = std::str::from_utf8(spec).ok()?;
+    // scales a component of 1 to 4 hex digits to 8 bits
+    let component = |hex: &str| -> Option<u8> {
+        if hex.is_empty() || hex.len() > 4 {
+            return None;
+        }
+        let value = u32::from_str_radix(hex, 16).ok()?;
+        let max = (1u32 << (4 * hex.len())) - 1;
+        Some((value * 255 / max) as u8)
+    };
+    if let Some(components) = spec.strip_prefix("rgb:") {
+        let mut components = components.split('/').map(component);
+        let color = (
+            components.next()??,
+            components.next()??,
+            components.next()??,
+        );
+        return components.next().is_none().then_some(color);
+    }
+    let digits = spec.strip_prefix('#')?;
+    if !digits.is_ascii() || digits.is_empty() || digits.len() % 3 != 0 || digits.len() > 12 {
+        return None;
+    }
+    let width = digits.len() / 3;
+    // the components of `#` specifications are the most significant bits, not scaled ones
+    let component = |hex: &str| -> Option<u8> {
+        let value = u32::from_str_radix(hex, 16).ok()?;
+        Some(match width {
+            1 => (value << 4) as u8,
+            2 => value as u8,
+            _ => (value >> (4 * (width - 2))) as u8,
+        })
+    };
+    Some((
+        component(&digits[..width])?,
+        component(&digits[width..2 * width])?,
+        component(&digits[2 * width..])?,
+    ))
+}
+
+/// The report of `color` for `code`, in the format queries are answered with
+pub fn color_report(code: &str, color: Rgb, terminator: &str) -> String {
+    let (r, g, b) = color;
+    format!(
+        "\u{1b}]{};rgb:{1:02x}{1:02x}/{2:02x}{2:02x}/{3:02x}{3:02x}{4}",
+        code, r, g, b, terminator
+    )
+}
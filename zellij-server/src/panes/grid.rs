@@ -1,21 +1,31 @@
 use unicode_width::UnicodeWidthChar;
 
 use std::{
+    cell::RefCell,
     cmp::Ordering,
     collections::{BTreeSet, VecDeque},
     fmt::{self, Debug, Formatter},
+    rc::Rc,
     str,
 };
 
-use zellij_utils::{position::Position, vte, zellij_tile};
+use zellij_utils::{
+    input::mouse::{MouseButton, MouseEvent, MouseModifiers},
+    pane_size::SizeInPixels,
+    position::Position,
+    vte, zellij_tile,
+};
 
 const TABSTOP_WIDTH: usize = 8; // TODO: is this always right?
 const SCROLL_BACK: usize = 10_000;
 
 use vte::{Params, Perform};
-use zellij_tile::data::{Palette, PaletteColor};
+use zellij_tile::data::Palette;
 use zellij_utils::{consts::VERSION, logging::debug_log_to_file, shared::version_number};
 
+use crate::panes::color_table::{color_report, parse_color_spec, ColorTable};
+use crate::panes::link_table::LinkTable;
+use crate::panes::sixel::SixelImageStore;
 use crate::panes::terminal_character::{
     CharacterStyles, CharsetIndex, Cursor, CursorShape, StandardCharset, TerminalCharacter,
     EMPTY_TERMINAL_CHARACTER,
@@ -317,7 +327,48 @@ pub struct Grid {
     pub width: usize,
     pub height: usize,
     pub pending_messages_to_pty: Vec<Vec<u8>>,
+    pub pending_clipboard_requests: Vec<ClipboardRequest>,
     pub selection: Selection,
+    /// The window title set by the program running in the pane (OSC 0/2)
+    pub title: Option<String>,
+    pub mouse_tracking: MouseTracking,
+    pub sgr_mouse_encoding: bool,   // DECSET 1006
+    pub bracketed_paste_mode: bool, // DECSET 2004
+    pub color_table: ColorTable,
+    pub link_table: LinkTable,
+    pub sixel_images: SixelImageStore,
+    /// The size of the character cells of the clients, which images are anchored to cells
+    /// with, shared by the grids of a session and updated as clients attach and resize
+    pub character_cell_size: Rc<RefCell<Option<SizeInPixels>>>,
+    mouse_button_held: Option<MouseButton>,
+    last_mouse_hold: Option<Position>,
+}
+
+/// A request of the program running in the pane to use the clipboard (OSC 52)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClipboardRequest {
+    /// Copy the text to the given selections, usually `c` for the clipboard
+    Copy {
+        selection: String,
+        base64_text: String,
+    },
+    /// Report the text of the given selections, ending the report with `terminator`
+    Paste {
+        selection: String,
+        terminator: String,
+    },
+}
+
+/// The mouse events the program running in the pane asked to be reported
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MouseTracking {
+    Off,
+    /// Presses and releases (DECSET 1000)
+    Normal,
+    /// Motion while a button is held as well (DECSET 1002)
+    ButtonEvent,
+    /// All motion (DECSET 1003), though only the motion while a button is held reaches us
+    AnyEvent,
 }
 
 impl Debug for Grid {
@@ -355,9 +406,20 @@ impl Grid {
             clear_viewport_before_rendering: false,
             active_charset: Default::default(),
             pending_messages_to_pty: vec![],
+            pending_clipboard_requests: vec![],
             colors,
             output_buffer: Default::default(),
             selection: Default::default(),
+            title: None,
+            mouse_tracking: MouseTracking::Off,
+            sgr_mouse_encoding: false,
+            bracketed_paste_mode: false,
+            color_table: ColorTable::default(),
+            link_table: LinkTable::default(),
+            sixel_images: SixelImageStore::default(),
+            character_cell_size: Rc::new(RefCell::new(None)),
+            mouse_button_held: None,
+            last_mouse_hold: None,
         }
     }
     pub fn render_full_viewport(&mut self) {
@@ -1125,6 +1187,11 @@ impl Grid {
     pub fn mark_for_rerender(&mut self) {
         self.should_render = true;
     }
+    /// Renders every character again, with the colors the program running in the pane redefined
+    fn mark_colors_changed(&mut self) {
+        self.output_buffer.update_all_lines();
+        self.mark_for_rerender();
+    }
     fn reset_terminal_state(&mut self) {
         self.lines_above = VecDeque::with_capacity(SCROLL_BACK);
         self.lines_below = vec![];
@@ -1139,11 +1206,135 @@ impl Grid {
         self.erasure_mode = false;
         self.disable_linewrap = false;
         self.cursor.change_shape(CursorShape::Block);
+        self.mouse_tracking = MouseTracking::Off;
+        self.sgr_mouse_encoding = false;
+        self.bracketed_paste_mode = false;
+        self.color_table = ColorTable::default();
+        self.link_table = LinkTable::default();
+        self.sixel_images = SixelImageStore::default();
         self.output_buffer.update_all_lines();
     }
+    /// Anchors the image to the character under the cursor, and moves the cursor to the line
+    /// below the image, as terminals that scroll with sixel images do
+    fn add_sixel_image(&mut self, image_id: u16) {
+        let character_cell_size = match *self.character_cell_size.borrow() {
+            Some(character_cell_size) => character_cell_size,
+            None => return,
+        };
+        // more lines than the pane has only scroll blank lines into the scrollback
+        let image_rows = match self.sixel_images.get(image_id) {
+            Some(image) => image.size_in_cells(character_cell_size).0.min(self.height),
+            None => return,
+        };
+        let x = self.cursor.x.min(self.width.saturating_sub(1));
+        self.cursor.x = x;
+        let mut anchor = EMPTY_TERMINAL_CHARACTER;
+        anchor.sixel_image = Some(image_id);
+        self.add_character_at_cursor_position(anchor);
+        for _ in 0..image_rows {
+            self.add_canonical_line();
+        }
+        self.cursor.x = x;
+        self.output_buffer.update_all_lines();
+        self.mark_for_rerender();
+    }
+    /// The sequences drawing the images of the viewport that cover any of `changed_lines`,
+    /// which were drawn over, with the coordinates they are drawn at
+    pub fn sixel_images_to_render(&self, changed_lines: &[usize]) -> Vec<(usize, usize, String)> {
+        let character_cell_size = match *self.character_cell_size.borrow() {
+            Some(character_cell_size) if !self.sixel_images.is_empty() => character_cell_size,
+            _ => return vec![],
+        };
+        let mut images_to_render = vec![];
+        for (y, row) in self.viewport.iter().enumerate() {
+            let mut x = 0;
+            for character in &row.columns {
+                if let Some(image) = character
+                    .sixel_image
+                    .and_then(|image_id| self.sixel_images.get(image_id))
+                {
+                    let (image_rows, _) = image.size_in_cells(character_cell_size);
+                    let is_drawn_over = changed_lines
+                        .iter()
+                        .any(|line| (y..y.saturating_add(image_rows)).contains(line));
+                    if is_drawn_over && x < self.width {
+                        let max_width = (self.width - x) * character_cell_size.width;
+                        let max_height = (self.height - y) * character_cell_size.height;
+                        images_to_render.push((x, y, image.output(max_width, max_height)));
+                    }
+                }
+                x += character.width;
+            }
+        }
+        images_to_render
+    }
     fn set_preceding_character(&mut self, terminal_character: TerminalCharacter) {
         self.preceding_char = Some(terminal_character);
     }
+    /// Reports `event`, positioned relatively to the pane, to the program running in it in the
+    /// encoding it asked for, if it tracks such events.
+    pub fn mouse_event_report(
+        &mut self,
+        event: &MouseEvent,
+        modifiers: MouseModifiers,
+    ) -> Option<Vec<u8>> {
+        let button_code = |button: MouseButton| match button {
+            MouseButton::Left => 0,
+            MouseButton::Middle => 1,
+            MouseButton::Right => 2,
+            MouseButton::WheelUp => 64,
+            MouseButton::WheelDown => 65,
+        };
+        let (code, is_release) = match (self.mouse_tracking, event) {
+            (MouseTracking::Off, _) => return None,
+            (_, MouseEvent::Press(button, position)) => {
+                if !matches!(button, MouseButton::WheelUp | MouseButton::WheelDown) {
+                    self.mouse_button_held = Some(*button);
+                    self.last_mouse_hold = Some(*position);
+                }
+                (button_code(*button), false)
+            }
+            (_, MouseEvent::Release(_)) => {
+                self.last_mouse_hold = None;
+                match self.mouse_button_held.take() {
+                    // only SGR tells which button was released
+                    Some(button) if self.sgr_mouse_encoding => (button_code(button), true),
+                    _ => (3, true),
+                }
+            }
+            (MouseTracking::Normal, MouseEvent::Hold(_)) => return None,
+            (_, MouseEvent::Hold(position)) => {
+                // the hold is repeated for as long as the mouse does not move
+                if self.last_mouse_hold == Some(*position) {
+                    return None;
+                }
+                self.last_mouse_hold = Some(*position);
+                (
+                    32 + self.mouse_button_held.map(button_code).unwrap_or(3),
+                    false,
+                )
+            }
+        };
+        let code = code
+            + if modifiers.shift { 4 } else { 0 }
+            + if modifiers.alt { 8 } else { 0 }
+            + if modifiers.ctrl { 16 } else { 0 };
+        // reported from 1, and within the pane even when dragged out of it
+        let position = event.position();
+        let column = position.column.0.min(self.width.saturating_sub(1)) + 1;
+        let line = position.line.0.clamp(0, self.height as isize - 1) as usize + 1;
+        if self.sgr_mouse_encoding {
+            let last = if is_release { 'm' } else { 'M' };
+            Some(format!("\u{1b}[<{};{};{}{}", code, column, line, last).into_bytes())
+        } else if column <= 223 && line <= 223 {
+            let mut report = b"\x1b[M".to_vec();
+            report.extend([32 + code as u8, 32 + column as u8, 32 + line as u8]);
+            Some(report)
+        } else {
+            // too far to be encoded
+            None
+        }
+    }
     pub fn start_selection(&mut self, start: &Position) {
         let old_selection = self.selection.clone();
         self.selection.start(*start);
@@ -1237,6 +1428,38 @@ impl Grid {
         Some(selection.join("\n"))
     }
 
+    /// The text of the last `count` lines of the primary screen, including its scrollback,
+    /// with wrapped lines joined back together.
+    pub fn last_lines(&self, count: usize) -> Vec<String> {
+        let (lines_above, viewport, lines_below) =
+            match &self.alternative_lines_above_viewport_and_cursor {
+                // the primary screen is stashed while an application uses the alternate one
+                Some((lines_above, viewport, _)) => (lines_above, viewport, &[][..]),
+                None => (&self.lines_above, &self.viewport, &self.lines_below[..]),
+            };
+        let mut lines: Vec<String> = vec![];
+        for row in lines_above
+            .iter()
+            .chain(viewport.iter())
+            .chain(lines_below.iter())
+        {
+            let row_text = row.columns.iter().map(|c| c.character);
+            match lines.last_mut() {
+                // rows that are not canonical continue the line wrapped above them
+                Some(line) if !row.is_canonical => line.extend(row_text),
+                _ => lines.push(row_text.collect()),
+            }
+        }
+        for line in lines.iter_mut() {
+            line.truncate(line.trim_end().len());
+        }
+        while lines.last().map(|line| line.is_empty()).unwrap_or(false) {
+            lines.pop();
+        }
+        let first_line = lines.len().saturating_sub(count);
+        lines.split_off(first_line)
+    }
+
     fn update_selected_lines(&mut self, old_selection: &Selection, new_selection: &Selection) {
         for l in old_selection.diff(new_selection, self.height) {
             self.output_buffer.update_line(l as usize);
@@ -1253,6 +1476,8 @@ impl Perform for Grid {
             character: c,
             width: c.width().unwrap_or(0),
             styles: self.cursor.pending_styles,
+            link: self.link_table.active,
+            sixel_image: None,
         };
         self.set_preceding_character(terminal_character);
         self.add_character(terminal_character);
@@ -1288,16 +1513,26 @@ impl Perform for Grid {
         }
     }
 
-    fn hook(&mut self, _params: &Params, _intermediates: &[u8], _ignore: bool, _c: char) {
-        // TBD
+    fn hook(&mut self, params: &Params, intermediates: &[u8], _ignore: bool, c: char) {
+        // images can only be anchored to cells when we know their size
+        if c == 'q' && intermediates.is_empty() && self.character_cell_size.borrow().is_some() {
+            let params = params
+                .iter()
+                .map(|param| param[0].to_string())
+                .collect::<Vec<_>>()
+                .join(";");
+            self.sixel_images.start_image(params);
+        }
     }
 
-    fn put(&mut self, _byte: u8) {
-        // TBD
+    fn put(&mut self, byte: u8) {
+        self.sixel_images.add_data(byte);
     }
 
     fn unhook(&mut self) {
-        // TBD
+        if let Some(image_id) = self.sixel_images.end_image() {
+            self.add_sixel_image(image_id);
+        }
     }
 
     fn osc_dispatch(&mut self, params: &[&[u8]], bell_terminated: bool) {
@@ -1311,60 +1546,73 @@ impl Perform for Grid {
             // Set window title.
             b"0" | b"2" => {
                 if params.len() >= 2 {
-                    let _title = params[1..]
+                    let title = params[1..]
                         .iter()
                         .flat_map(|x| str::from_utf8(x))
                         .collect::<Vec<&str>>()
                         .join(";")
                         .trim()
                         .to_owned();
-                    // TBD: do something with title?
+                    // an empty title resets the pane to its default title
+                    self.title = Some(title).filter(|title| !title.is_empty());
                 }
             }
 
             // Set color index.
             b"4" => {
-                // TBD: set color index - currently unsupported
-                //
-                // this changes a terminal color index to something else
-                // meaning anything set to that index will be changed
-                // during rendering
+                for pair in params[1..].chunks(2) {
+                    let (index, spec) = match pair {
+                        [index, spec] => (parse_number(index), *spec),
+                        _ => break,
+                    };
+                    if let Some(index) = index {
+                        if spec == b"?" {
+                            let color = self.color_table.index_color(index, &self.colors);
+                            let code = format!("4;{}", index);
+                            self.pending_messages_to_pty
+                                .push(color_report(&code, color, terminator).into_bytes());
+                        } else if let Some(color) = parse_color_spec(spec) {
+                            self.color_table.set_index(index, color);
+                            self.mark_colors_changed();
+                        }
+                    }
+                }
             }
 
             // Get/set Foreground, Background, Cursor colors.
             b"10" | b"11" | b"12" => {
-                if params.len() >= 2 {
-                    if let Some(mut dynamic_code) = parse_number(params[0]) {
-                        for param in &params[1..] {
-                            // currently only getting the color sequence is supported,
-                            // setting still isn't
-                            if param == b"?" {
-                                let color_response_message = match self.colors.bg {
-                                    PaletteColor::Rgb((r, g, b)) => {
-                                        format!(
-                                            "\u{1b}]{};rgb:{1:02x}{1:02x}/{2:02x}{2:02x}/{3:02x}{3:02x}{4}",
-                                            // dynamic_code, color.r, color.g, color.b, terminator
-                                            dynamic_code, r, g, b, terminator
-                                        )
-                                    }
-                                    _ => {
-                                        format!(
-                                            "\u{1b}]{};rgb:{1:02x}{1:02x}/{2:02x}{2:02x}/{3:02x}{3:02x}{4}",
-                                            // dynamic_code, color.r, color.g, color.b, terminator
-                                            dynamic_code, 0, 0, 0, terminator
-                                        )
-                                    }
-                                };
-                                self.pending_messages_to_pty
-                                    .push(color_response_message.as_bytes().to_vec());
+                if let Some(mut dynamic_code) = parse_number(params[0]) {
+                    // the following colors are set or queried by the following parameters
+                    for param in &params[1..] {
+                        if param == b"?" {
+                            let color = match dynamic_code {
+                                10 => self.color_table.foreground_color(&self.colors),
+                                11 => self.color_table.background_color(&self.colors),
+                                _ => self.color_table.cursor_color(&self.colors),
+                            };
+                            self.pending_messages_to_pty.push(
+                                color_report(&dynamic_code.to_string(), color, terminator)
+                                    .into_bytes(),
+                            );
+                        } else if let Some(color) = parse_color_spec(param) {
+                            match dynamic_code {
+                                10 => self.color_table.foreground = Some(color),
+                                11 => self.color_table.background = Some(color),
+                                _ => self.color_table.cursor = Some(color),
                             }
-                            dynamic_code += 1;
+                            self.mark_colors_changed();
+                        }
+                        dynamic_code += 1;
+                        if dynamic_code > 12 {
+                            break;
                         }
-                        return;
                     }
                 }
             }
 
+            // Hyperlink.
+            b"8" => self.link_table.dispatch_osc8(params),
+
             // Set cursor style.
             b"50" => {
                 if params.len() >= 2
@@ -1389,13 +1637,31 @@ impl Perform for Grid {
                     return;
                 }
 
-                let _clipboard = params[1].get(0).unwrap_or(&b'c');
+                let selection = match str::from_utf8(params[1]) {
+                    Ok("") => String::from("c"),
+                    Ok(selection) => selection.to_owned(),
+                    Err(_) => return,
+                };
                 match params[2] {
                     b"?" => {
-                        // TBD: paste from own clipboard - currently unsupported
+                        self.pending_clipboard_requests
+                            .push(ClipboardRequest::Paste {
+                                selection,
+                                terminator: terminator.to_owned(),
+                            });
                     }
-                    _base64 => {
-                        // TBD: copy to own clipboard - currently unsupported
+                    base64_text => {
+                        let is_base64 = base64_text
+                            .iter()
+                            .all(|&b| b.is_ascii_alphanumeric() || b"+/=".contains(&b));
+                        if is_base64 {
+                            self.pending_clipboard_requests
+                                .push(ClipboardRequest::Copy {
+                                    selection,
+                                    // only ascii, as checked above
+                                    base64_text: String::from_utf8_lossy(base64_text).into(),
+                                });
+                        }
                     }
                 }
             }
@@ -1403,32 +1669,34 @@ impl Perform for Grid {
             // Reset color index.
             b"104" => {
                 // Reset all color indexes when no parameters are given.
-                if params.len() == 1 {
-                    // TBD - reset all color changes - currently unsupported
-                    return;
-                }
-
-                // Reset color indexes given as parameters.
-                for param in &params[1..] {
-                    if let Some(_index) = parse_number(param) {
-                        // TBD - reset color index - currently unimplemented
+                if params.len() == 1 || params[1..].iter().all(|param| param.is_empty()) {
+                    self.color_table.reset_indices();
+                } else {
+                    // Reset color indexes given as parameters.
+                    for param in &params[1..] {
+                        if let Some(index) = parse_number(param) {
+                            self.color_table.reset_index(index);
+                        }
                     }
                 }
+                self.mark_colors_changed();
             }
 
             // Reset foreground color.
             b"110" => {
-                // TBD - reset foreground color - currently unimplemented
+                self.color_table.foreground = None;
+                self.mark_colors_changed();
             }
 
             // Reset background color.
             b"111" => {
-                // TBD - reset background color - currently unimplemented
+                self.color_table.background = None;
+                self.mark_colors_changed();
             }
 
             // Reset text cursor color.
             b"112" => {
-                // TBD - reset text cursor color - currently unimplemented
+                self.color_table.cursor = None;
             }
 
             _ => {}
@@ -1507,45 +1775,57 @@ impl Perform for Grid {
                 _ => false,
             };
             if first_intermediate_is_questionmark {
-                match params_iter.next().map(|param| param[0]) {
-                    Some(1049) => {
-                        if let Some((
-                            alternative_lines_above,
-                            alternative_viewport,
-                            alternative_cursor,
-                        )) = self.alternative_lines_above_viewport_and_cursor.as_mut()
-                        {
-                            std::mem::swap(&mut self.lines_above, alternative_lines_above);
-                            std::mem::swap(&mut self.viewport, alternative_viewport);
-                            std::mem::swap(&mut self.cursor, alternative_cursor);
+                // every parameter is a mode of its own, eg. `?1000;1006h`
+                for mode in params_iter.map(|param| param[0]) {
+                    match mode {
+                        1049 => {
+                            if let Some((
+                                alternative_lines_above,
+                                alternative_viewport,
+                                alternative_cursor,
+                            )) = self.alternative_lines_above_viewport_and_cursor.as_mut()
+                            {
+                                std::mem::swap(&mut self.lines_above, alternative_lines_above);
+                                std::mem::swap(&mut self.viewport, alternative_viewport);
+                                std::mem::swap(&mut self.cursor, alternative_cursor);
+                            }
+                            self.alternative_lines_above_viewport_and_cursor = None;
+                            self.clear_viewport_before_rendering = true;
+                            self.change_size(self.height, self.width); // the alternative_viewport might have been of a different size...
+                            self.mark_for_rerender();
                         }
-                        self.alternative_lines_above_viewport_and_cursor = None;
-                        self.clear_viewport_before_rendering = true;
-                        self.change_size(self.height, self.width); // the alternative_viewport might have been of a different size...
-                        self.mark_for_rerender();
-                    }
-                    Some(25) => {
-                        self.hide_cursor();
-                        self.mark_for_rerender();
-                    }
-                    Some(1) => {
-                        self.cursor_key_mode = false;
-                    }
-                    Some(3) => {
-                        // DECCOLM - only side effects
-                        self.scroll_region = None;
-                        self.clear_all(EMPTY_TERMINAL_CHARACTER);
-                        self.cursor.x = 0;
-                        self.cursor.y = 0;
-                    }
-                    Some(6) => {
-                        self.erasure_mode = false;
-                    }
-                    Some(7) => {
-                        self.disable_linewrap = true;
-                    }
-                    _ => {}
-                };
+                        25 => {
+                            self.hide_cursor();
+                            self.mark_for_rerender();
+                        }
+                        1 => {
+                            self.cursor_key_mode = false;
+                        }
+                        3 => {
+                            // DECCOLM - only side effects
+                            self.scroll_region = None;
+                            self.clear_all(EMPTY_TERMINAL_CHARACTER);
+                            self.cursor.x = 0;
+                            self.cursor.y = 0;
+                        }
+                        6 => {
+                            self.erasure_mode = false;
+                        }
+                        7 => {
+                            self.disable_linewrap = true;
+                        }
+                        1000 | 1002 | 1003 => {
+                            self.mouse_tracking = MouseTracking::Off;
+                        }
+                        1006 => {
+                            self.sgr_mouse_encoding = false;
+                        }
+                        2004 => {
+                            self.bracketed_paste_mode = false;
+                        }
+                        _ => {}
+                    };
+                }
             } else if let Some(4) = params_iter.next().map(|param| param[0]) {
                 self.insert_mode = false;
             }
@@ -1556,43 +1836,62 @@ impl Perform for Grid {
                 _ => false,
             };
             if first_intermediate_is_questionmark {
-                match params_iter.next().map(|param| param[0]) {
-                    Some(25) => {
-                        self.show_cursor();
-                        self.mark_for_rerender();
-                    }
-                    Some(1049) => {
-                        let current_lines_above = std::mem::replace(
-                            &mut self.lines_above,
-                            VecDeque::with_capacity(SCROLL_BACK),
-                        );
-                        let current_viewport = std::mem::replace(
-                            &mut self.viewport,
-                            vec![Row::new(self.width).canonical()],
-                        );
-                        let current_cursor = std::mem::replace(&mut self.cursor, Cursor::new(0, 0));
-                        self.alternative_lines_above_viewport_and_cursor =
-                            Some((current_lines_above, current_viewport, current_cursor));
-                        self.clear_viewport_before_rendering = true;
-                    }
-                    Some(1) => {
-                        self.cursor_key_mode = true;
-                    }
-                    Some(3) => {
-                        // DECCOLM - only side effects
-                        self.scroll_region = None;
-                        self.clear_all(EMPTY_TERMINAL_CHARACTER);
-                        self.cursor.x = 0;
-                        self.cursor.y = 0;
-                    }
-                    Some(6) => {
-                        self.erasure_mode = true;
-                    }
-                    Some(7) => {
-                        self.disable_linewrap = false;
-                    }
-                    _ => {}
-                };
+                // every parameter is a mode of its own, eg. `?1000;1006h`
+                for mode in params_iter.map(|param| param[0]) {
+                    match mode {
+                        25 => {
+                            self.show_cursor();
+                            self.mark_for_rerender();
+                        }
+                        1049 => {
+                            let current_lines_above = std::mem::replace(
+                                &mut self.lines_above,
+                                VecDeque::with_capacity(SCROLL_BACK),
+                            );
+                            let current_viewport = std::mem::replace(
+                                &mut self.viewport,
+                                vec![Row::new(self.width).canonical()],
+                            );
+                            let current_cursor =
+                                std::mem::replace(&mut self.cursor, Cursor::new(0, 0));
+                            self.alternative_lines_above_viewport_and_cursor =
+                                Some((current_lines_above, current_viewport, current_cursor));
+                            self.clear_viewport_before_rendering = true;
+                        }
+                        1 => {
+                            self.cursor_key_mode = true;
+                        }
+                        3 => {
+                            // DECCOLM - only side effects
+                            self.scroll_region = None;
+                            self.clear_all(EMPTY_TERMINAL_CHARACTER);
+                            self.cursor.x = 0;
+                            self.cursor.y = 0;
+                        }
+                        6 => {
+                            self.erasure_mode = true;
+                        }
+                        7 => {
+                            self.disable_linewrap = false;
+                        }
+                        1000 => {
+                            self.mouse_tracking = MouseTracking::Normal;
+                        }
+                        1002 => {
+                            self.mouse_tracking = MouseTracking::ButtonEvent;
+                        }
+                        1003 => {
+                            self.mouse_tracking = MouseTracking::AnyEvent;
+                        }
+                        1006 => {
+                            self.sgr_mouse_encoding = true;
+                        }
+                        2004 => {
+                            self.bracketed_paste_mode = true;
+                        }
+                        _ => {}
+                    };
+                }
             } else if let Some(4) = params_iter.next().map(|param| param[0]) {
                 self.insert_mode = true;
             }
@@ -0,0 +1,67 @@
+//! The hyperlinks programs print with OSC 8, which the characters of the grid refer to by id
+//! so that they are emitted again when the characters are rendered.
+
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Link {
+    // the `id=...:key=value` parameters, which let terminals tell links with the same
+    // target apart
+    params: String,
+    uri: String,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct LinkTable {
+    links: HashMap<u16, Link>,
+    ids: HashMap<Link, u16>,
+    next_id: u16,
+    /// The link printed characters are part of
+    pub active: Option<u16>,
+}
+
+impl LinkTable {
+    /// Starts the link of an `OSC 8 ; params ; uri` sequence, or ends the active one when
+    /// the uri is empty
+    pub fn dispatch_osc8(&mut self, params: &[&[u8]]) {
+        if params.len() < 3 {
+            return;
+        }
+        // uris may contain semicolons, which separate the parameters of the sequence
+        let uri = params[2..]
+            .iter()
+            .map(|part| String::from_utf8_lossy(part))
+            .collect::<Vec<_>>()
+            .join(";");
+        if uri.is_empty() {
+            self.active = None;
+            return;
+        }
+        let link = Link {
+            params: String::from_utf8_lossy(params[1]).into(),
+            uri,
+        };
+        let id = match self.ids.get(&link) {
+            Some(&id) => id,
+            None => {
+                let id = self.next_id;
+                self.next_id = self.next_id.wrapping_add(1);
+                // once the ids wrap around, the oldest links are replaced
+                if let Some(replaced_link) = self.links.insert(id, link.clone()) {
+                    self.ids.remove(&replaced_link);
+                }
+                self.ids.insert(link, id);
+                id
+            }
+        };
+        self.active = Some(id);
+    }
+
+    /// The sequence starting the link with `id`, or ending the current one for `None`
+    pub fn output_osc8(&self, id: Option<u16>) -> String {
+        match id.and_then(|id| self.links.get(&id)) {
+            Some(link) => format!("\u{1b}]8;{};{}\u{1b}\\", link.params, link.uri),
+            None => String::from("\u{1b}]8;;\u{1b}\\"),
+        }
+    }
+}
@@ -1,6 +1,9 @@
+mod color_table;
 mod grid;
+mod link_table;
 mod plugin_pane;
 mod selection;
+mod sixel;
 mod terminal_character;
 mod terminal_pane;
 
@@ -1,3 +1,4 @@
+use std::path::PathBuf;
 use std::sync::mpsc::channel;
 use std::time::Instant;
 use std::unimplemented;
@@ -6,10 +7,12 @@ use crate::panes::PaneId;
 use crate::pty::VteBytes;
 use crate::tab::Pane;
 use crate::wasm_vm::PluginInstruction;
+use crate::ClientId;
 use zellij_utils::{channels::SenderWithContext, pane_size::PositionAndSize};
 
 pub(crate) struct PluginPane {
     pub pid: u32,
+    pub location: PathBuf,
     pub should_render: bool,
     pub selectable: bool,
     pub invisible_borders: bool,
@@ -17,16 +20,19 @@ pub(crate) struct PluginPane {
     pub position_and_size_override: Option<PositionAndSize>,
     pub send_plugin_instructions: SenderWithContext<PluginInstruction>,
     pub active_at: Instant,
+    pub name: Option<String>,
 }
 
 impl PluginPane {
     pub fn new(
         pid: u32,
+        location: PathBuf,
         position_and_size: PositionAndSize,
         send_plugin_instructions: SenderWithContext<PluginInstruction>,
     ) -> Self {
         Self {
             pid,
+            location,
             should_render: true,
             selectable: true,
             invisible_borders: false,
@@ -34,6 +40,7 @@ impl PluginPane {
             position_and_size_override: None,
             send_plugin_instructions,
             active_at: Instant::now(),
+            name: None,
         }
     }
 }
@@ -120,7 +127,9 @@ impl Pane for PluginPane {
         self.position_and_size.cols = fixed_width;
         self.position_and_size.cols_fixed = true;
     }
-    fn render(&mut self) -> Option<String> {
+    fn render(&mut self, client_id: Option<ClientId>) -> Option<String> {
+        // every client has its own instance of the plugin, so we can only render for one of them
+        let client_id = client_id?;
         // if self.should_render {
         if true {
             // while checking should_render rather than rendering each pane every time
@@ -133,6 +142,7 @@ impl Pane for PluginPane {
                 .send(PluginInstruction::Render(
                     buf_tx,
                     self.pid,
+                    client_id,
                     self.rows(),
                     self.columns(),
                 ))
@@ -231,4 +241,13 @@ impl Pane for PluginPane {
     fn set_active_at(&mut self, time: Instant) {
         self.active_at = time;
     }
+    fn plugin_location(&self) -> Option<PathBuf> {
+        Some(self.location.clone())
+    }
+    fn name(&self) -> Option<String> {
+        self.name.clone()
+    }
+    fn set_name(&mut self, name: Option<String>) {
+        self.name = name;
+    }
 }
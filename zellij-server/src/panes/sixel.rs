@@ -0,0 +1,269 @@
+//! The sixel images programs draw (`DCS P1 ; P2 ; P3 q data ST`), kept so that they can be
+//! drawn again, cropped to their pane, whenever the lines they cover are rendered.
+
+use std::collections::BTreeMap;
+
+use zellij_utils::pane_size::SizeInPixels;
+
+// images are kept until they are replaced by newer ones, even if the characters they are
+// anchored to were overwritten in the meantime
+const MAX_IMAGES: usize = 64;
+// larger images are dropped instead of piling up in memory
+const MAX_IMAGE_DATA_LENGTH: usize = 16 * 1024 * 1024;
+
+#[derive(Debug, Clone)]
+pub struct SixelImage {
+    params: String,
+    data: Vec<u8>,
+    width: usize,
+    height: usize,
+}
+
+impl SixelImage {
+    pub fn new(params: String, data: Vec<u8>) -> Self {
+        let mut image = SixelImage {
+            params,
+            data,
+            width: 0,
+            height: 0,
+        };
+        let ((width, height), (declared_width, declared_height)) = image.size_in_pixels();
+        image.width = width;
+        image.height = height;
+        // the size the program declared is not bounded by anything, so the image is only as
+        // large as the pixels it draws
+        if declared_width > width || declared_height > height {
+            image.data = image.crop(width, height);
+        }
+        image
+    }
+    /// The rows and columns of character cells of `character_cell_size` the image covers
+    pub fn size_in_cells(&self, character_cell_size: SizeInPixels) -> (usize, usize) {
+        let cells = |pixels: usize, cell: usize| match cell {
+            0 => 0,
+            cell => pixels / cell + usize::from(pixels % cell != 0),
+        };
+        (
+            cells(self.height, character_cell_size.height),
+            cells(self.width, character_cell_size.width),
+        )
+    }
+    /// The sequence drawing the image, cropped to `max_width` and `max_height` pixels so that
+    /// it neither covers the panes next to it nor scrolls the terminal
+    pub fn output(&self, max_width: usize, max_height: usize) -> String {
+        let data = self.cropped_data(max_width, max_height);
+        format!(
+            "\u{1b}P{}q{}\u{1b}\\",
+            self.params,
+            String::from_utf8_lossy(&data)
+        )
+    }
+
+    /// The size of the pixels the image draws, and the size it declares with raster attributes
+    fn size_in_pixels(&self) -> ((usize, usize), (usize, usize)) {
+        let (mut width, mut height) = (0, 0);
+        let (mut declared_width, mut declared_height) = (0, 0);
+        let mut x: usize = 0;
+        let mut band = 0;
+        for command in SixelCommands::new(&self.data) {
+            match command {
+                SixelCommand::Raster(params) => {
+                    declared_width = declared_width.max(params.get(2).copied().unwrap_or(0));
+                    declared_height = declared_height.max(params.get(3).copied().unwrap_or(0));
+                }
+                SixelCommand::Sixels(count, _) => {
+                    x = x.saturating_add(count);
+                    width = width.max(x);
+                    height = height.max((band + 1) * 6);
+                }
+                SixelCommand::CarriageReturn => x = 0,
+                SixelCommand::NewLine => {
+                    x = 0;
+                    band += 1;
+                }
+                SixelCommand::Color(_) => {}
+            }
+        }
+        ((width, height), (declared_width, declared_height))
+    }
+    fn cropped_data(&self, max_width: usize, max_height: usize) -> Vec<u8> {
+        if self.width <= max_width && self.height <= max_height {
+            return self.data.clone();
+        }
+        self.crop(max_width, max_height)
+    }
+    fn crop(&self, max_width: usize, max_height: usize) -> Vec<u8> {
+        let max_bands = max_height / 6;
+        let mut cropped = Vec::with_capacity(self.data.len());
+        let mut x: usize = 0;
+        let mut band = 0;
+        for command in SixelCommands::new(&self.data) {
+            match command {
+                SixelCommand::Raster(params) => {
+                    let width = self.width.min(max_width);
+                    let height = self.height.min(max_bands * 6);
+                    let aspect_ratio = (
+                        params.first().copied().unwrap_or(1),
+                        params.get(1).copied().unwrap_or(1),
+                    );
+                    cropped.extend(
+                        format!(
+                            "\"{};{};{};{}",
+                            aspect_ratio.0, aspect_ratio.1, width, height
+                        )
+                        .bytes(),
+                    );
+                }
+                SixelCommand::Sixels(count, sixel) => {
+                    let visible_count = count.min(max_width.saturating_sub(x));
+                    x = x.saturating_add(count);
+                    match visible_count {
+                        0 => {}
+                        1 => cropped.push(sixel),
+                        _ => {
+                            cropped.extend(format!("!{}", visible_count).bytes());
+                            cropped.push(sixel);
+                        }
+                    }
+                }
+                SixelCommand::CarriageReturn => {
+                    x = 0;
+                    cropped.push(b'$');
+                }
+                SixelCommand::NewLine => {
+                    x = 0;
+                    band += 1;
+                    if band >= max_bands {
+                        break;
+                    }
+                    cropped.push(b'-');
+                }
+                SixelCommand::Color(command) => cropped.extend(command),
+            }
+        }
+        cropped
+    }
+}
+
+enum SixelCommand<'a> {
+    /// `" Pan ; Pad ; Ph ; Pv`: the aspect ratio and size of the image
+    Raster(Vec<usize>),
+    /// A column of six pixels, repeated `count` times with `! count`
+    Sixels(usize, u8),
+    /// `$`: back to the start of the band
+    CarriageReturn,
+    /// `-`: to the start of the next band
+    NewLine,
+    /// `# Pc ...`: selecting or defining a color, kept as is
+    Color(&'a [u8]),
+}
+
+struct SixelCommands<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> SixelCommands<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        SixelCommands { data, position: 0 }
+    }
+    fn params(&mut self) -> Vec<usize> {
+        let start = self.position;
+        while self
+            .data
+            .get(self.position)
+            .map_or(false, |b| b.is_ascii_digit() || *b == b';')
+        {
+            self.position += 1;
+        }
+        String::from_utf8_lossy(&self.data[start..self.position])
+            .split(';')
+            .map(|param| param.parse().unwrap_or(0))
+            .collect()
+    }
+}
+
+impl<'a> Iterator for SixelCommands<'a> {
+    type Item = SixelCommand<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let byte = *self.data.get(self.position)?;
+            self.position += 1;
+            match byte {
+                b'"' => return Some(SixelCommand::Raster(self.params())),
+                b'!' => {
+                    let count = self.params().first().copied().unwrap_or(1).max(1);
+                    let sixel = *self.data.get(self.position)?;
+                    self.position += 1;
+                    if (b'?'..=b'~').contains(&sixel) {
+                        return Some(SixelCommand::Sixels(count, sixel));
+                    }
+                }
+                b'$' => return Some(SixelCommand::CarriageReturn),
+                b'-' => return Some(SixelCommand::NewLine),
+                b'#' => {
+                    let start = self.position - 1;
+                    self.params();
+                    return Some(SixelCommand::Color(&self.data[start..self.position]));
+                }
+                b'?'..=b'~' => return Some(SixelCommand::Sixels(1, byte)),
+                // whitespace and anything else is ignored
+                _ => {}
+            }
+        }
+    }
+}
+
+/// The images of a grid, by the id the characters they are anchored to refer to them with
+#[derive(Debug, Default, Clone)]
+pub struct SixelImageStore {
+    images: BTreeMap<u16, SixelImage>,
+    next_id: u16,
+    // the image being received, as its parameters and data
+    pending: Option<(String, Vec<u8>)>,
+}
+
+impl SixelImageStore {
+    pub fn start_image(&mut self, params: String) {
+        self.pending = Some((params, vec![]));
+    }
+    pub fn add_data(&mut self, byte: u8) {
+        if let Some((_, data)) = self.pending.as_mut() {
+            if data.len() < MAX_IMAGE_DATA_LENGTH {
+                data.push(byte);
+            } else {
+                self.pending = None;
+            }
+        }
+    }
+    /// Stores the image that was received, returning its id
+    pub fn end_image(&mut self) -> Option<u16> {
+        let (params, data) = self.pending.take()?;
+        let image = SixelImage::new(params, data);
+        if image.width == 0 || image.height == 0 {
+            return None;
+        }
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+        self.images.insert(id, image);
+        if self.images.len() > MAX_IMAGES {
+            // ids wrap around, so the oldest image is the first after the newest one
+            let oldest_id = self
+                .images
+                .range(self.next_id..)
+                .chain(self.images.range(..self.next_id))
+                .map(|(&id, _)| id)
+                .next();
+            if let Some(oldest_id) = oldest_id {
+                self.images.remove(&oldest_id);
+            }
+        }
+        Some(id)
+    }
+    pub fn get(&self, id: u16) -> Option<&SixelImage> {
+        self.images.get(&id)
+    }
+    pub fn is_empty(&self) -> bool {
+        self.images.is_empty()
+    }
+}
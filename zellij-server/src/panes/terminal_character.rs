@@ -7,6 +7,8 @@ use zellij_utils::vte::ParamsIter;
 pub const EMPTY_TERMINAL_CHARACTER: TerminalCharacter = TerminalCharacter {
     character: ' ',
     width: 1,
+    link: None,
+    sixel_image: None,
     styles: CharacterStyles {
         foreground: Some(AnsiCode::Reset),
         background: Some(AnsiCode::Reset),
@@ -16,6 +18,7 @@ pub const EMPTY_TERMINAL_CHARACTER: TerminalCharacter = TerminalCharacter {
         slow_blink: Some(AnsiCode::Reset),
         fast_blink: Some(AnsiCode::Reset),
         underline: Some(AnsiCode::Reset),
+        underline_color: Some(AnsiCode::Reset),
         bold: Some(AnsiCode::Reset),
         dim: Some(AnsiCode::Reset),
         italic: Some(AnsiCode::Reset),
@@ -29,6 +32,36 @@ pub enum AnsiCode {
     NamedColor(NamedColor),
     RgbCode((u8, u8, u8)),
     ColorIndex(u8),
+    Underline(UnderlineStyle),
+}
+
+/// The underlines other than the single straight one, set with `SGR 4:2` to `SGR 4:5`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnderlineStyle {
+    Double,
+    Curly,
+    Dotted,
+    Dashed,
+}
+
+impl UnderlineStyle {
+    fn from_sgr_subparam(subparam: u16) -> Option<Self> {
+        match subparam {
+            2 => Some(UnderlineStyle::Double),
+            3 => Some(UnderlineStyle::Curly),
+            4 => Some(UnderlineStyle::Dotted),
+            5 => Some(UnderlineStyle::Dashed),
+            _ => None,
+        }
+    }
+    fn sgr_subparam(self) -> u16 {
+        match self {
+            UnderlineStyle::Double => 2,
+            UnderlineStyle::Curly => 3,
+            UnderlineStyle::Dotted => 4,
+            UnderlineStyle::Dashed => 5,
+        }
+    }
 }
 
 #[derive(Clone, Copy, Eq, PartialEq, Debug)]
@@ -52,6 +85,27 @@ pub enum NamedColor {
 }
 
 impl NamedColor {
+    /// The index of the color in the 256 color palette
+    pub fn index(self) -> u8 {
+        match self {
+            NamedColor::Black => 0,
+            NamedColor::Red => 1,
+            NamedColor::Green => 2,
+            NamedColor::Yellow => 3,
+            NamedColor::Blue => 4,
+            NamedColor::Magenta => 5,
+            NamedColor::Cyan => 6,
+            NamedColor::White => 7,
+            NamedColor::BrightBlack => 8,
+            NamedColor::BrightRed => 9,
+            NamedColor::BrightGreen => 10,
+            NamedColor::BrightYellow => 11,
+            NamedColor::BrightBlue => 12,
+            NamedColor::BrightMagenta => 13,
+            NamedColor::BrightCyan => 14,
+            NamedColor::BrightWhite => 15,
+        }
+    }
     fn to_foreground_ansi_code(self) -> String {
         match self {
             NamedColor::Black => format!("{}", 30),
@@ -104,6 +158,7 @@ pub struct CharacterStyles {
     pub slow_blink: Option<AnsiCode>,
     pub fast_blink: Option<AnsiCode>,
     pub underline: Option<AnsiCode>,
+    pub underline_color: Option<AnsiCode>,
     pub bold: Option<AnsiCode>,
     pub dim: Option<AnsiCode>,
     pub italic: Option<AnsiCode>,
@@ -120,6 +175,7 @@ impl Default for CharacterStyles {
             slow_blink: None,
             fast_blink: None,
             underline: None,
+            underline_color: None,
             bold: None,
             dim: None,
             italic: None,
@@ -155,6 +211,10 @@ impl CharacterStyles {
         self.underline = underline_code;
         self
     }
+    pub fn underline_color(mut self, underline_color_code: Option<AnsiCode>) -> Self {
+        self.underline_color = underline_color_code;
+        self
+    }
     pub fn blink_slow(mut self, slow_blink_code: Option<AnsiCode>) -> Self {
         self.slow_blink = slow_blink_code;
         self
@@ -184,6 +244,7 @@ impl CharacterStyles {
         self.slow_blink = None;
         self.fast_blink = None;
         self.underline = None;
+        self.underline_color = None;
         self.bold = None;
         self.dim = None;
         self.italic = None;
@@ -202,6 +263,7 @@ impl CharacterStyles {
             && new_styles.fast_blink == Some(AnsiCode::Reset)
             && new_styles.slow_blink == Some(AnsiCode::Reset)
             && new_styles.underline == Some(AnsiCode::Reset)
+            && new_styles.underline_color == Some(AnsiCode::Reset)
             && new_styles.bold == Some(AnsiCode::Reset)
             && new_styles.dim == Some(AnsiCode::Reset)
             && new_styles.italic == Some(AnsiCode::Reset)
@@ -214,6 +276,7 @@ impl CharacterStyles {
             self.fast_blink = Some(AnsiCode::Reset);
             self.slow_blink = Some(AnsiCode::Reset);
             self.underline = Some(AnsiCode::Reset);
+            self.underline_color = Some(AnsiCode::Reset);
             self.bold = Some(AnsiCode::Reset);
             self.dim = Some(AnsiCode::Reset);
             self.italic = Some(AnsiCode::Reset);
@@ -292,6 +355,15 @@ impl CharacterStyles {
                 self.underline = new_styles.underline;
             }
         }
+        if self.underline_color != new_styles.underline_color {
+            if let Some(new_diff) = diff.as_mut() {
+                diff = Some(new_diff.underline_color(new_styles.underline_color));
+                self.underline_color = new_styles.underline_color;
+            } else {
+                diff = Some(CharacterStyles::new().underline_color(new_styles.underline_color));
+                self.underline_color = new_styles.underline_color;
+            }
+        }
         if self.bold != new_styles.bold {
             if let Some(new_diff) = diff.as_mut() {
                 diff = Some(new_diff.bold(new_styles.bold));
@@ -319,6 +391,13 @@ impl CharacterStyles {
                 self.italic = new_styles.italic;
             }
         }
+        // resetting bold resets the underline as well, so an underline that stays is set again
+        if let Some(new_diff) = diff.as_mut() {
+            let keeps_underline = !matches!(self.underline, None | Some(AnsiCode::Reset));
+            if new_diff.bold == Some(AnsiCode::Reset) && keeps_underline {
+                new_diff.underline = self.underline;
+            }
+        }
         diff
     }
     pub fn reset_all(&mut self) {
@@ -328,6 +407,7 @@ impl CharacterStyles {
         self.dim = Some(AnsiCode::Reset);
         self.italic = Some(AnsiCode::Reset);
         self.underline = Some(AnsiCode::Reset);
+        self.underline_color = Some(AnsiCode::Reset);
         self.slow_blink = Some(AnsiCode::Reset);
         self.fast_blink = Some(AnsiCode::Reset);
         self.reverse = Some(AnsiCode::Reset);
@@ -341,7 +421,13 @@ impl CharacterStyles {
                 [1] => *self = self.bold(Some(AnsiCode::On)),
                 [2] => *self = self.dim(Some(AnsiCode::On)),
                 [3] => *self = self.italic(Some(AnsiCode::On)),
-                [4] => *self = self.underline(Some(AnsiCode::On)),
+                [4] | [4, 1] => *self = self.underline(Some(AnsiCode::On)),
+                [4, 0] => *self = self.underline(Some(AnsiCode::Reset)),
+                [4, style] => {
+                    if let Some(style) = UnderlineStyle::from_sgr_subparam(*style) {
+                        *self = self.underline(Some(AnsiCode::Underline(style)));
+                    }
+                }
                 [5] => *self = self.blink_slow(Some(AnsiCode::On)),
                 [6] => *self = self.blink_fast(Some(AnsiCode::On)),
                 [7] => *self = self.reverse(Some(AnsiCode::On)),
@@ -407,6 +493,21 @@ impl CharacterStyles {
                     }
                 }
                 [49] => *self = self.background(Some(AnsiCode::Reset)),
+                [58] => {
+                    let mut iter = params.map(|param| param[0]);
+                    if let Some(ansi_code) = parse_sgr_color(&mut iter) {
+                        *self = self.underline_color(Some(ansi_code));
+                    }
+                }
+                [58, params @ ..] => {
+                    let rgb_start = if params.len() > 4 { 2 } else { 1 };
+                    let rgb_iter = params[rgb_start..].iter().copied();
+                    let mut iter = std::iter::once(params[0]).chain(rgb_iter);
+                    if let Some(ansi_code) = parse_sgr_color(&mut iter) {
+                        *self = self.underline_color(Some(ansi_code));
+                    }
+                }
+                [59] => *self = self.underline_color(Some(AnsiCode::Reset)),
                 [90] => {
                     *self = self.foreground(Some(AnsiCode::NamedColor(NamedColor::BrightBlack)))
                 }
@@ -466,6 +567,7 @@ impl Display for CharacterStyles {
             && self.fast_blink == Some(AnsiCode::Reset)
             && self.slow_blink == Some(AnsiCode::Reset)
             && self.underline == Some(AnsiCode::Reset)
+            && self.underline_color == Some(AnsiCode::Reset)
             && self.bold == Some(AnsiCode::Reset)
             && self.dim == Some(AnsiCode::Reset)
             && self.italic == Some(AnsiCode::Reset)
@@ -583,12 +685,34 @@ impl Display for CharacterStyles {
                 AnsiCode::On => {
                     write!(f, "\u{1b}[4m")?;
                 }
+                AnsiCode::Underline(style) => {
+                    write!(f, "\u{1b}[4:{}m", style.sgr_subparam())?;
+                }
                 AnsiCode::Reset => {
                     write!(f, "\u{1b}[24m")?;
                 }
                 _ => {}
             }
         }
+        // with colons, as terminals that do not know underline colors ignore them then,
+        // instead of reading the color as other attributes
+        if let Some(ansi_code) = self.underline_color {
+            match ansi_code {
+                AnsiCode::RgbCode((r, g, b)) => {
+                    write!(f, "\u{1b}[58:2::{}:{}:{}m", r, g, b)?;
+                }
+                AnsiCode::ColorIndex(color_index) => {
+                    write!(f, "\u{1b}[58:5:{}m", color_index)?;
+                }
+                AnsiCode::NamedColor(named_color) => {
+                    write!(f, "\u{1b}[58:5:{}m", named_color.index())?;
+                }
+                AnsiCode::Reset => {
+                    write!(f, "\u{1b}[59m")?;
+                }
+                _ => {}
+            }
+        }
         if let Some(ansi_code) = self.dim {
             match ansi_code {
                 AnsiCode::On => {
@@ -750,6 +874,11 @@ pub struct TerminalCharacter {
     pub character: char,
     pub styles: CharacterStyles,
     pub width: usize,
+    /// The id of the hyperlink the character is part of, in the link table of its grid
+    pub link: Option<u16>,
+    /// The id of the sixel image whose top left corner is on the character, in the image
+    /// store of its grid
+    pub sixel_image: Option<u16>,
 }
 
 impl ::std::fmt::Debug for TerminalCharacter {
@@ -1,22 +1,26 @@
+use zellij_utils::input::mouse::{MouseEvent, MouseModifiers};
 use zellij_utils::position::Position;
 use zellij_utils::zellij_tile::prelude::PaletteColor;
 use zellij_utils::{vte, zellij_tile};
 
+use std::cell::RefCell;
 use std::fmt::Debug;
 use std::os::unix::io::RawFd;
+use std::rc::Rc;
 use std::time::{self, Instant};
 use zellij_tile::data::Palette;
-use zellij_utils::pane_size::PositionAndSize;
+use zellij_utils::pane_size::{PositionAndSize, SizeInPixels};
 
 use crate::panes::AnsiCode;
 use crate::panes::{
-    grid::Grid,
+    grid::{ClipboardRequest, Grid},
     terminal_character::{
         CharacterStyles, CursorShape, TerminalCharacter, EMPTY_TERMINAL_CHARACTER,
     },
 };
 use crate::pty::VteBytes;
 use crate::tab::Pane;
+use crate::ClientId;
 
 pub const SELECTION_SCROLL_INTERVAL_MS: u64 = 10;
 
@@ -34,6 +38,7 @@ pub struct TerminalPane {
     pub position_and_size_override: Option<PositionAndSize>,
     pub active_at: Instant,
     pub colors: Palette,
+    pub name: Option<String>,
     vte_parser: vte::Parser,
     selection_scrolled_at: time::Instant,
 }
@@ -118,6 +123,12 @@ impl Pane for TerminalPane {
                     return "OB".as_bytes().to_vec();
                 }
             }
+            [27, 91, 50, 48, 48, 126] | [27, 91, 50, 48, 49, 126] => {
+                // bracketed paste start and end, sent on their own by the client
+                if !self.grid.bracketed_paste_mode {
+                    return vec![];
+                }
+            }
             _ => {}
         };
         input_bytes
@@ -154,7 +165,7 @@ impl Pane for TerminalPane {
     fn set_invisible_borders(&mut self, _invisible_borders: bool) {
         unimplemented!();
     }
-    fn render(&mut self) -> Option<String> {
+    fn render(&mut self, _client_id: Option<ClientId>) -> Option<String> {
         if self.should_render() {
             let mut vte_output = String::new();
             let mut character_styles = CharacterStyles::new();
@@ -174,7 +185,9 @@ impl Pane for TerminalPane {
                 self.grid.clear_viewport_before_rendering = false;
             }
             let max_width = self.columns();
-            for character_chunk in self.grid.read_changes() {
+            let character_chunks = self.grid.read_changes();
+            let changed_lines: Vec<usize> = character_chunks.iter().map(|chunk| chunk.y).collect();
+            for character_chunk in character_chunks {
                 let pane_x = self.get_x();
                 let pane_y = self.get_y();
                 let chunk_absolute_x = pane_x + character_chunk.x;
@@ -187,7 +200,9 @@ impl Pane for TerminalPane {
                 )); // goto row/col and reset styles
 
                 let mut chunk_width = character_chunk.x;
+                let mut link = None;
                 for mut t_character in terminal_characters {
+                    t_character.styles = self.grid.color_table.apply(t_character.styles);
                     // adjust the background of currently selected characters
                     // doing it here is much easier than in grid
                     if self.grid.selection.contains(character_chunk.y, chunk_width) {
@@ -208,10 +223,26 @@ impl Pane for TerminalPane {
                     {
                         vte_output.push_str(&new_styles.to_string());
                     }
+                    if t_character.link != link {
+                        link = t_character.link;
+                        vte_output.push_str(&self.grid.link_table.output_osc8(link));
+                    }
                     vte_output.push(t_character.character);
                 }
+                if link.is_some() {
+                    vte_output.push_str(&self.grid.link_table.output_osc8(None));
+                }
                 character_styles.clear();
             }
+            // images are drawn over the text of the lines they cover
+            for (x, y, image) in self.grid.sixel_images_to_render(&changed_lines) {
+                vte_output.push_str(&format!(
+                    "\u{1b}[{};{}H{}",
+                    self.get_y() + y + 1,
+                    self.get_x() + x + 1,
+                    image
+                ));
+            }
             self.set_should_render(false);
             Some(vte_output)
         } else {
@@ -302,6 +333,9 @@ impl Pane for TerminalPane {
     fn drain_messages_to_pty(&mut self) -> Vec<Vec<u8>> {
         self.grid.pending_messages_to_pty.drain(..).collect()
     }
+    fn drain_clipboard_requests(&mut self) -> Vec<ClipboardRequest> {
+        self.grid.pending_clipboard_requests.drain(..).collect()
+    }
 
     fn start_selection(&mut self, start: &Position) {
         self.grid.start_selection(start);
@@ -337,11 +371,37 @@ impl Pane for TerminalPane {
     fn get_selected_text(&self) -> Option<String> {
         self.grid.get_selected_text()
     }
+
+    fn last_lines(&self, count: usize) -> Vec<String> {
+        self.grid.last_lines(count)
+    }
+    fn name(&self) -> Option<String> {
+        self.name.clone()
+    }
+    fn set_name(&mut self, name: Option<String>) {
+        self.name = name;
+    }
+    fn title(&self) -> Option<String> {
+        self.grid.title.clone()
+    }
+    fn mouse_event_report(
+        &mut self,
+        event: &MouseEvent,
+        modifiers: MouseModifiers,
+    ) -> Option<Vec<u8>> {
+        self.grid.mouse_event_report(event, modifiers)
+    }
 }
 
 impl TerminalPane {
-    pub fn new(pid: RawFd, position_and_size: PositionAndSize, palette: Palette) -> TerminalPane {
-        let grid = Grid::new(position_and_size.rows, position_and_size.cols, palette);
+    pub fn new(
+        pid: RawFd,
+        position_and_size: PositionAndSize,
+        palette: Palette,
+        character_cell_size: Rc<RefCell<Option<SizeInPixels>>>,
+    ) -> TerminalPane {
+        let mut grid = Grid::new(position_and_size.rows, position_and_size.cols, palette);
+        grid.character_cell_size = character_cell_size;
         TerminalPane {
             pid,
             grid,
@@ -351,6 +411,7 @@ impl TerminalPane {
             vte_parser: vte::Parser::new(),
             active_at: Instant::now(),
             colors: palette,
+            name: None,
             selection_scrolled_at: time::Instant::now(),
         }
     }
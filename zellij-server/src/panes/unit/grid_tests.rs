@@ -1,6 +1,12 @@
-use super::super::Grid;
+use super::super::{ClipboardRequest, Grid};
 use ::insta::assert_snapshot;
-use zellij_utils::{position::Position, vte, zellij_tile::data::Palette};
+use zellij_utils::{
+    input::mouse::{MouseButton, MouseEvent, MouseModifiers},
+    pane_size::SizeInPixels,
+    position::Position,
+    vte,
+    zellij_tile::data::Palette,
+};
 
 fn read_fixture(fixture_name: &str) -> Vec<u8> {
     let mut path_to_file = std::path::PathBuf::new();
@@ -907,3 +913,278 @@ pub fn exa_plus_omf_theme() {
     }
     assert_snapshot!(format!("{:?}", grid));
 }
+
+#[test]
+pub fn last_lines_include_scrollback_of_primary_screen() {
+    let mut vte_parser = vte::Parser::new();
+    let mut grid = Grid::new(3, 20, Palette::default());
+    let content = "one\r\ntwo\r\nthree\r\nfour\r\nfive   \r\n";
+    for byte in content.as_bytes() {
+        vte_parser.advance(&mut grid, *byte);
+    }
+    assert_eq!(grid.last_lines(3), vec!["three", "four", "five"]);
+    assert_eq!(
+        grid.last_lines(10).len(),
+        5,
+        "Lines above the viewport included"
+    );
+    for byte in "a line wider than the grid\r\n".as_bytes() {
+        vte_parser.advance(&mut grid, *byte);
+    }
+    assert_eq!(
+        grid.last_lines(1),
+        vec!["a line wider than the grid"],
+        "Wrapped line joined"
+    );
+    // switch to the alternate screen, as full screen applications do
+    for byte in "\u{1b}[?1049hvim".as_bytes() {
+        vte_parser.advance(&mut grid, *byte);
+    }
+    assert_eq!(
+        grid.last_lines(2),
+        vec!["five", "a line wider than the grid"],
+        "Alternate screen is left out"
+    );
+}
+
+#[test]
+pub fn window_title_is_kept_until_reset() {
+    let mut vte_parser = vte::Parser::new();
+    let mut grid = Grid::new(3, 20, Palette::default());
+    for byte in "\u{1b}]2;vim main.rs\u{7}".as_bytes() {
+        vte_parser.advance(&mut grid, *byte);
+    }
+    assert_eq!(grid.title.as_deref(), Some("vim main.rs"));
+    for byte in "\u{1b}]0;cargo; build\u{1b}\\".as_bytes() {
+        vte_parser.advance(&mut grid, *byte);
+    }
+    assert_eq!(
+        grid.title.as_deref(),
+        Some("cargo; build"),
+        "Semicolons are part of the title"
+    );
+    for byte in "\u{1b}]2;\u{7}".as_bytes() {
+        vte_parser.advance(&mut grid, *byte);
+    }
+    assert_eq!(grid.title, None, "Empty title resets it");
+}
+
+#[test]
+pub fn mouse_events_are_reported_in_the_requested_encoding() {
+    let mut vte_parser = vte::Parser::new();
+    let mut grid = Grid::new(10, 20, Palette::default());
+    let press = MouseEvent::Press(MouseButton::Left, Position::new(2, 4));
+    let hold = MouseEvent::Hold(Position::new(3, 30));
+    let release = MouseEvent::Release(Position::new(3, 30));
+    let modifiers = MouseModifiers::default();
+    assert_eq!(
+        grid.mouse_event_report(&press, modifiers),
+        None,
+        "Not tracking the mouse"
+    );
+
+    for byte in "\u{1b}[?1000h".as_bytes() {
+        vte_parser.advance(&mut grid, *byte);
+    }
+    assert_eq!(
+        grid.mouse_event_report(&press, modifiers),
+        Some(b"\x1b[M %#".to_vec())
+    );
+    assert_eq!(
+        grid.mouse_event_report(&hold, modifiers),
+        None,
+        "Motion is not reported in normal tracking mode"
+    );
+    assert_eq!(
+        grid.mouse_event_report(&release, modifiers),
+        Some(b"\x1b[M#4$".to_vec())
+    );
+
+    for byte in "\u{1b}[?1002;1006h".as_bytes() {
+        vte_parser.advance(&mut grid, *byte);
+    }
+    let ctrl = MouseModifiers {
+        ctrl: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        grid.mouse_event_report(&press, ctrl),
+        Some(b"\x1b[<16;5;3M".to_vec())
+    );
+    assert_eq!(
+        grid.mouse_event_report(&hold, modifiers),
+        Some(b"\x1b[<32;20;4M".to_vec()),
+        "Drags out of the pane are reported at its edge"
+    );
+    assert_eq!(
+        grid.mouse_event_report(&hold, modifiers),
+        None,
+        "Repeated holds are not reported"
+    );
+    assert_eq!(
+        grid.mouse_event_report(&release, modifiers),
+        Some(b"\x1b[<0;20;4m".to_vec())
+    );
+
+    for byte in "\u{1b}[?1002l".as_bytes() {
+        vte_parser.advance(&mut grid, *byte);
+    }
+    assert_eq!(grid.mouse_event_report(&press, modifiers), None);
+}
+
+#[test]
+pub fn colors_can_be_redefined_queried_and_reset() {
+    let mut vte_parser = vte::Parser::new();
+    let mut grid = Grid::new(10, 20, Palette::default());
+    let mut send = |grid: &mut Grid, bytes: &str| {
+        for byte in bytes.as_bytes() {
+            vte_parser.advance(grid, *byte);
+        }
+        std::mem::take(&mut grid.pending_messages_to_pty)
+            .into_iter()
+            .map(|message| String::from_utf8(message).unwrap())
+            .collect::<Vec<String>>()
+    };
+    assert_eq!(
+        send(&mut grid, "\u{1b}]4;1;?\u{7}"),
+        vec!["\u{1b}]4;1;rgb:cdcd/0000/0000\u{7}"],
+        "Default color of the index"
+    );
+    send(&mut grid, "\u{1b}]4;1;#ff8000;196;rgb:0/8/f\u{1b}\\");
+    assert_eq!(
+        send(&mut grid, "\u{1b}]4;1;?;196;?\u{7}"),
+        vec![
+            "\u{1b}]4;1;rgb:ffff/8080/0000\u{7}",
+            "\u{1b}]4;196;rgb:0000/8888/ffff\u{7}"
+        ]
+    );
+    send(&mut grid, "\u{1b}]10;#102030\u{7}");
+    assert_eq!(
+        send(&mut grid, "\u{1b}]10;?;?\u{7}"),
+        vec![
+            "\u{1b}]10;rgb:1010/2020/3030\u{7}",
+            "\u{1b}]11;rgb:0000/0000/0000\u{7}"
+        ],
+        "Each parameter is the next color"
+    );
+    assert_eq!(
+        send(&mut grid, "\u{1b}]12;?\u{7}"),
+        vec!["\u{1b}]12;rgb:1010/2020/3030\u{7}"],
+        "Cursor has the foreground color by default"
+    );
+    send(&mut grid, "\u{1b}]104;1\u{7}\u{1b}]110\u{7}");
+    assert_eq!(
+        send(&mut grid, "\u{1b}]4;1;?;196;?\u{7}\u{1b}]10;?\u{7}"),
+        vec![
+            "\u{1b}]4;1;rgb:cdcd/0000/0000\u{7}",
+            "\u{1b}]4;196;rgb:0000/8888/ffff\u{7}",
+            "\u{1b}]10;rgb:e5e5/e5e5/e5e5\u{7}"
+        ]
+    );
+}
+
+#[test]
+pub fn clipboard_requests_are_kept_for_the_tab() {
+    let mut vte_parser = vte::Parser::new();
+    let mut grid = Grid::new(3, 20, Palette::default());
+    for byte in
+        "\u{1b}]52;c;eWFuaw==\u{7}\u{1b}]52;;?\u{1b}\\\u{1b}]52;c;not base64\u{7}".as_bytes()
+    {
+        vte_parser.advance(&mut grid, *byte);
+    }
+    assert_eq!(
+        grid.pending_clipboard_requests,
+        vec![
+            ClipboardRequest::Copy {
+                selection: "c".into(),
+                base64_text: "eWFuaw==".into(),
+            },
+            ClipboardRequest::Paste {
+                selection: "c".into(),
+                terminator: "\u{1b}\\".into(),
+            },
+        ],
+        "The invalid copy is dropped"
+    );
+}
+
+#[test]
+pub fn sixel_images_are_anchored_to_cells_and_cropped_to_the_pane() {
+    let image = "\u{1b}P0;1q\"1;1;20;24#0;2;100;0;0#0!20~-!20~-!20~-!20~\u{1b}\\";
+    let character_cell_size = Some(SizeInPixels {
+        width: 10,
+        height: 20,
+    });
+
+    let mut vte_parser = vte::Parser::new();
+    let mut grid = Grid::new(5, 10, Palette::default());
+    *grid.character_cell_size.borrow_mut() = character_cell_size;
+    for byte in format!("ab{}", image).as_bytes() {
+        vte_parser.advance(&mut grid, *byte);
+    }
+    assert_eq!(
+        grid.cursor_coordinates(),
+        Some((2, 2)),
+        "The cursor is below the image"
+    );
+    assert_eq!(
+        grid.sixel_images_to_render(&[1]),
+        vec![(2, 0, image.to_string())],
+        "The image is drawn again when a line it covers changes"
+    );
+    assert_eq!(grid.sixel_images_to_render(&[2]), vec![]);
+
+    let mut vte_parser = vte::Parser::new();
+    let mut grid = Grid::new(5, 3, Palette::default());
+    *grid.character_cell_size.borrow_mut() = character_cell_size;
+    for byte in format!("ab{}", image).as_bytes() {
+        vte_parser.advance(&mut grid, *byte);
+    }
+    assert_eq!(
+        grid.sixel_images_to_render(&[0]),
+        vec![(
+            2,
+            0,
+            "\u{1b}P0;1q\"1;1;10;24#0;2;100;0;0#0!10~-!10~-!10~-!10~\u{1b}\\".to_string()
+        )],
+        "The image is cropped to the width of the pane"
+    );
+}
+
+#[test]
+pub fn sixel_images_are_dropped_with_their_anchor() {
+    let mut vte_parser = vte::Parser::new();
+    let mut grid = Grid::new(5, 10, Palette::default());
+    *grid.character_cell_size.borrow_mut() = Some(SizeInPixels {
+        width: 10,
+        height: 20,
+    });
+    for byte in "\u{1b}P0;1q#0!20~-!20~\u{1b}\\\u{1b}[2J".as_bytes() {
+        vte_parser.advance(&mut grid, *byte);
+    }
+    assert_eq!(grid.sixel_images_to_render(&[0]), vec![]);
+}
+
+#[test]
+pub fn sixel_images_are_only_as_large_as_the_pixels_they_draw() {
+    let mut vte_parser = vte::Parser::new();
+    let mut grid = Grid::new(5, 10, Palette::default());
+    *grid.character_cell_size.borrow_mut() = Some(SizeInPixels {
+        width: 10,
+        height: 20,
+    });
+    let image = "\u{1b}P0;1q\"1;1;99999999999;99999999999#0!18446744073709551615~~\u{1b}\\";
+    for byte in image.as_bytes() {
+        vte_parser.advance(&mut grid, *byte);
+    }
+    assert_eq!(
+        grid.cursor_coordinates(),
+        Some((0, 1)),
+        "The cursor is below the pixels the image draws"
+    );
+    assert_eq!(
+        grid.sixel_images_to_render(&[0]),
+        vec![(0, 0, "\u{1b}P0;1q\"1;1;100;6#0!100~\u{1b}\\".to_string())],
+        "The declared size is replaced by the one of the pixels drawn"
+    );
+}
@@ -1,6 +1,8 @@
 use super::super::TerminalPane;
 use crate::tab::Pane;
 use ::insta::assert_snapshot;
+use std::cell::RefCell;
+use std::rc::Rc;
 use zellij_utils::pane_size::PositionAndSize;
 use zellij_utils::zellij_tile::data::Palette;
 
@@ -15,7 +17,8 @@ pub fn scrolling_inside_a_pane() {
     };
     let pid = 1;
     let palette = Palette::default();
-    let mut terminal_pane = TerminalPane::new(pid, fake_win_size, palette);
+    let mut terminal_pane =
+        TerminalPane::new(pid, fake_win_size, palette, Rc::new(RefCell::new(None)));
     let mut text_to_fill_pane = String::new();
     for i in 0..30 {
         text_to_fill_pane.push_str(&format!("\rline {}\n", i + 1));
@@ -28,3 +31,122 @@ pub fn scrolling_inside_a_pane() {
     terminal_pane.clear_scroll();
     assert_snapshot!(format!("{:?}", terminal_pane.grid));
 }
+
+#[test]
+pub fn paste_markers_reach_only_panes_in_bracketed_paste_mode() {
+    let fake_win_size = PositionAndSize {
+        cols: 121,
+        rows: 20,
+        x: 0,
+        y: 0,
+        ..Default::default()
+    };
+    let pid = 1;
+    let palette = Palette::default();
+    let mut terminal_pane =
+        TerminalPane::new(pid, fake_win_size, palette, Rc::new(RefCell::new(None)));
+    let paste_start = b"\x1b[200~".to_vec();
+    let paste_end = b"\x1b[201~".to_vec();
+    assert!(terminal_pane
+        .adjust_input_to_terminal(paste_start.clone())
+        .is_empty());
+    terminal_pane.handle_pty_bytes(b"\x1b[?2004h".to_vec());
+    assert_eq!(
+        terminal_pane.adjust_input_to_terminal(paste_start.clone()),
+        paste_start
+    );
+    assert_eq!(
+        terminal_pane.adjust_input_to_terminal(paste_end.clone()),
+        paste_end
+    );
+    terminal_pane.handle_pty_bytes(b"\x1b[?2004l".to_vec());
+    assert!(terminal_pane.adjust_input_to_terminal(paste_end).is_empty());
+}
+
+#[test]
+pub fn redefined_colors_are_rendered() {
+    let fake_win_size = PositionAndSize {
+        cols: 10,
+        rows: 2,
+        x: 0,
+        y: 0,
+        ..Default::default()
+    };
+    let pid = 1;
+    let palette = Palette::default();
+    let mut terminal_pane =
+        TerminalPane::new(pid, fake_win_size, palette, Rc::new(RefCell::new(None)));
+    terminal_pane.handle_pty_bytes(b"\x1b]4;1;#ff8000\x07\x1b]11;#000010\x07\x1b[31mred".to_vec());
+    let output = terminal_pane.render(None).unwrap();
+    assert!(
+        output.contains("\u{1b}[38;2;255;128;0m\u{1b}[48;2;0;0;16mred"),
+        "Red is rendered as redefined, on the redefined background: {:?}",
+        output
+    );
+}
+
+#[test]
+pub fn hyperlinks_are_rendered() {
+    let fake_win_size = PositionAndSize {
+        cols: 20,
+        rows: 2,
+        x: 0,
+        y: 0,
+        ..Default::default()
+    };
+    let pid = 1;
+    let palette = Palette::default();
+    let mut terminal_pane =
+        TerminalPane::new(pid, fake_win_size, palette, Rc::new(RefCell::new(None)));
+    terminal_pane.handle_pty_bytes(
+        b"see \x1b]8;id=1;file:///a;b\x1b\\a;b\x1b]8;;\x1b\\ and \x1b]8;;https://c\x07cccccccc"
+            .to_vec(),
+    );
+    let output = terminal_pane.render(None).unwrap();
+    assert!(
+        output.contains("see \u{1b}]8;id=1;file:///a;b\u{1b}\\a;b\u{1b}]8;;\u{1b}\\ and "),
+        "The link is emitted around its text: {:?}",
+        output
+    );
+    let second_link = output.find("\u{1b}]8;;https://c\u{1b}\\c").unwrap();
+    let second_line = output.find("\u{1b}[2;1H").unwrap();
+    assert!(
+        output[second_link..second_line].ends_with("c\u{1b}]8;;\u{1b}\\"),
+        "A link reaching the end of the line is ended with it: {:?}",
+        output
+    );
+}
+
+#[test]
+pub fn styled_and_colored_underlines_are_rendered() {
+    let fake_win_size = PositionAndSize {
+        cols: 20,
+        rows: 2,
+        x: 0,
+        y: 0,
+        ..Default::default()
+    };
+    let pid = 1;
+    let palette = Palette::default();
+    let mut terminal_pane =
+        TerminalPane::new(pid, fake_win_size, palette, Rc::new(RefCell::new(None)));
+    terminal_pane.handle_pty_bytes(
+        b"\x1b[1;4:3;58;2;255;0;0mab\x1b[22mc\x1b[58:5:4md\x1b[4:0;59me".to_vec(),
+    );
+    let output = terminal_pane.render(None).unwrap();
+    assert!(
+        output.contains("\u{1b}[1m\u{1b}[4:3m\u{1b}[58:2::255:0:0mab"),
+        "The curly red underline is rendered: {:?}",
+        output
+    );
+    assert!(
+        output.contains("\u{1b}[22m\u{1b}[24m\u{1b}[4:3m\u{1b}[22mc"),
+        "The underline is kept when bold is reset: {:?}",
+        output
+    );
+    assert!(
+        output.contains("\u{1b}[58:5:4md\u{1b}[24m\u{1b}[59me"),
+        "The underline color changes, then both are reset: {:?}",
+        output
+    );
+}
@@ -4,7 +4,7 @@ use crate::{
     screen::ScreenInstruction,
     thread_bus::{Bus, ThreadSenders},
     wasm_vm::PluginInstruction,
-    ServerInstruction,
+    ClientId, ServerInstruction, SessionInfoRequester,
 };
 use async_std::{
     future::timeout as async_timeout,
@@ -12,17 +12,21 @@ use async_std::{
 };
 use std::{
     collections::HashMap,
+    env, mem,
     os::unix::io::RawFd,
+    path::PathBuf,
     time::{Duration, Instant},
 };
 use zellij_utils::{
     async_std,
     errors::{get_current_ctx, ContextType, PtyContext},
     input::{
-        command::TerminalAction,
+        command::{RunCommand, TerminalAction},
         layout::{Layout, Run},
     },
+    ipc::SessionInfo,
     logging::debug_to_file,
+    resurrection::TabSnapshot,
 };
 
 pub type VteBytes = Vec<u8>;
@@ -30,24 +34,31 @@ pub type VteBytes = Vec<u8>;
 /// Instructions related to PTYs (pseudoterminals).
 #[derive(Clone, Debug)]
 pub(crate) enum PtyInstruction {
-    SpawnTerminal(Option<TerminalAction>),
-    SpawnTerminalVertically(Option<TerminalAction>),
-    SpawnTerminalHorizontally(Option<TerminalAction>),
-    NewTab(Option<TerminalAction>),
+    SpawnTerminal(Option<TerminalAction>, ClientId),
+    SpawnTerminalVertically(Option<TerminalAction>, ClientId),
+    SpawnTerminalHorizontally(Option<TerminalAction>, ClientId),
+    NewTab(Option<TerminalAction>, ClientId),
+    /// Opens the panes of the layout in a new tab, named after the layout
+    NewTabWithLayout(Box<Layout>, String, Option<TerminalAction>, ClientId),
     ClosePane(PaneId),
     CloseTab(Vec<PaneId>),
+    FillSessionInfo(Box<SessionInfo>, SessionInfoRequester),
+    ResurrectTab(Box<TabSnapshot>, ClientId),
     Exit,
 }
 
 impl From<&PtyInstruction> for PtyContext {
     fn from(pty_instruction: &PtyInstruction) -> Self {
         match *pty_instruction {
-            PtyInstruction::SpawnTerminal(_) => PtyContext::SpawnTerminal,
-            PtyInstruction::SpawnTerminalVertically(_) => PtyContext::SpawnTerminalVertically,
-            PtyInstruction::SpawnTerminalHorizontally(_) => PtyContext::SpawnTerminalHorizontally,
+            PtyInstruction::SpawnTerminal(..) => PtyContext::SpawnTerminal,
+            PtyInstruction::SpawnTerminalVertically(..) => PtyContext::SpawnTerminalVertically,
+            PtyInstruction::SpawnTerminalHorizontally(..) => PtyContext::SpawnTerminalHorizontally,
             PtyInstruction::ClosePane(_) => PtyContext::ClosePane,
             PtyInstruction::CloseTab(_) => PtyContext::CloseTab,
-            PtyInstruction::NewTab(_) => PtyContext::NewTab,
+            PtyInstruction::NewTab(..) => PtyContext::NewTab,
+            PtyInstruction::NewTabWithLayout(..) => PtyContext::NewTabWithLayout,
+            PtyInstruction::FillSessionInfo(..) => PtyContext::FillSessionInfo,
+            PtyInstruction::ResurrectTab(..) => PtyContext::ResurrectTab,
             PtyInstruction::Exit => PtyContext::Exit,
         }
     }
@@ -56,47 +67,77 @@ impl From<&PtyInstruction> for PtyContext {
 pub(crate) struct Pty {
     pub bus: Bus<PtyInstruction>,
     pub id_to_child_pid: HashMap<RawFd, Pid>,
+    // what each terminal was started with, to be able to start it again on resurrection
+    id_to_terminal_action: HashMap<RawFd, Option<TerminalAction>>,
     debug_to_file: bool,
     task_handles: HashMap<RawFd, JoinHandle<()>>,
 }
 
-pub(crate) fn pty_thread_main(mut pty: Pty, maybe_layout: Option<Layout>) {
+pub(crate) fn pty_thread_main(mut pty: Pty, mut maybe_layout: Option<Layout>) {
+    // the tabs of the layout are opened in place of the first tab of the session
+    let mut layout_tabs = maybe_layout
+        .as_mut()
+        .map(|layout| mem::take(&mut layout.tabs))
+        .unwrap_or_default();
     loop {
         let (event, mut err_ctx) = pty.bus.recv().expect("failed to receive event on channel");
         err_ctx.add_call(ContextType::Pty((&event).into()));
         match event {
-            PtyInstruction::SpawnTerminal(terminal_action) => {
+            PtyInstruction::SpawnTerminal(terminal_action, client_id) => {
                 let pid = pty.spawn_terminal(terminal_action);
                 pty.bus
                     .senders
-                    .send_to_screen(ScreenInstruction::NewPane(PaneId::Terminal(pid)))
+                    .send_to_screen(ScreenInstruction::NewPane(PaneId::Terminal(pid), client_id))
                     .unwrap();
             }
-            PtyInstruction::SpawnTerminalVertically(terminal_action) => {
+            PtyInstruction::SpawnTerminalVertically(terminal_action, client_id) => {
                 let pid = pty.spawn_terminal(terminal_action);
                 pty.bus
                     .senders
-                    .send_to_screen(ScreenInstruction::VerticalSplit(PaneId::Terminal(pid)))
+                    .send_to_screen(ScreenInstruction::VerticalSplit(
+                        PaneId::Terminal(pid),
+                        client_id,
+                    ))
                     .unwrap();
             }
-            PtyInstruction::SpawnTerminalHorizontally(terminal_action) => {
+            PtyInstruction::SpawnTerminalHorizontally(terminal_action, client_id) => {
                 let pid = pty.spawn_terminal(terminal_action);
                 pty.bus
                     .senders
-                    .send_to_screen(ScreenInstruction::HorizontalSplit(PaneId::Terminal(pid)))
+                    .send_to_screen(ScreenInstruction::HorizontalSplit(
+                        PaneId::Terminal(pid),
+                        client_id,
+                    ))
                     .unwrap();
             }
-            PtyInstruction::NewTab(terminal_action) => {
-                if let Some(layout) = maybe_layout.clone() {
-                    pty.spawn_terminals_for_layout(layout, terminal_action);
+            PtyInstruction::NewTab(terminal_action, client_id) => {
+                if !layout_tabs.is_empty() {
+                    let tabs = mem::take(&mut layout_tabs);
+                    pty.spawn_tabs_for_layout(tabs, terminal_action, client_id);
+                } else if let Some(layout) = maybe_layout.clone() {
+                    pty.spawn_terminals_for_layout(layout, terminal_action, client_id);
                 } else {
                     let pid = pty.spawn_terminal(terminal_action);
                     pty.bus
                         .senders
-                        .send_to_screen(ScreenInstruction::NewTab(pid))
+                        .send_to_screen(ScreenInstruction::NewTab(pid, client_id))
                         .unwrap();
                 }
             }
+            PtyInstruction::NewTabWithLayout(mut layout, tab_name, terminal_action, client_id) => {
+                if layout.tabs.is_empty() {
+                    pty.spawn_terminals_for_tab(
+                        *layout,
+                        Some(tab_name),
+                        terminal_action,
+                        vec![],
+                        client_id,
+                    );
+                } else {
+                    let tabs = mem::take(&mut layout.tabs);
+                    pty.spawn_tabs_for_layout(tabs, terminal_action, client_id);
+                }
+            }
             PtyInstruction::ClosePane(id) => {
                 pty.close_pane(id);
                 pty.bus
@@ -111,6 +152,16 @@ pub(crate) fn pty_thread_main(mut pty: Pty, maybe_layout: Option<Layout>) {
                     .send_to_server(ServerInstruction::UnblockInputThread)
                     .unwrap();
             }
+            PtyInstruction::FillSessionInfo(mut session_info, requester) => {
+                pty.fill_process_info(&mut session_info);
+                pty.bus
+                    .senders
+                    .send_to_server(ServerInstruction::SessionInfo(*session_info, requester))
+                    .unwrap();
+            }
+            PtyInstruction::ResurrectTab(tab_snapshot, client_id) => {
+                pty.resurrect_tab(*tab_snapshot, client_id);
+            }
             PtyInstruction::Exit => break,
         }
     }
@@ -217,6 +268,7 @@ impl Pty {
         Pty {
             bus,
             id_to_child_pid: HashMap::new(),
+            id_to_terminal_action: HashMap::new(),
             debug_to_file,
             task_handles: HashMap::new(),
         }
@@ -227,7 +279,9 @@ impl Pty {
             .os_input
             .as_mut()
             .unwrap()
-            .spawn_terminal(terminal_action);
+            .spawn_terminal(terminal_action.clone());
+        self.id_to_terminal_action
+            .insert(pid_primary, terminal_action);
         let task_handle = stream_terminal_bytes(
             pid_primary,
             self.bus.senders.clone(),
@@ -242,43 +296,102 @@ impl Pty {
         &mut self,
         layout: Layout,
         default_shell: Option<TerminalAction>,
+        client_id: ClientId,
+    ) {
+        self.spawn_terminals_for_tab(layout, None, default_shell, vec![], client_id);
+    }
+    /// Opens a tab for each of `tabs`, then switches the client to the one marked
+    /// as focused, or to the first one.
+    pub fn spawn_tabs_for_layout(
+        &mut self,
+        tabs: Vec<Layout>,
+        default_shell: Option<TerminalAction>,
+        client_id: ClientId,
+    ) {
+        let focused_tab = tabs.iter().position(|tab| tab.focus).unwrap_or(0);
+        let tabs_after_focused = tabs.len().saturating_sub(focused_tab + 1);
+        for mut tab in tabs {
+            // the name and focus of a tab are not those of its pane when it has no parts
+            let tab_name = tab.name.take();
+            tab.focus = false;
+            self.spawn_terminals_for_tab(tab, tab_name, default_shell.clone(), vec![], client_id);
+        }
+        // the client is on the last tab opened, and other tabs may come before the first
+        for _ in 0..tabs_after_focused {
+            self.bus
+                .senders
+                .send_to_screen(ScreenInstruction::SwitchTabPrev(client_id))
+                .unwrap();
+        }
+    }
+    /// Opens a new tab from the snapshot of a tab of a session that is gone, showing the
+    /// lines its panes displayed before the output of their respawned commands.
+    pub fn resurrect_tab(&mut self, tab_snapshot: TabSnapshot, client_id: ClientId) {
+        let tab_name = Some(tab_snapshot.name).filter(|name| !name.is_empty());
+        self.spawn_terminals_for_tab(
+            tab_snapshot.layout,
+            tab_name,
+            None,
+            tab_snapshot.scrollback,
+            client_id,
+        );
+    }
+    /// Spawns the terminals of `layout` and opens it in a new tab, where `scrollback` holds the
+    /// lines to display in each pane first, in the order of [`Layout::extract_run_instructions`].
+    fn spawn_terminals_for_tab(
+        &mut self,
+        layout: Layout,
+        tab_name: Option<String>,
+        default_shell: Option<TerminalAction>,
+        scrollback: Vec<Vec<String>>,
+        client_id: ClientId,
     ) {
-        let extracted_run_instructions = layout.extract_run_instructions();
+        let mut scrollback = scrollback.into_iter();
         let mut new_pane_pids = vec![];
-        for run_instruction in extracted_run_instructions {
-            match run_instruction {
-                Some(Run::Command(command)) => {
-                    let cmd = TerminalAction::RunCommand(command);
-                    let (pid_primary, pid_secondary): (RawFd, Pid) = self
-                        .bus
-                        .os_input
-                        .as_mut()
-                        .unwrap()
-                        .spawn_terminal(Some(cmd));
-                    self.id_to_child_pid.insert(pid_primary, pid_secondary);
-                    new_pane_pids.push(pid_primary);
-                }
-                None => {
-                    let (pid_primary, pid_secondary): (RawFd, Pid) = self
-                        .bus
-                        .os_input
-                        .as_mut()
-                        .unwrap()
-                        .spawn_terminal(default_shell.clone());
-                    self.id_to_child_pid.insert(pid_primary, pid_secondary);
-                    new_pane_pids.push(pid_primary);
+        let mut new_pane_scrollback = vec![];
+        for pane in layout.extract_panes() {
+            let pane_scrollback = scrollback.next().unwrap_or_default();
+            let terminal_action = match &pane.run {
+                Some(Run::Command(command)) => Some(TerminalAction::RunCommand(command.clone())),
+                None if pane.cwd.is_some() || !pane.env.is_empty() => {
+                    Some(shell_in_pane_environment(&default_shell, pane))
                 }
+                None => default_shell.clone(),
                 // Investigate moving plugin loading to here.
-                Some(Run::Plugin(_)) => {}
-            }
+                Some(Run::Plugin(_)) => continue,
+            };
+            let (pid_primary, pid_secondary): (RawFd, Pid) = self
+                .bus
+                .os_input
+                .as_mut()
+                .unwrap()
+                .spawn_terminal(terminal_action.clone());
+            self.id_to_child_pid.insert(pid_primary, pid_secondary);
+            self.id_to_terminal_action
+                .insert(pid_primary, terminal_action);
+            new_pane_pids.push(pid_primary);
+            new_pane_scrollback.push(pane_scrollback);
         }
         self.bus
             .senders
             .send_to_screen(ScreenInstruction::ApplyLayout(
                 layout,
                 new_pane_pids.clone(),
+                tab_name,
+                client_id,
             ))
             .unwrap();
+        for (&id, lines) in new_pane_pids.iter().zip(new_pane_scrollback) {
+            // sent before the pane's output starts streaming, so that it comes first
+            if !lines.is_empty() {
+                let mut bytes = lines.join("\r\n");
+                bytes.push_str("\r\n");
+                self.bus
+                    .senders
+                    .send_to_screen(ScreenInstruction::PtyBytes(id, bytes.into_bytes()))
+                    .unwrap();
+            }
+        }
         for id in new_pane_pids {
             let task_handle = stream_terminal_bytes(
                 id,
@@ -289,9 +402,33 @@ impl Pty {
             self.task_handles.insert(id, task_handle);
         }
     }
+    /// Adds the child pid and foreground process of each terminal pane to `session_info`.
+    pub fn fill_process_info(&self, session_info: &mut SessionInfo) {
+        let os_input = self.bus.os_input.as_ref().unwrap();
+        for pane in session_info
+            .tabs
+            .iter_mut()
+            .flat_map(|tab| tab.panes.iter_mut())
+            .filter(|pane| !pane.is_plugin)
+        {
+            let fd = pane.id as RawFd;
+            let child_pid = self.id_to_child_pid.get(&fd).copied();
+            let foreground_pid = os_input.get_foreground_pid(fd).or(child_pid);
+            pane.child_pid = child_pid.map(|pid| pid.as_raw());
+            pane.launched_command = self
+                .id_to_terminal_action
+                .get(&fd)
+                .and_then(launched_command);
+            if let Some(foreground_pid) = foreground_pid {
+                pane.command = os_input.get_cmdline(foreground_pid);
+                pane.cwd = os_input.get_cwd(foreground_pid);
+            }
+        }
+    }
     pub fn close_pane(&mut self, id: PaneId) {
         match id {
             PaneId::Terminal(id) => {
+                self.id_to_terminal_action.remove(&id);
                 let child_pid = self.id_to_child_pid.remove(&id).unwrap();
                 let handle = self.task_handles.remove(&id).unwrap();
                 task::block_on(async {
@@ -324,6 +461,39 @@ impl Pty {
     }
 }
 
+/// The default shell, started in the `cwd` and with the `env` a layout gives to `pane`.
+fn shell_in_pane_environment(
+    default_shell: &Option<TerminalAction>,
+    pane: &Layout,
+) -> TerminalAction {
+    let mut shell = match default_shell {
+        Some(TerminalAction::RunCommand(command)) => command.clone(),
+        _ => RunCommand {
+            command: PathBuf::from(env::var("SHELL").expect("Could not find the SHELL variable")),
+            ..Default::default()
+        },
+    };
+    shell.cwd = pane.cwd.clone();
+    shell.env.extend(pane.env.clone());
+    TerminalAction::RunCommand(shell)
+}
+
+/// The command line a terminal was started with, resolved the way `spawn_terminal` does.
+fn launched_command(terminal_action: &Option<TerminalAction>) -> Option<Vec<String>> {
+    match terminal_action {
+        Some(TerminalAction::RunCommand(command)) => {
+            let mut command_line = vec![command.command.to_string_lossy().into_owned()];
+            command_line.extend(command.args.iter().cloned());
+            Some(command_line)
+        }
+        Some(TerminalAction::OpenFile(file_to_open)) => {
+            let editor = env::var("EDITOR").or_else(|_| env::var("VISUAL")).ok()?;
+            Some(vec![editor, file_to_open.to_string_lossy().into_owned()])
+        }
+        None => env::var("SHELL").ok().map(|shell| vec![shell]),
+    }
+}
+
 impl Drop for Pty {
     fn drop(&mut self) {
         let child_ids: Vec<RawFd> = self.id_to_child_pid.keys().copied().collect();
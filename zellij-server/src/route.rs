@@ -4,7 +4,8 @@ use zellij_utils::zellij_tile::data::Event;
 
 use crate::{
     os_input_output::ServerOsApi, pty::PtyInstruction, screen::ScreenInstruction,
-    wasm_vm::PluginInstruction, ServerInstruction, SessionMetaData, SessionState,
+    wasm_vm::PluginInstruction, ClientId, ServerInstruction, SessionInfoRequester, SessionMetaData,
+    SessionState, DETACHED_CLIENT_ID,
 };
 use zellij_utils::{
     channels::SenderWithContext,
@@ -12,26 +13,37 @@ use zellij_utils::{
         actions::{Action, Direction},
         command::TerminalAction,
         get_mode_info,
+        layout::Layout,
     },
-    ipc::{ClientToServerMsg, ExitReason, ServerToClientMsg},
+    ipc::{ClientToServerMsg, ClientType, ExitReason, IpcReceiverWithContext, ServerToClientMsg},
 };
 
+/// Returns the action a read-only client is allowed to perform in place of `action`, if any.
+fn reader_action(action: Action) -> Option<Action> {
+    match action {
+        // quitting would end the session for everyone, so it only detaches the reader
+        Action::Quit | Action::Detach => Some(Action::Detach),
+        _ => None,
+    }
+}
+
 fn route_action(
     action: Action,
     session: &SessionMetaData,
     _os_input: &dyn ServerOsApi,
     to_server: &SenderWithContext<ServerInstruction>,
-) -> bool {
+    client_id: ClientId,
+) -> Result<bool, String> {
     let mut should_break = false;
     match action {
         Action::Write(val) => {
             session
                 .senders
-                .send_to_screen(ScreenInstruction::ClearScroll)
+                .send_to_screen(ScreenInstruction::ClearScroll(client_id))
                 .unwrap();
             session
                 .senders
-                .send_to_screen(ScreenInstruction::WriteCharacter(val))
+                .send_to_screen(ScreenInstruction::WriteCharacter(val, client_id))
                 .unwrap();
         }
         Action::SwitchToMode(mode) => {
@@ -43,16 +55,16 @@ fn route_action(
                 .senders
                 .send_to_plugin(PluginInstruction::Update(
                     None,
+                    Some(client_id),
                     Event::ModeUpdate(get_mode_info(mode, palette, session.capabilities)),
                 ))
                 .unwrap();
             session
                 .senders
-                .send_to_screen(ScreenInstruction::ChangeMode(get_mode_info(
-                    mode,
-                    palette,
-                    session.capabilities,
-                )))
+                .send_to_screen(ScreenInstruction::ChangeMode(
+                    get_mode_info(mode, palette, session.capabilities),
+                    client_id,
+                ))
                 .unwrap();
             session
                 .senders
@@ -61,44 +73,44 @@ fn route_action(
         }
         Action::Resize(direction) => {
             let screen_instr = match direction {
-                Direction::Left => ScreenInstruction::ResizeLeft,
-                Direction::Right => ScreenInstruction::ResizeRight,
-                Direction::Up => ScreenInstruction::ResizeUp,
-                Direction::Down => ScreenInstruction::ResizeDown,
+                Direction::Left => ScreenInstruction::ResizeLeft(client_id),
+                Direction::Right => ScreenInstruction::ResizeRight(client_id),
+                Direction::Up => ScreenInstruction::ResizeUp(client_id),
+                Direction::Down => ScreenInstruction::ResizeDown(client_id),
             };
             session.senders.send_to_screen(screen_instr).unwrap();
         }
         Action::SwitchFocus => {
             session
                 .senders
-                .send_to_screen(ScreenInstruction::SwitchFocus)
+                .send_to_screen(ScreenInstruction::SwitchFocus(client_id))
                 .unwrap();
         }
         Action::FocusNextPane => {
             session
                 .senders
-                .send_to_screen(ScreenInstruction::FocusNextPane)
+                .send_to_screen(ScreenInstruction::FocusNextPane(client_id))
                 .unwrap();
         }
         Action::FocusPreviousPane => {
             session
                 .senders
-                .send_to_screen(ScreenInstruction::FocusPreviousPane)
+                .send_to_screen(ScreenInstruction::FocusPreviousPane(client_id))
                 .unwrap();
         }
         Action::MoveFocus(direction) => {
             let screen_instr = match direction {
-                Direction::Left => ScreenInstruction::MoveFocusLeft,
-                Direction::Right => ScreenInstruction::MoveFocusRight,
-                Direction::Up => ScreenInstruction::MoveFocusUp,
-                Direction::Down => ScreenInstruction::MoveFocusDown,
+                Direction::Left => ScreenInstruction::MoveFocusLeft(client_id),
+                Direction::Right => ScreenInstruction::MoveFocusRight(client_id),
+                Direction::Up => ScreenInstruction::MoveFocusUp(client_id),
+                Direction::Down => ScreenInstruction::MoveFocusDown(client_id),
             };
             session.senders.send_to_screen(screen_instr).unwrap();
         }
         Action::MoveFocusOrTab(direction) => {
             let screen_instr = match direction {
-                Direction::Left => ScreenInstruction::MoveFocusLeftOrPreviousTab,
-                Direction::Right => ScreenInstruction::MoveFocusRightOrNextTab,
+                Direction::Left => ScreenInstruction::MoveFocusLeftOrPreviousTab(client_id),
+                Direction::Right => ScreenInstruction::MoveFocusRightOrNextTab(client_id),
                 _ => unreachable!(),
             };
             session.senders.send_to_screen(screen_instr).unwrap();
@@ -106,116 +118,126 @@ fn route_action(
         Action::ScrollUp => {
             session
                 .senders
-                .send_to_screen(ScreenInstruction::ScrollUp)
+                .send_to_screen(ScreenInstruction::ScrollUp(client_id))
                 .unwrap();
         }
         Action::ScrollUpAt(point) => {
             session
                 .senders
-                .send_to_screen(ScreenInstruction::ScrollUpAt(point))
+                .send_to_screen(ScreenInstruction::ScrollUpAt(point, client_id))
                 .unwrap();
         }
         Action::ScrollDown => {
             session
                 .senders
-                .send_to_screen(ScreenInstruction::ScrollDown)
+                .send_to_screen(ScreenInstruction::ScrollDown(client_id))
                 .unwrap();
         }
         Action::ScrollDownAt(point) => {
             session
                 .senders
-                .send_to_screen(ScreenInstruction::ScrollDownAt(point))
+                .send_to_screen(ScreenInstruction::ScrollDownAt(point, client_id))
                 .unwrap();
         }
         Action::PageScrollUp => {
             session
                 .senders
-                .send_to_screen(ScreenInstruction::PageScrollUp)
+                .send_to_screen(ScreenInstruction::PageScrollUp(client_id))
                 .unwrap();
         }
         Action::PageScrollDown => {
             session
                 .senders
-                .send_to_screen(ScreenInstruction::PageScrollDown)
+                .send_to_screen(ScreenInstruction::PageScrollDown(client_id))
                 .unwrap();
         }
         Action::ToggleFocusFullscreen => {
             session
                 .senders
-                .send_to_screen(ScreenInstruction::ToggleActiveTerminalFullscreen)
+                .send_to_screen(ScreenInstruction::ToggleActiveTerminalFullscreen(client_id))
                 .unwrap();
         }
         Action::NewPane(direction) => {
             let shell = session.default_shell.clone();
             let pty_instr = match direction {
-                Some(Direction::Left) => PtyInstruction::SpawnTerminalVertically(shell),
-                Some(Direction::Right) => PtyInstruction::SpawnTerminalVertically(shell),
-                Some(Direction::Up) => PtyInstruction::SpawnTerminalHorizontally(shell),
-                Some(Direction::Down) => PtyInstruction::SpawnTerminalHorizontally(shell),
+                Some(Direction::Left) => PtyInstruction::SpawnTerminalVertically(shell, client_id),
+                Some(Direction::Right) => PtyInstruction::SpawnTerminalVertically(shell, client_id),
+                Some(Direction::Up) => PtyInstruction::SpawnTerminalHorizontally(shell, client_id),
+                Some(Direction::Down) => {
+                    PtyInstruction::SpawnTerminalHorizontally(shell, client_id)
+                }
                 // No direction specified - try to put it in the biggest available spot
-                None => PtyInstruction::SpawnTerminal(shell),
+                None => PtyInstruction::SpawnTerminal(shell, client_id),
             };
             session.senders.send_to_pty(pty_instr).unwrap();
         }
         Action::Run(command) => {
             let run_cmd = Some(TerminalAction::RunCommand(command.clone().into()));
             let pty_instr = match command.direction {
-                Some(Direction::Left) => PtyInstruction::SpawnTerminalVertically(run_cmd),
-                Some(Direction::Right) => PtyInstruction::SpawnTerminalVertically(run_cmd),
-                Some(Direction::Up) => PtyInstruction::SpawnTerminalHorizontally(run_cmd),
-                Some(Direction::Down) => PtyInstruction::SpawnTerminalHorizontally(run_cmd),
+                Some(Direction::Left) => {
+                    PtyInstruction::SpawnTerminalVertically(run_cmd, client_id)
+                }
+                Some(Direction::Right) => {
+                    PtyInstruction::SpawnTerminalVertically(run_cmd, client_id)
+                }
+                Some(Direction::Up) => {
+                    PtyInstruction::SpawnTerminalHorizontally(run_cmd, client_id)
+                }
+                Some(Direction::Down) => {
+                    PtyInstruction::SpawnTerminalHorizontally(run_cmd, client_id)
+                }
                 // No direction specified - try to put it in the biggest available spot
-                None => PtyInstruction::SpawnTerminal(run_cmd),
+                None => PtyInstruction::SpawnTerminal(run_cmd, client_id),
             };
             session.senders.send_to_pty(pty_instr).unwrap();
         }
         Action::CloseFocus => {
             session
                 .senders
-                .send_to_screen(ScreenInstruction::CloseFocusedPane)
+                .send_to_screen(ScreenInstruction::CloseFocusedPane(client_id))
                 .unwrap();
         }
         Action::NewTab => {
             let shell = session.default_shell.clone();
             session
                 .senders
-                .send_to_pty(PtyInstruction::NewTab(shell))
+                .send_to_pty(PtyInstruction::NewTab(shell, client_id))
                 .unwrap();
         }
         Action::GoToNextTab => {
             session
                 .senders
-                .send_to_screen(ScreenInstruction::SwitchTabNext)
+                .send_to_screen(ScreenInstruction::SwitchTabNext(client_id))
                 .unwrap();
         }
         Action::GoToPreviousTab => {
             session
                 .senders
-                .send_to_screen(ScreenInstruction::SwitchTabPrev)
+                .send_to_screen(ScreenInstruction::SwitchTabPrev(client_id))
                 .unwrap();
         }
         Action::ToggleActiveSyncTab => {
             session
                 .senders
-                .send_to_screen(ScreenInstruction::ToggleActiveSyncTab)
+                .send_to_screen(ScreenInstruction::ToggleActiveSyncTab(client_id))
                 .unwrap();
         }
         Action::CloseTab => {
             session
                 .senders
-                .send_to_screen(ScreenInstruction::CloseTab)
+                .send_to_screen(ScreenInstruction::CloseTab(client_id))
                 .unwrap();
         }
         Action::GoToTab(i) => {
             session
                 .senders
-                .send_to_screen(ScreenInstruction::GoToTab(i))
+                .send_to_screen(ScreenInstruction::GoToTab(i, client_id))
                 .unwrap();
         }
         Action::TabNameInput(c) => {
             session
                 .senders
-                .send_to_screen(ScreenInstruction::UpdateTabName(c))
+                .send_to_screen(ScreenInstruction::UpdateTabName(c, client_id))
                 .unwrap();
         }
         Action::Quit => {
@@ -223,36 +245,82 @@ fn route_action(
             should_break = true;
         }
         Action::Detach => {
-            to_server.send(ServerInstruction::DetachSession).unwrap();
+            to_server
+                .send(ServerInstruction::DetachSession(client_id))
+                .unwrap();
             should_break = true;
         }
+        Action::RenameSession(name) => {
+            to_server
+                .send(ServerInstruction::RenameSession(name, None))
+                .unwrap();
+        }
         Action::LeftClick(point) => {
             session
                 .senders
-                .send_to_screen(ScreenInstruction::LeftClick(point))
+                .send_to_screen(ScreenInstruction::LeftClick(point, client_id))
                 .unwrap();
         }
         Action::MouseRelease(point) => {
             session
                 .senders
-                .send_to_screen(ScreenInstruction::MouseRelease(point))
+                .send_to_screen(ScreenInstruction::MouseRelease(point, client_id))
                 .unwrap();
         }
         Action::MouseHold(point) => {
             session
                 .senders
-                .send_to_screen(ScreenInstruction::MouseHold(point))
+                .send_to_screen(ScreenInstruction::MouseHold(point, client_id))
+                .unwrap();
+        }
+        Action::MouseEvent(event, modifiers) => {
+            session
+                .senders
+                .send_to_screen(ScreenInstruction::MouseEvent(event, modifiers, client_id))
+                .unwrap();
+        }
+        Action::NewTabWithLayout(path, layout_args) => {
+            let tab_name = path
+                .file_stem()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            let layout = if path.is_absolute() {
+                Layout::from_template(&path, session.layout_dir.as_deref(), &layout_args)
+            } else {
+                Layout::from_dir(&path, session.layout_dir.as_ref(), &layout_args)
+            }
+            .map_err(|e| format!("There was an error in the layout file:\n{}", e))?;
+            let shell = session.default_shell.clone();
+            session
+                .senders
+                .send_to_pty(PtyInstruction::NewTabWithLayout(
+                    Box::new(layout),
+                    tab_name,
+                    shell,
+                    client_id,
+                ))
+                .unwrap();
+        }
+        Action::DumpLayout => {
+            session
+                .senders
+                .send_to_screen(ScreenInstruction::QuerySession(
+                    SessionInfoRequester::DumpLayout {
+                        client_id,
+                        requester: None,
+                    },
+                ))
                 .unwrap();
         }
         Action::Copy => {
             session
                 .senders
-                .send_to_screen(ScreenInstruction::Copy)
+                .send_to_screen(ScreenInstruction::Copy(client_id))
                 .unwrap();
         }
         Action::NoOp => {}
     }
-    should_break
+    Ok(should_break)
 }
 
 pub(crate) fn route_thread_main(
@@ -260,47 +328,180 @@ pub(crate) fn route_thread_main(
     session_state: Arc<RwLock<SessionState>>,
     os_input: Box<dyn ServerOsApi>,
     to_server: SenderWithContext<ServerInstruction>,
+    mut receiver: IpcReceiverWithContext<ClientToServerMsg>,
+    client_id: ClientId,
 ) {
+    // clients attached with `--read-only` can watch the session, but not act on it,
+    // connections that never attach only send actions from the command line
+    let mut client_type = None;
     loop {
-        let (instruction, err_ctx) = os_input.recv_from_client();
+        let (instruction, err_ctx) = receiver.recv();
         err_ctx.update_thread_ctx();
         let rlocked_sessions = session_data.read().unwrap();
 
         match instruction {
-            ClientToServerMsg::Action(action) => {
-                if let Some(rlocked_sessions) = rlocked_sessions.as_ref() {
-                    if route_action(action, rlocked_sessions, &*os_input, &to_server) {
-                        break;
+            ClientToServerMsg::Action(action) => match client_type {
+                Some(ClientType::Writer) => {
+                    if let Some(rlocked_sessions) = rlocked_sessions.as_ref() {
+                        // failing actions from the keyboard have nowhere to be reported
+                        if let Ok(true) = route_action(
+                            action,
+                            rlocked_sessions,
+                            &*os_input,
+                            &to_server,
+                            client_id,
+                        ) {
+                            break;
+                        }
                     }
                 }
-            }
-            ClientToServerMsg::TerminalResize(new_size) => {
-                rlocked_sessions
-                    .as_ref()
-                    .unwrap()
-                    .senders
-                    .send_to_screen(ScreenInstruction::TerminalResize(new_size))
-                    .unwrap();
+                Some(ClientType::Reader) => match reader_action(action) {
+                    Some(action) => {
+                        if let Some(rlocked_sessions) = rlocked_sessions.as_ref() {
+                            if let Ok(true) = route_action(
+                                action,
+                                rlocked_sessions,
+                                &*os_input,
+                                &to_server,
+                                client_id,
+                            ) {
+                                break;
+                            }
+                        }
+                    }
+                    None => {
+                        // the client might be blocking its input until this action is done
+                        os_input.send_to_client(client_id, ServerToClientMsg::UnblockInputThread);
+                    }
+                },
+                None => {
+                    // `zellij action` performs its actions on behalf of the client that was
+                    // most recently active in the session, and waits for each to be routed
+                    let latest_client_id = session_state.read().unwrap().latest_client();
+                    let acting_client_id = match (rlocked_sessions.as_ref(), latest_client_id) {
+                        (Some(_), Some(latest_client_id)) => Some(latest_client_id),
+                        (Some(rlocked_sessions), None) => {
+                            // starting on the first tab, unless earlier actions moved it
+                            rlocked_sessions
+                                .senders
+                                .send_to_screen(ScreenInstruction::AddClient(DETACHED_CLIENT_ID))
+                                .unwrap();
+                            Some(DETACHED_CLIENT_ID)
+                        }
+                        (None, _) => None,
+                    };
+                    match (rlocked_sessions.as_ref(), acting_client_id) {
+                        (Some(rlocked_sessions), Some(acting_client_id))
+                            if matches!(action, Action::DumpLayout) =>
+                        {
+                            // answered with the layout once it was dumped
+                            rlocked_sessions
+                                .senders
+                                .send_to_screen(ScreenInstruction::QuerySession(
+                                    SessionInfoRequester::DumpLayout {
+                                        client_id: acting_client_id,
+                                        requester: Some(client_id),
+                                    },
+                                ))
+                                .unwrap();
+                        }
+                        (Some(rlocked_sessions), Some(acting_client_id)) => {
+                            let answer = match route_action(
+                                action,
+                                rlocked_sessions,
+                                &*os_input,
+                                &to_server,
+                                acting_client_id,
+                            ) {
+                                Ok(_) => ServerToClientMsg::UnblockInputThread,
+                                Err(e) => ServerToClientMsg::Exit(ExitReason::Error(e)),
+                            };
+                            os_input.send_to_client(client_id, answer);
+                        }
+                        _ => {
+                            os_input.send_to_client(
+                                client_id,
+                                ServerToClientMsg::Exit(ExitReason::Error(
+                                    "The session has not started yet".into(),
+                                )),
+                            );
+                        }
+                    }
+                }
+            },
+            ClientToServerMsg::TerminalResize(new_size, character_cell_size) => {
+                let mut session_state = session_state.write().unwrap();
+                session_state.set_client_size(client_id, new_size);
+                let senders = &rlocked_sessions.as_ref().unwrap().senders;
+                // the font of the terminal might have changed along with its size
+                if let Some(character_cell_size) = character_cell_size {
+                    senders
+                        .send_to_screen(ScreenInstruction::SetCharacterCellSize(
+                            character_cell_size,
+                        ))
+                        .unwrap();
+                }
+                if let Some(session_size) = session_state.session_size() {
+                    senders
+                        .send_to_screen(ScreenInstruction::TerminalResize(session_size))
+                        .unwrap();
+                }
             }
             ClientToServerMsg::NewClient(..) => {
-                if *session_state.read().unwrap() != SessionState::Uninitialized {
-                    os_input.send_to_temp_client(ServerToClientMsg::Exit(ExitReason::Error(
-                        "Cannot add new client".into(),
-                    )));
+                if rlocked_sessions.is_some() {
+                    os_input.send_to_client(
+                        client_id,
+                        ServerToClientMsg::Exit(ExitReason::Error("Cannot add new client".into())),
+                    );
                 } else {
-                    os_input.add_client_sender();
-                    to_server.send(instruction.into()).unwrap();
+                    client_type = Some(ClientType::Writer);
+                    to_server.send((instruction, client_id).into()).unwrap();
                 }
             }
-            ClientToServerMsg::AttachClient(_, force, _) => {
-                if *session_state.read().unwrap() == SessionState::Attached && !force {
-                    os_input.send_to_temp_client(ServerToClientMsg::Exit(ExitReason::CannotAttach));
-                } else {
-                    os_input.add_client_sender();
-                    to_server.send(instruction.into()).unwrap();
+            ClientToServerMsg::AttachClient(_, _, attached_client_type, _) => {
+                client_type = Some(attached_client_type);
+                to_server.send((instruction, client_id).into()).unwrap();
+            }
+            ClientToServerMsg::QuerySession => match rlocked_sessions.as_ref() {
+                Some(rlocked_sessions) => {
+                    rlocked_sessions
+                        .senders
+                        .send_to_screen(ScreenInstruction::QuerySession(
+                            SessionInfoRequester::Client(client_id),
+                        ))
+                        .unwrap();
+                }
+                None => {
+                    os_input.send_to_client(
+                        client_id,
+                        ServerToClientMsg::Exit(ExitReason::Error(
+                            "The session has not started yet".into(),
+                        )),
+                    );
                 }
+            },
+            ClientToServerMsg::KillSession => {
+                to_server
+                    .send(ServerInstruction::KillSession(client_id))
+                    .unwrap();
+            }
+            ClientToServerMsg::RenameSession(name) => {
+                to_server
+                    .send(ServerInstruction::RenameSession(name, Some(client_id)))
+                    .unwrap();
+            }
+            ClientToServerMsg::ClientExited => {
+                // the client might have never been attached, eg. when it
+                // was only checking whether this session is alive
+                to_server
+                    .send(ServerInstruction::RemoveClient(client_id))
+                    .unwrap();
+                break;
             }
-            ClientToServerMsg::ClientExited => break,
         }
     }
 }
+
+#[cfg(test)]
+#[path = "./unit/route_tests.rs"]
+mod route_tests;
@@ -1,11 +1,20 @@
 //! Things related to [`Screen`]s.
 
+use std::cell::RefCell;
 use std::collections::BTreeMap;
 use std::os::unix::io::RawFd;
+use std::rc::Rc;
 use std::str;
 use std::sync::{Arc, RwLock};
 
-use zellij_utils::{input::layout::Layout, position::Position, zellij_tile};
+use zellij_utils::{
+    input::{
+        layout::Layout,
+        mouse::{MouseEvent, MouseModifiers},
+    },
+    position::Position,
+    zellij_tile,
+};
 
 use crate::{
     panes::PaneId,
@@ -13,14 +22,16 @@ use crate::{
     tab::Tab,
     thread_bus::Bus,
     wasm_vm::PluginInstruction,
-    ServerInstruction, SessionState,
+    ClientId, ServerInstruction, SessionInfoRequester, SessionState,
+};
+use zellij_tile::data::{
+    Event, InputMode, ModeInfo, Palette, PaneInfo, PluginCapabilities, TabInfo,
 };
-use zellij_tile::data::{Event, InputMode, ModeInfo, Palette, PluginCapabilities, TabInfo};
 use zellij_utils::{
     errors::{ContextType, ScreenContext},
     input::options::Options,
-    ipc::ClientAttributes,
-    pane_size::PositionAndSize,
+    ipc::{ClientAttributes, SessionInfo, TabInfo as SessionTabInfo},
+    pane_size::{PositionAndSize, SizeInPixels},
 };
 
 /// Instructions that can be sent to the [`Screen`].
@@ -28,52 +39,58 @@ use zellij_utils::{
 pub(crate) enum ScreenInstruction {
     PtyBytes(RawFd, VteBytes),
     Render,
-    NewPane(PaneId),
-    HorizontalSplit(PaneId),
-    VerticalSplit(PaneId),
-    WriteCharacter(Vec<u8>),
-    ResizeLeft,
-    ResizeRight,
-    ResizeDown,
-    ResizeUp,
-    SwitchFocus,
-    FocusNextPane,
-    FocusPreviousPane,
-    MoveFocusLeft,
-    MoveFocusLeftOrPreviousTab,
-    MoveFocusDown,
-    MoveFocusUp,
-    MoveFocusRight,
-    MoveFocusRightOrNextTab,
+    NewPane(PaneId, ClientId),
+    HorizontalSplit(PaneId, ClientId),
+    VerticalSplit(PaneId, ClientId),
+    WriteCharacter(Vec<u8>, ClientId),
+    ResizeLeft(ClientId),
+    ResizeRight(ClientId),
+    ResizeDown(ClientId),
+    ResizeUp(ClientId),
+    SwitchFocus(ClientId),
+    FocusNextPane(ClientId),
+    FocusPreviousPane(ClientId),
+    MoveFocusLeft(ClientId),
+    MoveFocusLeftOrPreviousTab(ClientId),
+    MoveFocusDown(ClientId),
+    MoveFocusUp(ClientId),
+    MoveFocusRight(ClientId),
+    MoveFocusRightOrNextTab(ClientId),
     Exit,
-    ScrollUp,
-    ScrollUpAt(Position),
-    ScrollDown,
-    ScrollDownAt(Position),
-    PageScrollUp,
-    PageScrollDown,
-    ClearScroll,
-    CloseFocusedPane,
-    ToggleActiveTerminalFullscreen,
+    ScrollUp(ClientId),
+    ScrollUpAt(Position, ClientId),
+    ScrollDown(ClientId),
+    ScrollDownAt(Position, ClientId),
+    PageScrollUp(ClientId),
+    PageScrollDown(ClientId),
+    ClearScroll(ClientId),
+    CloseFocusedPane(ClientId),
+    ToggleActiveTerminalFullscreen(ClientId),
     SetSelectable(PaneId, bool),
     SetFixedHeight(PaneId, usize),
     SetFixedWidth(PaneId, usize),
     SetInvisibleBorders(PaneId, bool),
     ClosePane(PaneId),
-    ApplyLayout(Layout, Vec<RawFd>),
-    NewTab(RawFd),
-    SwitchTabNext,
-    SwitchTabPrev,
-    ToggleActiveSyncTab,
-    CloseTab,
-    GoToTab(u32),
-    UpdateTabName(Vec<u8>),
+    ApplyLayout(Layout, Vec<RawFd>, Option<String>, ClientId),
+    NewTab(RawFd, ClientId),
+    SwitchTabNext(ClientId),
+    SwitchTabPrev(ClientId),
+    ToggleActiveSyncTab(ClientId),
+    CloseTab(ClientId),
+    GoToTab(u32, ClientId),
+    UpdateTabName(Vec<u8>, ClientId),
     TerminalResize(PositionAndSize),
-    ChangeMode(ModeInfo),
-    LeftClick(Position),
-    MouseRelease(Position),
-    MouseHold(Position),
-    Copy,
+    SetCharacterCellSize(SizeInPixels),
+    ChangeMode(ModeInfo, ClientId),
+    LeftClick(Position, ClientId),
+    MouseRelease(Position, ClientId),
+    MouseHold(Position, ClientId),
+    MouseEvent(MouseEvent, MouseModifiers, ClientId),
+    Copy(ClientId),
+    AddClient(ClientId),
+    RemoveClient(ClientId),
+    QuerySession(SessionInfoRequester),
+    UpdateForegroundCommands,
 }
 
 impl From<&ScreenInstruction> for ScreenContext {
@@ -81,33 +98,35 @@ impl From<&ScreenInstruction> for ScreenContext {
         match *screen_instruction {
             ScreenInstruction::PtyBytes(..) => ScreenContext::HandlePtyBytes,
             ScreenInstruction::Render => ScreenContext::Render,
-            ScreenInstruction::NewPane(_) => ScreenContext::NewPane,
-            ScreenInstruction::HorizontalSplit(_) => ScreenContext::HorizontalSplit,
-            ScreenInstruction::VerticalSplit(_) => ScreenContext::VerticalSplit,
-            ScreenInstruction::WriteCharacter(_) => ScreenContext::WriteCharacter,
-            ScreenInstruction::ResizeLeft => ScreenContext::ResizeLeft,
-            ScreenInstruction::ResizeRight => ScreenContext::ResizeRight,
-            ScreenInstruction::ResizeDown => ScreenContext::ResizeDown,
-            ScreenInstruction::ResizeUp => ScreenContext::ResizeUp,
-            ScreenInstruction::SwitchFocus => ScreenContext::SwitchFocus,
-            ScreenInstruction::FocusNextPane => ScreenContext::FocusNextPane,
-            ScreenInstruction::FocusPreviousPane => ScreenContext::FocusPreviousPane,
-            ScreenInstruction::MoveFocusLeft => ScreenContext::MoveFocusLeft,
-            ScreenInstruction::MoveFocusLeftOrPreviousTab => {
+            ScreenInstruction::NewPane(..) => ScreenContext::NewPane,
+            ScreenInstruction::HorizontalSplit(..) => ScreenContext::HorizontalSplit,
+            ScreenInstruction::VerticalSplit(..) => ScreenContext::VerticalSplit,
+            ScreenInstruction::WriteCharacter(..) => ScreenContext::WriteCharacter,
+            ScreenInstruction::ResizeLeft(..) => ScreenContext::ResizeLeft,
+            ScreenInstruction::ResizeRight(..) => ScreenContext::ResizeRight,
+            ScreenInstruction::ResizeDown(..) => ScreenContext::ResizeDown,
+            ScreenInstruction::ResizeUp(..) => ScreenContext::ResizeUp,
+            ScreenInstruction::SwitchFocus(..) => ScreenContext::SwitchFocus,
+            ScreenInstruction::FocusNextPane(..) => ScreenContext::FocusNextPane,
+            ScreenInstruction::FocusPreviousPane(..) => ScreenContext::FocusPreviousPane,
+            ScreenInstruction::MoveFocusLeft(..) => ScreenContext::MoveFocusLeft,
+            ScreenInstruction::MoveFocusLeftOrPreviousTab(..) => {
                 ScreenContext::MoveFocusLeftOrPreviousTab
             }
-            ScreenInstruction::MoveFocusDown => ScreenContext::MoveFocusDown,
-            ScreenInstruction::MoveFocusUp => ScreenContext::MoveFocusUp,
-            ScreenInstruction::MoveFocusRight => ScreenContext::MoveFocusRight,
-            ScreenInstruction::MoveFocusRightOrNextTab => ScreenContext::MoveFocusRightOrNextTab,
+            ScreenInstruction::MoveFocusDown(..) => ScreenContext::MoveFocusDown,
+            ScreenInstruction::MoveFocusUp(..) => ScreenContext::MoveFocusUp,
+            ScreenInstruction::MoveFocusRight(..) => ScreenContext::MoveFocusRight,
+            ScreenInstruction::MoveFocusRightOrNextTab(..) => {
+                ScreenContext::MoveFocusRightOrNextTab
+            }
             ScreenInstruction::Exit => ScreenContext::Exit,
-            ScreenInstruction::ScrollUp => ScreenContext::ScrollUp,
-            ScreenInstruction::ScrollDown => ScreenContext::ScrollDown,
-            ScreenInstruction::PageScrollUp => ScreenContext::PageScrollUp,
-            ScreenInstruction::PageScrollDown => ScreenContext::PageScrollDown,
-            ScreenInstruction::ClearScroll => ScreenContext::ClearScroll,
-            ScreenInstruction::CloseFocusedPane => ScreenContext::CloseFocusedPane,
-            ScreenInstruction::ToggleActiveTerminalFullscreen => {
+            ScreenInstruction::ScrollUp(..) => ScreenContext::ScrollUp,
+            ScreenInstruction::ScrollDown(..) => ScreenContext::ScrollDown,
+            ScreenInstruction::PageScrollUp(..) => ScreenContext::PageScrollUp,
+            ScreenInstruction::PageScrollDown(..) => ScreenContext::PageScrollDown,
+            ScreenInstruction::ClearScroll(..) => ScreenContext::ClearScroll,
+            ScreenInstruction::CloseFocusedPane(..) => ScreenContext::CloseFocusedPane,
+            ScreenInstruction::ToggleActiveTerminalFullscreen(..) => {
                 ScreenContext::ToggleActiveTerminalFullscreen
             }
             ScreenInstruction::SetSelectable(..) => ScreenContext::SetSelectable,
@@ -116,27 +135,34 @@ impl From<&ScreenInstruction> for ScreenContext {
             ScreenInstruction::SetFixedWidth(..) => ScreenContext::SetFixedWidth,
             ScreenInstruction::ClosePane(_) => ScreenContext::ClosePane,
             ScreenInstruction::ApplyLayout(..) => ScreenContext::ApplyLayout,
-            ScreenInstruction::NewTab(_) => ScreenContext::NewTab,
-            ScreenInstruction::SwitchTabNext => ScreenContext::SwitchTabNext,
-            ScreenInstruction::SwitchTabPrev => ScreenContext::SwitchTabPrev,
-            ScreenInstruction::CloseTab => ScreenContext::CloseTab,
-            ScreenInstruction::GoToTab(_) => ScreenContext::GoToTab,
-            ScreenInstruction::UpdateTabName(_) => ScreenContext::UpdateTabName,
+            ScreenInstruction::NewTab(..) => ScreenContext::NewTab,
+            ScreenInstruction::SwitchTabNext(..) => ScreenContext::SwitchTabNext,
+            ScreenInstruction::SwitchTabPrev(..) => ScreenContext::SwitchTabPrev,
+            ScreenInstruction::CloseTab(..) => ScreenContext::CloseTab,
+            ScreenInstruction::GoToTab(..) => ScreenContext::GoToTab,
+            ScreenInstruction::UpdateTabName(..) => ScreenContext::UpdateTabName,
             ScreenInstruction::TerminalResize(_) => ScreenContext::TerminalResize,
-            ScreenInstruction::ChangeMode(_) => ScreenContext::ChangeMode,
-            ScreenInstruction::ToggleActiveSyncTab => ScreenContext::ToggleActiveSyncTab,
-            ScreenInstruction::ScrollUpAt(_) => ScreenContext::ScrollUpAt,
-            ScreenInstruction::ScrollDownAt(_) => ScreenContext::ScrollDownAt,
-            ScreenInstruction::LeftClick(_) => ScreenContext::LeftClick,
-            ScreenInstruction::MouseRelease(_) => ScreenContext::MouseRelease,
-            ScreenInstruction::MouseHold(_) => ScreenContext::MouseHold,
-            ScreenInstruction::Copy => ScreenContext::Copy,
+            ScreenInstruction::SetCharacterCellSize(_) => ScreenContext::SetCharacterCellSize,
+            ScreenInstruction::ChangeMode(..) => ScreenContext::ChangeMode,
+            ScreenInstruction::ToggleActiveSyncTab(..) => ScreenContext::ToggleActiveSyncTab,
+            ScreenInstruction::ScrollUpAt(..) => ScreenContext::ScrollUpAt,
+            ScreenInstruction::ScrollDownAt(..) => ScreenContext::ScrollDownAt,
+            ScreenInstruction::LeftClick(..) => ScreenContext::LeftClick,
+            ScreenInstruction::MouseRelease(..) => ScreenContext::MouseRelease,
+            ScreenInstruction::MouseHold(..) => ScreenContext::MouseHold,
+            ScreenInstruction::MouseEvent(..) => ScreenContext::MouseEvent,
+            ScreenInstruction::Copy(..) => ScreenContext::Copy,
+            ScreenInstruction::AddClient(..) => ScreenContext::AddClient,
+            ScreenInstruction::RemoveClient(..) => ScreenContext::RemoveClient,
+            ScreenInstruction::QuerySession(..) => ScreenContext::QuerySession,
+            ScreenInstruction::UpdateForegroundCommands => ScreenContext::UpdateForegroundCommands,
         }
     }
 }
 
 /// A [`Screen`] holds multiple [`Tab`]s, each one holding multiple [`panes`](crate::client::panes).
-/// It only directly controls which tab is active, delegating the rest to the individual `Tab`.
+/// It only directly controls which tab is active for each client, delegating the rest to the
+/// individual `Tab`.
 pub(crate) struct Screen {
     /// A Bus for sending and receiving messages with the other threads.
     pub bus: Bus<ScreenInstruction>,
@@ -146,12 +172,20 @@ pub(crate) struct Screen {
     tabs: BTreeMap<usize, Tab>,
     /// The full size of this [`Screen`].
     position_and_size: PositionAndSize,
-    /// The index of this [`Screen`]'s active [`Tab`].
-    active_tab_index: Option<usize>,
-    mode_info: ModeInfo,
+    /// The indices of the [`Tab`]s each client is looking at.
+    active_tab_indices: BTreeMap<ClientId, usize>,
+    /// The mode each client is in.
+    mode_info: BTreeMap<ClientId, ModeInfo>,
+    /// The mode clients start in.
+    default_mode_info: ModeInfo,
     input_mode: InputMode,
     colors: Palette,
     session_state: Arc<RwLock<SessionState>>,
+    /// The panes last sent to the plugins, to only send them again when they change.
+    pane_infos: Vec<PaneInfo>,
+    /// The size of the character cells of the client that attached or resized last, which
+    /// images are sized in cells with.
+    character_cell_size: Rc<RefCell<Option<SizeInPixels>>>,
 }
 
 impl Screen {
@@ -169,17 +203,21 @@ impl Screen {
             max_panes,
             position_and_size: client_attributes.position_and_size,
             colors: client_attributes.palette,
-            active_tab_index: None,
+            active_tab_indices: BTreeMap::new(),
             tabs: BTreeMap::new(),
-            mode_info,
+            mode_info: BTreeMap::new(),
+            default_mode_info: mode_info,
             input_mode,
             session_state,
+            pane_infos: vec![],
+            character_cell_size: Rc::new(RefCell::new(client_attributes.character_cell_size)),
         }
     }
 
     /// Creates a new [`Tab`] in this [`Screen`], containing a single
-    /// [pane](crate::client::panes) with PTY file descriptor `pane_id`.
-    pub fn new_tab(&mut self, pane_id: RawFd) {
+    /// [pane](crate::client::panes) with PTY file descriptor `pane_id`, and switches
+    /// the client to it.
+    pub fn new_tab(&mut self, pane_id: RawFd, client_id: ClientId) {
         let tab_index = self.get_new_tab_index();
         let position = self.tabs.len();
         let tab = Tab::new(
@@ -195,9 +233,15 @@ impl Screen {
             self.input_mode,
             self.colors,
             self.session_state.clone(),
+            self.character_cell_size.clone(),
         );
-        self.active_tab_index = Some(tab_index);
         self.tabs.insert(tab_index, tab);
+        // the client might have detached while the terminals of the tab were spawned
+        if self.active_tab_indices.contains_key(&client_id)
+            || self.session_state.read().unwrap().has_client(client_id)
+        {
+            self.move_client_to_tab(client_id, tab_index);
+        }
         self.update_tabs();
         self.render();
     }
@@ -213,63 +257,106 @@ impl Screen {
         }
     }
 
-    /// Sets this [`Screen`]'s active [`Tab`] to the next tab.
-    pub fn switch_tab_next(&mut self) {
-        let active_tab_pos = self.get_active_tab().unwrap().position;
-        let new_tab_pos = (active_tab_pos + 1) % self.tabs.len();
-
-        for tab in self.tabs.values_mut() {
-            if tab.position == new_tab_pos {
-                tab.set_force_render();
-                self.active_tab_index = Some(tab.index);
-                break;
+    /// Makes the [`Tab`] at `tab_index` the active tab of the given client.
+    fn move_client_to_tab(&mut self, client_id: ClientId, tab_index: usize) {
+        if let Some(previous_tab_index) = self.active_tab_indices.insert(client_id, tab_index) {
+            if let Some(previous_tab) = self.tabs.get_mut(&previous_tab_index) {
+                previous_tab.remove_client(client_id);
             }
         }
+        if let Some(tab) = self.tabs.get_mut(&tab_index) {
+            tab.add_client(client_id);
+        }
+    }
+
+    /// Returns the index of the [`Tab`] at the given position, if there is one.
+    fn tab_index_at_position(&self, position: usize) -> Option<usize> {
+        self.tabs
+            .values()
+            .find(|tab| tab.position == position)
+            .map(|tab| tab.index)
+    }
+
+    /// Sets the client's active [`Tab`] to the next tab.
+    pub fn switch_tab_next(&mut self, client_id: ClientId) {
+        let active_tab_pos = match self.get_active_tab(client_id) {
+            Some(active_tab) => active_tab.position,
+            None => return,
+        };
+        let new_tab_pos = (active_tab_pos + 1) % self.tabs.len();
+        if let Some(new_tab_index) = self.tab_index_at_position(new_tab_pos) {
+            self.move_client_to_tab(client_id, new_tab_index);
+        }
         self.update_tabs();
         self.render();
     }
 
-    /// Sets this [`Screen`]'s active [`Tab`] to the previous tab.
-    pub fn switch_tab_prev(&mut self) {
-        let active_tab_pos = self.get_active_tab().unwrap().position;
+    /// Sets the client's active [`Tab`] to the previous tab.
+    pub fn switch_tab_prev(&mut self, client_id: ClientId) {
+        let active_tab_pos = match self.get_active_tab(client_id) {
+            Some(active_tab) => active_tab.position,
+            None => return,
+        };
         let new_tab_pos = if active_tab_pos == 0 {
             self.tabs.len() - 1
         } else {
             active_tab_pos - 1
         };
-        for tab in self.tabs.values_mut() {
-            if tab.position == new_tab_pos {
-                tab.set_force_render();
-                self.active_tab_index = Some(tab.index);
-                break;
-            }
+        if let Some(new_tab_index) = self.tab_index_at_position(new_tab_pos) {
+            self.move_client_to_tab(client_id, new_tab_index);
         }
         self.update_tabs();
         self.render();
     }
 
-    pub fn go_to_tab(&mut self, mut tab_index: usize) {
-        tab_index -= 1;
-        let active_tab_index = self.get_active_tab().unwrap().index;
-        if let Some(t) = self.tabs.values_mut().find(|t| t.position == tab_index) {
-            if t.index != active_tab_index {
-                t.set_force_render();
-                self.active_tab_index = Some(t.index);
+    pub fn go_to_tab(&mut self, tab_index: usize, client_id: ClientId) {
+        let active_tab_index = match self.get_active_tab(client_id) {
+            Some(active_tab) => active_tab.index,
+            None => return,
+        };
+        // tab indices start from 1
+        let position = tab_index.checked_sub(1);
+        if let Some(new_tab_index) =
+            position.and_then(|position| self.tab_index_at_position(position))
+        {
+            if new_tab_index != active_tab_index {
+                self.move_client_to_tab(client_id, new_tab_index);
                 self.update_tabs();
                 self.render();
             }
         }
     }
 
-    /// Closes this [`Screen`]'s active [`Tab`], exiting the application if it happens
+    /// Closes the client's active [`Tab`], exiting the application if it happens
     /// to be the last tab.
-    pub fn close_tab(&mut self) {
-        let active_tab_index = self.active_tab_index.unwrap();
+    pub fn close_tab(&mut self, client_id: ClientId) {
+        if let Some(&active_tab_index) = self.active_tab_indices.get(&client_id) {
+            self.close_tab_at_index(active_tab_index);
+        }
+    }
+
+    /// Closes the [`Tab`] at `tab_index`, moving the clients looking at it to the previous tab.
+    fn close_tab_at_index(&mut self, tab_index: usize) {
+        let tab_position = self.tabs.get(&tab_index).unwrap().position;
         if self.tabs.len() > 1 {
-            self.switch_tab_prev();
+            let previous_tab_position = if tab_position == 0 {
+                self.tabs.len() - 1
+            } else {
+                tab_position - 1
+            };
+            let previous_tab_index = self.tab_index_at_position(previous_tab_position).unwrap();
+            let client_ids: Vec<ClientId> = self
+                .active_tab_indices
+                .iter()
+                .filter(|(_, &active_tab_index)| active_tab_index == tab_index)
+                .map(|(&client_id, _)| client_id)
+                .collect();
+            for client_id in client_ids {
+                self.move_client_to_tab(client_id, previous_tab_index);
+            }
         }
-        let active_tab = self.tabs.remove(&active_tab_index).unwrap();
-        let pane_ids = active_tab.get_pane_ids();
+        let closed_tab = self.tabs.remove(&tab_index).unwrap();
+        let pane_ids = closed_tab.get_pane_ids();
         // below we don't check the result of sending the CloseTab instruction to the pty thread
         // because this might be happening when the app is closing, at which point the pty thread
         // has already closed and this would result in an error
@@ -278,8 +365,8 @@ impl Screen {
             .send_to_pty(PtyInstruction::CloseTab(pane_ids))
             .unwrap();
         if self.tabs.is_empty() {
-            self.active_tab_index = None;
-            if *self.session_state.read().unwrap() == SessionState::Attached {
+            self.active_tab_indices.clear();
+            if self.session_state.read().unwrap().is_attached() {
                 self.bus
                     .senders
                     .send_to_server(ServerInstruction::Render(None))
@@ -287,7 +374,7 @@ impl Screen {
             }
         } else {
             for t in self.tabs.values_mut() {
-                if t.position > active_tab.position {
+                if t.position > closed_tab.position {
                     t.position -= 1;
                 }
             }
@@ -295,27 +382,82 @@ impl Screen {
         }
     }
 
+    /// Sets the size of the character cells the grids of every tab size their images with.
+    pub fn set_character_cell_size(&mut self, character_cell_size: SizeInPixels) {
+        *self.character_cell_size.borrow_mut() = Some(character_cell_size);
+    }
+
     pub fn resize_to_screen(&mut self, new_screen_size: PositionAndSize) {
         self.position_and_size = new_screen_size;
         for (_, tab) in self.tabs.iter_mut() {
             tab.resize_whole_tab(new_screen_size);
+            tab.set_force_render();
         }
-        let _ = self.get_active_tab_mut().map(|t| t.set_force_render());
         self.render();
     }
 
-    /// Renders this [`Screen`], which amounts to rendering its active [`Tab`].
+    /// Renders this [`Screen`], which amounts to rendering the [`Tab`]s clients are looking at.
     pub fn render(&mut self) {
-        if *self.session_state.read().unwrap() != SessionState::Attached {
+        if !self.session_state.read().unwrap().is_attached() {
             return;
         }
-        if let Some(active_tab) = self.get_active_tab_mut() {
-            if active_tab.get_active_pane().is_some() {
-                active_tab.render();
-            } else {
-                self.close_tab();
+        let mut tabs_to_close = vec![];
+        for (tab_index, tab) in self.tabs.iter_mut() {
+            if !tab.has_selectable_panes() {
+                tabs_to_close.push(*tab_index);
+            } else if tab.has_clients() {
+                tab.render();
             }
-        };
+        }
+        for tab_index in tabs_to_close {
+            self.close_tab_at_index(tab_index);
+        }
+        // the titles of the panes change with what they render
+        self.update_panes();
+    }
+
+    /// Looks up the commands the panes of every tab are titled by, rendering if they changed.
+    pub fn update_foreground_commands(&mut self) {
+        let mut changed = false;
+        for tab in self.tabs.values_mut() {
+            changed |= tab.update_foreground_commands();
+        }
+        if changed {
+            self.render();
+        }
+    }
+
+    /// Sends the plugins the panes of every tab, if they changed since they were last sent.
+    pub fn update_panes(&mut self) {
+        let pane_infos: Vec<PaneInfo> = self
+            .tabs
+            .values()
+            .flat_map(|tab| {
+                tab.pane_titles().into_iter().map(move |(pane_id, title)| {
+                    let (id, is_plugin) = match pane_id {
+                        PaneId::Terminal(fd) => (fd as u32, false),
+                        PaneId::Plugin(plugin_id) => (plugin_id, true),
+                    };
+                    PaneInfo {
+                        id,
+                        is_plugin,
+                        tab_position: tab.position,
+                        title,
+                    }
+                })
+            })
+            .collect();
+        if pane_infos != self.pane_infos {
+            self.bus
+                .senders
+                .send_to_plugin(PluginInstruction::Update(
+                    None,
+                    None,
+                    Event::PaneUpdate(pane_infos.clone()),
+                ))
+                .unwrap();
+            self.pane_infos = pane_infos;
+        }
     }
 
     /// Returns a mutable reference to this [`Screen`]'s tabs.
@@ -323,31 +465,37 @@ impl Screen {
         &mut self.tabs
     }
 
-    /// Returns an immutable reference to this [`Screen`]'s active [`Tab`].
-    pub fn get_active_tab(&self) -> Option<&Tab> {
-        match self.active_tab_index {
-            Some(tab) => self.tabs.get(&tab),
+    /// Returns an immutable reference to the client's active [`Tab`].
+    pub fn get_active_tab(&self, client_id: ClientId) -> Option<&Tab> {
+        match self.active_tab_indices.get(&client_id) {
+            Some(tab) => self.tabs.get(tab),
             None => None,
         }
     }
 
-    /// Returns a mutable reference to this [`Screen`]'s active [`Tab`].
-    pub fn get_active_tab_mut(&mut self) -> Option<&mut Tab> {
-        match self.active_tab_index {
-            Some(tab) => self.get_tabs_mut().get_mut(&tab),
+    /// Returns a mutable reference to the client's active [`Tab`].
+    pub fn get_active_tab_mut(&mut self, client_id: ClientId) -> Option<&mut Tab> {
+        match self.active_tab_indices.get(&client_id) {
+            Some(tab) => self.tabs.get_mut(tab),
             None => None,
         }
     }
 
     /// Creates a new [`Tab`] in this [`Screen`], applying the specified [`Layout`]
-    /// and switching to it.
-    pub fn apply_layout(&mut self, layout: Layout, new_pids: Vec<RawFd>) {
+    /// and switching the client to it.
+    pub fn apply_layout(
+        &mut self,
+        layout: Layout,
+        new_pids: Vec<RawFd>,
+        tab_name: Option<String>,
+        client_id: ClientId,
+    ) {
         let tab_index = self.get_new_tab_index();
         let position = self.tabs.len();
-        let mut tab = Tab::new(
+        let tab = Tab::new(
             tab_index,
             position,
-            String::new(),
+            tab_name.unwrap_or_default(),
             &self.position_and_size,
             self.bus.os_input.as_ref().unwrap().clone(),
             self.bus.senders.clone(),
@@ -357,33 +505,51 @@ impl Screen {
             self.input_mode,
             self.colors,
             self.session_state.clone(),
+            self.character_cell_size.clone(),
         );
-        tab.apply_layout(layout, new_pids);
-        self.active_tab_index = Some(tab_index);
         self.tabs.insert(tab_index, tab);
+        // the client might have detached while the terminals of the tab were spawned
+        if self.active_tab_indices.contains_key(&client_id)
+            || self.session_state.read().unwrap().has_client(client_id)
+        {
+            self.move_client_to_tab(client_id, tab_index);
+        }
+        self.tabs
+            .get_mut(&tab_index)
+            .unwrap()
+            .apply_layout(layout, new_pids);
         self.update_tabs();
     }
 
+    /// Sends every client the list of tabs, marking the one it is looking at as active.
     pub fn update_tabs(&self) {
-        let mut tab_data = vec![];
-        let active_tab_index = self.active_tab_index.unwrap();
-        for tab in self.tabs.values() {
-            tab_data.push(TabInfo {
-                position: tab.position,
-                name: tab.name.clone(),
-                active: active_tab_index == tab.index,
-                is_sync_panes_active: tab.is_sync_panes_active(),
-            });
+        for (&client_id, &active_tab_index) in &self.active_tab_indices {
+            let mut tab_data = vec![];
+            for tab in self.tabs.values() {
+                tab_data.push(TabInfo {
+                    position: tab.position,
+                    name: tab.name.clone(),
+                    active: active_tab_index == tab.index,
+                    is_sync_panes_active: tab.is_sync_panes_active(),
+                });
+            }
+            self.bus
+                .senders
+                .send_to_plugin(PluginInstruction::Update(
+                    None,
+                    Some(client_id),
+                    Event::TabUpdate(tab_data),
+                ))
+                .unwrap();
         }
-        self.bus
-            .senders
-            .send_to_plugin(PluginInstruction::Update(None, Event::TabUpdate(tab_data)))
-            .unwrap();
     }
 
-    pub fn update_active_tab_name(&mut self, buf: Vec<u8>) {
+    pub fn update_active_tab_name(&mut self, buf: Vec<u8>, client_id: ClientId) {
         let s = str::from_utf8(&buf).unwrap();
-        let active_tab = self.get_active_tab_mut().unwrap();
+        let active_tab = match self.get_active_tab_mut(client_id) {
+            Some(active_tab) => active_tab,
+            None => return,
+        };
         match s {
             "\0" => {
                 active_tab.name = String::new();
@@ -398,21 +564,81 @@ impl Screen {
         }
         self.update_tabs();
     }
-    pub fn change_mode(&mut self, mode_info: ModeInfo) {
+    pub fn change_mode(&mut self, mode_info: ModeInfo, client_id: ClientId) {
         self.colors = mode_info.palette;
-        self.mode_info = mode_info;
         for tab in self.tabs.values_mut() {
-            tab.mode_info = self.mode_info.clone();
+            tab.mode_info.insert(client_id, mode_info.clone());
+        }
+        self.mode_info.insert(client_id, mode_info);
+    }
+    /// Starts showing this [`Screen`] to a newly attached client, on the same [`Tab`] as
+    /// the clients already attached (if there are any), or else on the first tab.
+    pub fn add_client(&mut self, client_id: ClientId) {
+        if self.active_tab_indices.contains_key(&client_id) {
+            return;
+        }
+        let tab_index = self
+            .active_tab_indices
+            .values()
+            .next()
+            .copied()
+            .or_else(|| self.tab_index_at_position(0));
+        self.change_mode(self.default_mode_info.clone(), client_id);
+        if let Some(tab_index) = tab_index {
+            self.move_client_to_tab(client_id, tab_index);
+            self.update_tabs();
+            self.render();
+        }
+    }
+    pub fn remove_client(&mut self, client_id: ClientId) {
+        if let Some(tab_index) = self.active_tab_indices.remove(&client_id) {
+            if let Some(tab) = self.tabs.get_mut(&tab_index) {
+                tab.remove_client(client_id);
+            }
+        }
+        self.mode_info.remove(&client_id);
+        for tab in self.tabs.values_mut() {
+            tab.mode_info.remove(&client_id);
+        }
+    }
+    /// Describes the tabs and panes of the session, ordered by tab position,
+    /// including up to `scrollback_lines` lines of the scrollback of each terminal pane.
+    pub fn session_info(&self, scrollback_lines: usize) -> SessionInfo {
+        let mut tabs: Vec<&Tab> = self.tabs.values().collect();
+        tabs.sort_by_key(|tab| tab.position);
+        let tabs = tabs
+            .into_iter()
+            .map(|tab| SessionTabInfo {
+                position: tab.position,
+                name: tab.name.clone(),
+                active_clients: self
+                    .active_tab_indices
+                    .iter()
+                    .filter(|(_, tab_index)| **tab_index == tab.index)
+                    .map(|(client_id, _)| *client_id)
+                    .collect(),
+                sync_panes: tab.is_sync_panes_active(),
+                fullscreen: tab.is_fullscreen_active(),
+                panes: tab.pane_infos(scrollback_lines),
+            })
+            .collect();
+        SessionInfo {
+            tabs,
+            ..Default::default()
         }
     }
-    pub fn move_focus_left_or_previous_tab(&mut self) {
-        if !self.get_active_tab_mut().unwrap().move_focus_left() {
-            self.switch_tab_prev();
+    pub fn move_focus_left_or_previous_tab(&mut self, client_id: ClientId) {
+        if let Some(active_tab) = self.get_active_tab_mut(client_id) {
+            if !active_tab.move_focus_left(client_id) {
+                self.switch_tab_prev(client_id);
+            }
         }
     }
-    pub fn move_focus_right_or_next_tab(&mut self) {
-        if !self.get_active_tab_mut().unwrap().move_focus_right() {
-            self.switch_tab_next();
+    pub fn move_focus_right_or_next_tab(&mut self, client_id: ClientId) {
+        if let Some(active_tab) = self.get_active_tab_mut(client_id) {
+            if !active_tab.move_focus_right(client_id) {
+                self.switch_tab_next(client_id);
+            }
         }
     }
 }
@@ -453,271 +679,334 @@ pub(crate) fn screen_thread_main(
         err_ctx.add_call(ContextType::Screen((&event).into()));
         match event {
             ScreenInstruction::PtyBytes(pid, vte_bytes) => {
-                let active_tab = screen.get_active_tab_mut().unwrap();
-                if active_tab.has_terminal_pid(pid) {
-                    // it's most likely that this event is directed at the active tab
-                    // look there first
-                    active_tab.handle_pty_bytes(pid, vte_bytes);
-                } else {
-                    // if this event wasn't directed at the active tab, start looking
-                    // in other tabs
-                    let all_tabs = screen.get_tabs_mut();
-                    for tab in all_tabs.values_mut() {
-                        if tab.has_terminal_pid(pid) {
-                            tab.handle_pty_bytes(pid, vte_bytes);
-                            break;
-                        }
+                for tab in screen.get_tabs_mut().values_mut() {
+                    if tab.has_terminal_pid(pid) {
+                        tab.handle_pty_bytes(pid, vte_bytes);
+                        break;
                     }
                 }
             }
             ScreenInstruction::Render => {
                 screen.render();
             }
-            ScreenInstruction::NewPane(pid) => {
-                screen.get_active_tab_mut().unwrap().new_pane(pid);
+            ScreenInstruction::NewPane(pid, client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.new_pane(pid, client_id);
+                } else {
+                    // the client detached while the terminal was spawned
+                    screen
+                        .bus
+                        .senders
+                        .send_to_pty(PtyInstruction::ClosePane(pid))
+                        .unwrap();
+                }
                 screen
                     .bus
                     .senders
                     .send_to_server(ServerInstruction::UnblockInputThread)
                     .unwrap();
             }
-            ScreenInstruction::HorizontalSplit(pid) => {
-                screen.get_active_tab_mut().unwrap().horizontal_split(pid);
+            ScreenInstruction::HorizontalSplit(pid, client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.horizontal_split(pid, client_id);
+                } else {
+                    // the client detached while the terminal was spawned
+                    screen
+                        .bus
+                        .senders
+                        .send_to_pty(PtyInstruction::ClosePane(pid))
+                        .unwrap();
+                }
                 screen
                     .bus
                     .senders
                     .send_to_server(ServerInstruction::UnblockInputThread)
                     .unwrap();
             }
-            ScreenInstruction::VerticalSplit(pid) => {
-                screen.get_active_tab_mut().unwrap().vertical_split(pid);
+            ScreenInstruction::VerticalSplit(pid, client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.vertical_split(pid, client_id);
+                } else {
+                    // the client detached while the terminal was spawned
+                    screen
+                        .bus
+                        .senders
+                        .send_to_pty(PtyInstruction::ClosePane(pid))
+                        .unwrap();
+                }
                 screen
                     .bus
                     .senders
                     .send_to_server(ServerInstruction::UnblockInputThread)
                     .unwrap();
             }
-            ScreenInstruction::WriteCharacter(bytes) => {
-                let active_tab = screen.get_active_tab_mut().unwrap();
-                match active_tab.is_sync_panes_active() {
-                    true => active_tab.write_to_terminals_on_current_tab(bytes),
-                    false => active_tab.write_to_active_terminal(bytes),
+            ScreenInstruction::WriteCharacter(bytes, client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    match active_tab.is_sync_panes_active() {
+                        true => active_tab.write_to_terminals_on_current_tab(bytes, client_id),
+                        false => active_tab.write_to_active_terminal(bytes, client_id),
+                    }
                 }
             }
-            ScreenInstruction::ResizeLeft => {
-                screen.get_active_tab_mut().unwrap().resize_left();
+            ScreenInstruction::ResizeLeft(client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.resize_left(client_id);
+                }
             }
-            ScreenInstruction::ResizeRight => {
-                screen.get_active_tab_mut().unwrap().resize_right();
+            ScreenInstruction::ResizeRight(client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.resize_right(client_id);
+                }
             }
-            ScreenInstruction::ResizeDown => {
-                screen.get_active_tab_mut().unwrap().resize_down();
+            ScreenInstruction::ResizeDown(client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.resize_down(client_id);
+                }
             }
-            ScreenInstruction::ResizeUp => {
-                screen.get_active_tab_mut().unwrap().resize_up();
+            ScreenInstruction::ResizeUp(client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.resize_up(client_id);
+                }
             }
-            ScreenInstruction::SwitchFocus => {
-                screen.get_active_tab_mut().unwrap().move_focus();
+            ScreenInstruction::SwitchFocus(client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.move_focus(client_id);
+                }
             }
-            ScreenInstruction::FocusNextPane => {
-                screen.get_active_tab_mut().unwrap().focus_next_pane();
+            ScreenInstruction::FocusNextPane(client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.focus_next_pane(client_id);
+                }
             }
-            ScreenInstruction::FocusPreviousPane => {
-                screen.get_active_tab_mut().unwrap().focus_previous_pane();
+            ScreenInstruction::FocusPreviousPane(client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.focus_previous_pane(client_id);
+                }
             }
-            ScreenInstruction::MoveFocusLeft => {
-                screen.get_active_tab_mut().unwrap().move_focus_left();
+            ScreenInstruction::MoveFocusLeft(client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.move_focus_left(client_id);
+                }
             }
-            ScreenInstruction::MoveFocusLeftOrPreviousTab => {
-                screen.move_focus_left_or_previous_tab();
+            ScreenInstruction::MoveFocusLeftOrPreviousTab(client_id) => {
+                screen.move_focus_left_or_previous_tab(client_id);
                 screen
                     .bus
                     .senders
                     .send_to_server(ServerInstruction::UnblockInputThread)
                     .unwrap();
             }
-            ScreenInstruction::MoveFocusDown => {
-                screen.get_active_tab_mut().unwrap().move_focus_down();
+            ScreenInstruction::MoveFocusDown(client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.move_focus_down(client_id);
+                }
             }
-            ScreenInstruction::MoveFocusRight => {
-                screen.get_active_tab_mut().unwrap().move_focus_right();
+            ScreenInstruction::MoveFocusRight(client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.move_focus_right(client_id);
+                }
             }
-            ScreenInstruction::MoveFocusRightOrNextTab => {
-                screen.move_focus_right_or_next_tab();
+            ScreenInstruction::MoveFocusRightOrNextTab(client_id) => {
+                screen.move_focus_right_or_next_tab(client_id);
                 screen
                     .bus
                     .senders
                     .send_to_server(ServerInstruction::UnblockInputThread)
                     .unwrap();
             }
-            ScreenInstruction::MoveFocusUp => {
-                screen.get_active_tab_mut().unwrap().move_focus_up();
+            ScreenInstruction::MoveFocusUp(client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.move_focus_up(client_id);
+                }
             }
-            ScreenInstruction::ScrollUp => {
-                screen
-                    .get_active_tab_mut()
-                    .unwrap()
-                    .scroll_active_terminal_up();
+            ScreenInstruction::ScrollUp(client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.scroll_active_terminal_up(client_id);
+                }
             }
-            ScreenInstruction::ScrollUpAt(point) => {
-                screen
-                    .get_active_tab_mut()
-                    .unwrap()
-                    .scroll_terminal_up(&point, 3);
+            ScreenInstruction::ScrollUpAt(point, client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.scroll_terminal_up(&point, 3, client_id);
+                }
             }
-            ScreenInstruction::ScrollDown => {
-                screen
-                    .get_active_tab_mut()
-                    .unwrap()
-                    .scroll_active_terminal_down();
+            ScreenInstruction::ScrollDown(client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.scroll_active_terminal_down(client_id);
+                }
             }
-            ScreenInstruction::ScrollDownAt(point) => {
-                screen
-                    .get_active_tab_mut()
-                    .unwrap()
-                    .scroll_terminal_down(&point, 3);
+            ScreenInstruction::ScrollDownAt(point, client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.scroll_terminal_down(&point, 3, client_id);
+                }
             }
-            ScreenInstruction::PageScrollUp => {
-                screen
-                    .get_active_tab_mut()
-                    .unwrap()
-                    .scroll_active_terminal_up_page();
+            ScreenInstruction::PageScrollUp(client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.scroll_active_terminal_up_page(client_id);
+                }
             }
-            ScreenInstruction::PageScrollDown => {
-                screen
-                    .get_active_tab_mut()
-                    .unwrap()
-                    .scroll_active_terminal_down_page();
+            ScreenInstruction::PageScrollDown(client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.scroll_active_terminal_down_page(client_id);
+                }
             }
-            ScreenInstruction::ClearScroll => {
-                screen
-                    .get_active_tab_mut()
-                    .unwrap()
-                    .clear_active_terminal_scroll();
+            ScreenInstruction::ClearScroll(client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.clear_active_terminal_scroll(client_id);
+                }
             }
-            ScreenInstruction::CloseFocusedPane => {
-                screen.get_active_tab_mut().unwrap().close_focused_pane();
+            ScreenInstruction::CloseFocusedPane(client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.close_focused_pane(client_id);
+                }
                 screen.render();
             }
             ScreenInstruction::SetSelectable(id, selectable) => {
-                screen
-                    .get_active_tab_mut()
-                    .unwrap()
-                    .set_pane_selectable(id, selectable);
+                for tab in screen.get_tabs_mut().values_mut() {
+                    tab.set_pane_selectable(id, selectable);
+                }
             }
             ScreenInstruction::SetFixedHeight(id, fixed_height) => {
-                screen
-                    .get_active_tab_mut()
-                    .unwrap()
-                    .set_pane_fixed_height(id, fixed_height);
+                for tab in screen.get_tabs_mut().values_mut() {
+                    tab.set_pane_fixed_height(id, fixed_height);
+                }
             }
             ScreenInstruction::SetFixedWidth(id, fixed_width) => {
-                screen
-                    .get_active_tab_mut()
-                    .unwrap()
-                    .set_pane_fixed_width(id, fixed_width);
+                for tab in screen.get_tabs_mut().values_mut() {
+                    tab.set_pane_fixed_width(id, fixed_width);
+                }
             }
             ScreenInstruction::SetInvisibleBorders(id, invisible_borders) => {
-                screen
-                    .get_active_tab_mut()
-                    .unwrap()
-                    .set_pane_invisible_borders(id, invisible_borders);
+                for tab in screen.get_tabs_mut().values_mut() {
+                    tab.set_pane_invisible_borders(id, invisible_borders);
+                }
                 screen.render();
             }
             ScreenInstruction::ClosePane(id) => {
-                screen.get_active_tab_mut().unwrap().close_pane(id);
+                for tab in screen.get_tabs_mut().values_mut() {
+                    tab.close_pane(id);
+                }
                 screen.render();
             }
-            ScreenInstruction::ToggleActiveTerminalFullscreen => {
-                screen
-                    .get_active_tab_mut()
-                    .unwrap()
-                    .toggle_active_pane_fullscreen();
+            ScreenInstruction::ToggleActiveTerminalFullscreen(client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.toggle_active_pane_fullscreen(client_id);
+                }
             }
-            ScreenInstruction::NewTab(pane_id) => {
-                screen.new_tab(pane_id);
+            ScreenInstruction::NewTab(pane_id, client_id) => {
+                screen.new_tab(pane_id, client_id);
                 screen
                     .bus
                     .senders
                     .send_to_server(ServerInstruction::UnblockInputThread)
                     .unwrap();
             }
-            ScreenInstruction::SwitchTabNext => {
-                screen.switch_tab_next();
+            ScreenInstruction::SwitchTabNext(client_id) => {
+                screen.switch_tab_next(client_id);
                 screen
                     .bus
                     .senders
                     .send_to_server(ServerInstruction::UnblockInputThread)
                     .unwrap();
             }
-            ScreenInstruction::SwitchTabPrev => {
-                screen.switch_tab_prev();
+            ScreenInstruction::SwitchTabPrev(client_id) => {
+                screen.switch_tab_prev(client_id);
                 screen
                     .bus
                     .senders
                     .send_to_server(ServerInstruction::UnblockInputThread)
                     .unwrap();
             }
-            ScreenInstruction::CloseTab => {
-                screen.close_tab();
+            ScreenInstruction::CloseTab(client_id) => {
+                screen.close_tab(client_id);
                 screen
                     .bus
                     .senders
                     .send_to_server(ServerInstruction::UnblockInputThread)
                     .unwrap();
             }
-            ScreenInstruction::ApplyLayout(layout, new_pane_pids) => {
-                screen.apply_layout(layout, new_pane_pids);
+            ScreenInstruction::ApplyLayout(layout, new_pane_pids, tab_name, client_id) => {
+                screen.apply_layout(layout, new_pane_pids, tab_name, client_id);
                 screen
                     .bus
                     .senders
                     .send_to_server(ServerInstruction::UnblockInputThread)
                     .unwrap();
             }
-            ScreenInstruction::GoToTab(tab_index) => {
-                screen.go_to_tab(tab_index as usize);
+            ScreenInstruction::GoToTab(tab_index, client_id) => {
+                screen.go_to_tab(tab_index as usize, client_id);
                 screen
                     .bus
                     .senders
                     .send_to_server(ServerInstruction::UnblockInputThread)
                     .unwrap();
             }
-            ScreenInstruction::UpdateTabName(c) => {
-                screen.update_active_tab_name(c);
+            ScreenInstruction::UpdateTabName(c, client_id) => {
+                screen.update_active_tab_name(c, client_id);
             }
             ScreenInstruction::TerminalResize(new_size) => {
                 screen.resize_to_screen(new_size);
             }
-            ScreenInstruction::ChangeMode(mode_info) => {
-                screen.change_mode(mode_info);
+            ScreenInstruction::SetCharacterCellSize(character_cell_size) => {
+                screen.set_character_cell_size(character_cell_size);
             }
-            ScreenInstruction::ToggleActiveSyncTab => {
-                screen
-                    .get_active_tab_mut()
-                    .unwrap()
-                    .toggle_sync_panes_is_active();
+            ScreenInstruction::ChangeMode(mode_info, client_id) => {
+                screen.change_mode(mode_info, client_id);
+            }
+            ScreenInstruction::ToggleActiveSyncTab(client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.toggle_sync_panes_is_active();
+                }
                 screen.update_tabs();
             }
-            ScreenInstruction::LeftClick(point) => {
-                screen
-                    .get_active_tab_mut()
-                    .unwrap()
-                    .handle_left_click(&point);
+            ScreenInstruction::LeftClick(point, client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.handle_left_click(&point, client_id);
+                }
             }
-            ScreenInstruction::MouseRelease(point) => {
-                screen
-                    .get_active_tab_mut()
-                    .unwrap()
-                    .handle_mouse_release(&point);
+            ScreenInstruction::MouseRelease(point, client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.handle_mouse_release(&point, client_id);
+                }
+            }
+            ScreenInstruction::MouseHold(point, client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.handle_mouse_hold(&point, client_id);
+                }
+            }
+            ScreenInstruction::MouseEvent(event, modifiers, client_id) => {
+                if let Some(active_tab) = screen.get_active_tab_mut(client_id) {
+                    active_tab.handle_mouse_event(&event, modifiers, client_id);
+                }
+            }
+            ScreenInstruction::Copy(client_id) => {
+                if let Some(active_tab) = screen.get_active_tab(client_id) {
+                    active_tab.copy_selection(client_id);
+                }
+            }
+            ScreenInstruction::AddClient(client_id) => {
+                screen.add_client(client_id);
+            }
+            ScreenInstruction::RemoveClient(client_id) => {
+                screen.remove_client(client_id);
+                screen.update_tabs();
+                screen.render();
             }
-            ScreenInstruction::MouseHold(point) => {
+            ScreenInstruction::QuerySession(requester) => {
+                let scrollback_lines = match requester {
+                    SessionInfoRequester::Client(_) | SessionInfoRequester::DumpLayout { .. } => 0,
+                    SessionInfoRequester::Resurrection { scrollback_lines } => scrollback_lines,
+                };
                 screen
-                    .get_active_tab_mut()
-                    .unwrap()
-                    .handle_mouse_hold(&point);
+                    .bus
+                    .senders
+                    .send_to_pty(PtyInstruction::FillSessionInfo(
+                        Box::new(screen.session_info(scrollback_lines)),
+                        requester,
+                    ))
+                    .unwrap();
             }
-            ScreenInstruction::Copy => {
-                screen.get_active_tab().unwrap().copy_selection();
+            ScreenInstruction::UpdateForegroundCommands => {
+                screen.update_foreground_commands();
             }
             ScreenInstruction::Exit => {
                 break;
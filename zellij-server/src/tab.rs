@@ -9,28 +9,34 @@ use crate::ui::pane_resizer::PaneResizer;
 use crate::ui::pane_resizer_beta::PaneResizer;
 use crate::{
     os_input_output::ServerOsApi,
-    panes::{PaneId, PluginPane, TerminalPane},
+    panes::{ClipboardRequest, PaneId, PluginPane, TerminalPane},
     pty::{PtyInstruction, VteBytes},
     thread_bus::ThreadSenders,
     ui::boundaries::Boundaries,
     wasm_vm::PluginInstruction,
-    ServerInstruction, SessionState,
+    ClientId, ServerInstruction, SessionState,
 };
 use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
 use std::os::unix::io::RawFd;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
 use std::sync::{mpsc::channel, Arc, RwLock};
 use std::time::Instant;
 use std::{
     cmp::Reverse,
-    collections::{BTreeMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
 };
 use zellij_tile::data::{Event, InputMode, ModeInfo, Palette};
 use zellij_utils::{
+    input::options::ClipboardAccess,
     input::{
         layout::{Layout, Run},
+        mouse::{MouseButton, MouseEvent, MouseModifiers},
         parse_keys,
     },
-    pane_size::PositionAndSize,
+    ipc::PaneInfo,
+    pane_size::{PositionAndSize, SizeInPixels},
     shared::adjust_to_size,
 };
 
@@ -77,7 +83,8 @@ pub(crate) struct Tab {
     pub name: String,
     panes: BTreeMap<PaneId, Box<dyn Pane>>,
     panes_to_hide: HashSet<PaneId>,
-    active_terminal: Option<PaneId>,
+    active_panes: BTreeMap<ClientId, PaneId>,
+    connected_clients: HashSet<ClientId>,
     max_panes: Option<usize>,
     full_screen_ws: PositionAndSize,
     fullscreen_is_active: bool,
@@ -86,7 +93,13 @@ pub(crate) struct Tab {
     synchronize_is_active: bool,
     should_clear_display_before_rendering: bool,
     session_state: Arc<RwLock<SessionState>>,
-    pub mode_info: ModeInfo,
+    // the copies to the clipboard waiting for the user to allow them, as `(selection, base64_text)`
+    pending_clipboard_copies: HashMap<PaneId, (String, String)>,
+    // the command in the foreground of the terminal panes without a name or title, which is
+    // too slow to look up on every render, see `update_foreground_commands`
+    foreground_commands: HashMap<PaneId, String>,
+    character_cell_size: Rc<RefCell<Option<SizeInPixels>>>,
+    pub mode_info: BTreeMap<ClientId, ModeInfo>,
     pub input_mode: InputMode,
     pub colors: Palette,
 }
@@ -124,7 +137,7 @@ pub trait Pane {
     fn set_invisible_borders(&mut self, invisible_borders: bool);
     fn set_fixed_height(&mut self, fixed_height: usize);
     fn set_fixed_width(&mut self, fixed_width: usize);
-    fn render(&mut self) -> Option<String>;
+    fn render(&mut self, client_id: Option<ClientId>) -> Option<String>;
     fn pid(&self) -> PaneId;
     fn reduce_height_down(&mut self, count: usize);
     fn increase_height_down(&mut self, count: usize);
@@ -159,6 +172,30 @@ pub trait Pane {
     fn get_selected_text(&self) -> Option<String> {
         None
     }
+    /// The last `count` lines this pane displayed, including its scrollback
+    fn last_lines(&self, _count: usize) -> Vec<String> {
+        vec![]
+    }
+    /// The location of the plugin running in this pane
+    fn plugin_location(&self) -> Option<PathBuf> {
+        None
+    }
+    /// The name given to this pane by the layout it was opened with
+    fn name(&self) -> Option<String>;
+    fn set_name(&mut self, name: Option<String>);
+    /// The window title set by the program running in this pane
+    fn title(&self) -> Option<String> {
+        None
+    }
+    /// Reports `event`, positioned relatively to the pane, to the program running in it,
+    /// returning the report if it tracks such events
+    fn mouse_event_report(
+        &mut self,
+        _event: &MouseEvent,
+        _modifiers: MouseModifiers,
+    ) -> Option<Vec<u8>> {
+        None
+    }
 
     fn right_boundary_x_coords(&self) -> usize {
         self.x() + self.columns()
@@ -238,6 +275,9 @@ pub trait Pane {
         // we should probably refactor away from this trait at some point
         vec![]
     }
+    fn drain_clipboard_requests(&mut self) -> Vec<ClipboardRequest> {
+        vec![]
+    }
     fn render_full_viewport(&mut self) {}
     fn relative_position(&self, position: &Position) -> Position {
         match self.position_and_size_override() {
@@ -259,13 +299,15 @@ impl Tab {
         senders: ThreadSenders,
         max_panes: Option<usize>,
         pane_id: Option<PaneId>,
-        mode_info: ModeInfo,
+        mode_info: BTreeMap<ClientId, ModeInfo>,
         input_mode: InputMode,
         colors: Palette,
         session_state: Arc<RwLock<SessionState>>,
+        character_cell_size: Rc<RefCell<Option<SizeInPixels>>>,
     ) -> Self {
         let panes = if let Some(PaneId::Terminal(pid)) = pane_id {
-            let new_terminal = TerminalPane::new(pid, *full_screen_ws, colors);
+            let new_terminal =
+                TerminalPane::new(pid, *full_screen_ws, colors, character_cell_size.clone());
             os_api.set_terminal_size_using_fd(
                 new_terminal.pid,
                 new_terminal.columns() as u16,
@@ -291,7 +333,8 @@ impl Tab {
             name,
             max_panes,
             panes_to_hide: HashSet::new(),
-            active_terminal: pane_id,
+            active_panes: BTreeMap::new(),
+            connected_clients: HashSet::new(),
             full_screen_ws: *full_screen_ws,
             fullscreen_is_active: false,
             synchronize_is_active: false,
@@ -302,6 +345,9 @@ impl Tab {
             input_mode,
             colors,
             session_state,
+            pending_clipboard_copies: HashMap::new(),
+            foreground_commands: HashMap::new(),
+            character_cell_size,
         }
     }
 
@@ -317,11 +363,16 @@ impl Tab {
         self.panes_to_hide.clear();
         let positions_in_layout = layout.position_panes_in_space(&free_space);
         let mut positions_and_size = positions_in_layout.iter();
+        let mut focused_pane_id = None;
         for (pane_kind, terminal_pane) in self.panes.iter_mut() {
             // for now the layout only supports terminal panes
             if let PaneId::Terminal(pid) = pane_kind {
                 match positions_and_size.next() {
-                    Some((_, position_and_size)) => {
+                    Some((layout, position_and_size)) => {
+                        if layout.focus {
+                            focused_pane_id = Some(*pane_kind);
+                        }
+                        terminal_pane.set_name(layout.name.clone());
                         terminal_pane.reset_size_and_position_override();
                         terminal_pane.change_pos_and_size(position_and_size);
                         self.os_api.set_terminal_size_using_fd(
@@ -347,23 +398,37 @@ impl Tab {
                     .send_to_plugin(PluginInstruction::Load(pid_tx, plugin.clone()))
                     .unwrap();
                 let pid = pid_rx.recv().unwrap();
-                let new_plugin = PluginPane::new(
+                let mut new_plugin = PluginPane::new(
                     pid,
+                    plugin.clone(),
                     *position_and_size,
                     self.senders.to_plugin.as_ref().unwrap().clone(),
                 );
+                new_plugin.set_name(layout.name.clone());
                 self.panes.insert(PaneId::Plugin(pid), Box::new(new_plugin));
+                if layout.focus {
+                    focused_pane_id = Some(PaneId::Plugin(pid));
+                }
                 // Send an initial mode update to the newly loaded plugin only!
-                self.senders
-                    .send_to_plugin(PluginInstruction::Update(
-                        Some(pid),
-                        Event::ModeUpdate(self.mode_info.clone()),
-                    ))
-                    .unwrap();
+                for (&client_id, mode_info) in &self.mode_info {
+                    self.senders
+                        .send_to_plugin(PluginInstruction::Update(
+                            Some(pid),
+                            Some(client_id),
+                            Event::ModeUpdate(mode_info.clone()),
+                        ))
+                        .unwrap();
+                }
             } else {
                 // there are still panes left to fill, use the pids we received in this method
                 let pid = new_pids.next().unwrap(); // if this crashes it means we got less pids than there are panes in this layout
-                let new_terminal = TerminalPane::new(*pid, *position_and_size, self.colors);
+                let mut new_terminal = TerminalPane::new(
+                    *pid,
+                    *position_and_size,
+                    self.colors,
+                    self.character_cell_size.clone(),
+                );
+                new_terminal.set_name(layout.name.clone());
                 self.os_api.set_terminal_size_using_fd(
                     new_terminal.pid,
                     new_terminal.columns() as u16,
@@ -371,6 +436,9 @@ impl Tab {
                 );
                 self.panes
                     .insert(PaneId::Terminal(*pid), Box::new(new_terminal));
+                if layout.focus {
+                    focused_pane_id = Some(PaneId::Terminal(*pid));
+                }
             }
         }
         for unused_pid in new_pids {
@@ -381,24 +449,39 @@ impl Tab {
                 .send_to_pty(PtyInstruction::ClosePane(PaneId::Terminal(*unused_pid)))
                 .unwrap();
         }
-        self.active_terminal = self.panes.iter().map(|(id, _)| id.to_owned()).next();
+        // the pane marked as focused by the layout, or else the first one
+        let focused_pane_id = focused_pane_id.or_else(|| self.panes.keys().next().copied());
+        if let Some(focused_pane_id) = focused_pane_id {
+            for &client_id in &self.connected_clients {
+                self.active_panes.insert(client_id, focused_pane_id);
+            }
+        }
         self.render();
     }
-    pub fn new_pane(&mut self, pid: PaneId) {
+    pub fn new_pane(&mut self, pid: PaneId, client_id: ClientId) {
         self.close_down_to_max_terminals();
         if self.fullscreen_is_active {
-            self.toggle_active_pane_fullscreen();
+            self.unset_fullscreen();
         }
         if !self.has_panes() {
             if let PaneId::Terminal(term_pid) = pid {
-                let new_terminal = TerminalPane::new(term_pid, self.full_screen_ws, self.colors);
+                let new_terminal = TerminalPane::new(
+                    term_pid,
+                    self.full_screen_ws,
+                    self.colors,
+                    self.character_cell_size.clone(),
+                );
                 self.os_api.set_terminal_size_using_fd(
                     new_terminal.pid,
                     new_terminal.columns() as u16,
                     new_terminal.rows() as u16,
                 );
                 self.panes.insert(pid, Box::new(new_terminal));
-                self.active_terminal = Some(pid);
+                // this is the first pane in the tab, so everyone looking at it is focused on it
+                for &connected_client_id in &self.connected_clients {
+                    self.active_panes.insert(connected_client_id, pid);
+                }
+                self.active_panes.insert(client_id, pid);
             }
         } else {
             // TODO: check minimum size of active terminal
@@ -441,7 +524,12 @@ impl Tab {
             {
                 if let PaneId::Terminal(term_pid) = pid {
                     let (top_winsize, bottom_winsize) = split_horizontally_with_gap(&terminal_ws);
-                    let new_terminal = TerminalPane::new(term_pid, bottom_winsize, self.colors);
+                    let new_terminal = TerminalPane::new(
+                        term_pid,
+                        bottom_winsize,
+                        self.colors,
+                        self.character_cell_size.clone(),
+                    );
                     self.os_api.set_terminal_size_using_fd(
                         new_terminal.pid,
                         bottom_winsize.cols as u16,
@@ -456,12 +544,17 @@ impl Tab {
                             top_winsize.rows as u16,
                         );
                     }
-                    self.active_terminal = Some(pid);
+                    self.active_panes.insert(client_id, pid);
                 }
             } else if terminal_to_split.columns() > terminal_to_split.min_width() * 2 {
                 if let PaneId::Terminal(term_pid) = pid {
                     let (left_winsize, right_winsize) = split_vertically_with_gap(&terminal_ws);
-                    let new_terminal = TerminalPane::new(term_pid, right_winsize, self.colors);
+                    let new_terminal = TerminalPane::new(
+                        term_pid,
+                        right_winsize,
+                        self.colors,
+                        self.character_cell_size.clone(),
+                    );
                     self.os_api.set_terminal_size_using_fd(
                         new_terminal.pid,
                         right_winsize.cols as u16,
@@ -478,29 +571,38 @@ impl Tab {
                     }
                 }
             }
-            self.active_terminal = Some(pid);
+            self.active_panes.insert(client_id, pid);
             self.render();
         }
     }
-    pub fn horizontal_split(&mut self, pid: PaneId) {
+    pub fn horizontal_split(&mut self, pid: PaneId, client_id: ClientId) {
         self.close_down_to_max_terminals();
         if self.fullscreen_is_active {
-            self.toggle_active_pane_fullscreen();
+            self.unset_fullscreen();
         }
         if !self.has_panes() {
             if let PaneId::Terminal(term_pid) = pid {
-                let new_terminal = TerminalPane::new(term_pid, self.full_screen_ws, self.colors);
+                let new_terminal = TerminalPane::new(
+                    term_pid,
+                    self.full_screen_ws,
+                    self.colors,
+                    self.character_cell_size.clone(),
+                );
                 self.os_api.set_terminal_size_using_fd(
                     new_terminal.pid,
                     new_terminal.columns() as u16,
                     new_terminal.rows() as u16,
                 );
                 self.panes.insert(pid, Box::new(new_terminal));
-                self.active_terminal = Some(pid);
+                // this is the first pane in the tab, so everyone looking at it is focused on it
+                for &connected_client_id in &self.connected_clients {
+                    self.active_panes.insert(connected_client_id, pid);
+                }
+                self.active_panes.insert(client_id, pid);
             }
         } else if let PaneId::Terminal(term_pid) = pid {
             // TODO: check minimum size of active terminal
-            let active_pane_id = &self.get_active_pane_id().unwrap();
+            let active_pane_id = &self.get_active_pane_id(client_id).unwrap();
             let active_pane = self.panes.get_mut(active_pane_id).unwrap();
             if active_pane.rows() < MIN_TERMINAL_HEIGHT * 2 + 1 {
                 self.senders
@@ -519,7 +621,12 @@ impl Tab {
 
             active_pane.change_pos_and_size(&top_winsize);
 
-            let new_terminal = TerminalPane::new(term_pid, bottom_winsize, self.colors);
+            let new_terminal = TerminalPane::new(
+                term_pid,
+                bottom_winsize,
+                self.colors,
+                self.character_cell_size.clone(),
+            );
             self.os_api.set_terminal_size_using_fd(
                 new_terminal.pid,
                 bottom_winsize.cols as u16,
@@ -535,29 +642,38 @@ impl Tab {
                 );
             }
 
-            self.active_terminal = Some(pid);
+            self.active_panes.insert(client_id, pid);
             self.render();
         }
     }
-    pub fn vertical_split(&mut self, pid: PaneId) {
+    pub fn vertical_split(&mut self, pid: PaneId, client_id: ClientId) {
         self.close_down_to_max_terminals();
         if self.fullscreen_is_active {
-            self.toggle_active_pane_fullscreen();
+            self.unset_fullscreen();
         }
         if !self.has_panes() {
             if let PaneId::Terminal(term_pid) = pid {
-                let new_terminal = TerminalPane::new(term_pid, self.full_screen_ws, self.colors);
+                let new_terminal = TerminalPane::new(
+                    term_pid,
+                    self.full_screen_ws,
+                    self.colors,
+                    self.character_cell_size.clone(),
+                );
                 self.os_api.set_terminal_size_using_fd(
                     new_terminal.pid,
                     new_terminal.columns() as u16,
                     new_terminal.rows() as u16,
                 );
                 self.panes.insert(pid, Box::new(new_terminal));
-                self.active_terminal = Some(pid);
+                // this is the first pane in the tab, so everyone looking at it is focused on it
+                for &connected_client_id in &self.connected_clients {
+                    self.active_panes.insert(connected_client_id, pid);
+                }
+                self.active_panes.insert(client_id, pid);
             }
         } else if let PaneId::Terminal(term_pid) = pid {
             // TODO: check minimum size of active terminal
-            let active_pane_id = &self.get_active_pane_id().unwrap();
+            let active_pane_id = &self.get_active_pane_id(client_id).unwrap();
             let active_pane = self.panes.get_mut(active_pane_id).unwrap();
             if active_pane.columns() < MIN_TERMINAL_WIDTH * 2 + 1 {
                 self.senders
@@ -576,7 +692,12 @@ impl Tab {
 
             active_pane.change_pos_and_size(&left_winsize);
 
-            let new_terminal = TerminalPane::new(term_pid, right_winsize, self.colors);
+            let new_terminal = TerminalPane::new(
+                term_pid,
+                right_winsize,
+                self.colors,
+                self.character_cell_size.clone(),
+            );
             self.os_api.set_terminal_size_using_fd(
                 new_terminal.pid,
                 right_winsize.cols as u16,
@@ -592,23 +713,44 @@ impl Tab {
                 );
             }
 
-            self.active_terminal = Some(pid);
+            self.active_panes.insert(client_id, pid);
             self.render();
         }
     }
-    pub fn get_active_pane(&self) -> Option<&dyn Pane> {
+    /// Starts displaying this tab to the given client, focusing the same pane as the clients
+    /// already looking at it (or the first selectable pane, if there are none).
+    pub fn add_client(&mut self, client_id: ClientId) {
+        let pane_id = self
+            .connected_clients
+            .iter()
+            .find_map(|other_client_id| self.active_panes.get(other_client_id).copied())
+            .or_else(|| self.next_active_pane(self.get_pane_ids()));
+        if let Some(pane_id) = pane_id {
+            self.active_panes.insert(client_id, pane_id);
+        }
+        self.connected_clients.insert(client_id);
+        self.set_force_render();
+    }
+    pub fn remove_client(&mut self, client_id: ClientId) {
+        self.connected_clients.remove(&client_id);
+        self.active_panes.remove(&client_id);
+    }
+    pub fn has_clients(&self) -> bool {
+        !self.connected_clients.is_empty()
+    }
+    pub fn get_active_pane(&self, client_id: ClientId) -> Option<&dyn Pane> {
         // FIXME: Could use Option::map() here
-        match self.get_active_pane_id() {
+        match self.get_active_pane_id(client_id) {
             Some(active_pane) => self.panes.get(&active_pane).map(Box::as_ref),
             None => None,
         }
     }
-    fn get_active_pane_id(&self) -> Option<PaneId> {
-        self.active_terminal
+    fn get_active_pane_id(&self, client_id: ClientId) -> Option<PaneId> {
+        self.active_panes.get(&client_id).copied()
     }
-    fn get_active_terminal_id(&self) -> Option<RawFd> {
+    fn get_active_terminal_id(&self, client_id: ClientId) -> Option<RawFd> {
         // FIXME: Is there a better way to do this?
-        if let Some(PaneId::Terminal(pid)) = self.active_terminal {
+        if let Some(PaneId::Terminal(pid)) = self.get_active_pane_id(client_id) {
             Some(pid)
         } else {
             None
@@ -626,26 +768,71 @@ impl Tab {
         if let Some(terminal_output) = self.panes.get_mut(&PaneId::Terminal(pid)) {
             terminal_output.handle_pty_bytes(bytes);
             let messages_to_pty = terminal_output.drain_messages_to_pty();
+            let clipboard_requests = terminal_output.drain_clipboard_requests();
             for message in messages_to_pty {
-                self.write_to_pane_id(message, PaneId::Terminal(pid));
+                self.write_to_pane_id(message, PaneId::Terminal(pid), None);
+            }
+            for request in clipboard_requests {
+                self.handle_clipboard_request(request, PaneId::Terminal(pid));
             }
             // self.render();
         }
     }
-    pub fn write_to_terminals_on_current_tab(&mut self, input_bytes: Vec<u8>) {
+    pub fn write_to_terminals_on_current_tab(&mut self, input_bytes: Vec<u8>, client_id: ClientId) {
         let pane_ids = self.get_pane_ids();
+        if self.answer_clipboard_prompts(&input_bytes, &pane_ids) {
+            return;
+        }
         pane_ids.iter().for_each(|&pane_id| {
-            self.write_to_pane_id(input_bytes.clone(), pane_id);
+            self.write_to_pane_id(input_bytes.clone(), pane_id, Some(client_id));
         });
     }
-    pub fn write_to_active_terminal(&mut self, input_bytes: Vec<u8>) {
-        self.write_to_pane_id(input_bytes, self.get_active_pane_id().unwrap());
+    pub fn write_to_active_terminal(&mut self, input_bytes: Vec<u8>, client_id: ClientId) {
+        let active_pane_id = self.get_active_pane_id(client_id).unwrap();
+        if self.answer_clipboard_prompts(&input_bytes, &[active_pane_id]) {
+            return;
+        }
+        self.write_to_pane_id(input_bytes, active_pane_id, Some(client_id));
+    }
+    /// Answers the clipboard prompts of `pane_ids` if the input is _y_, _n_ or _Esc_, returning
+    /// whether it did. Other input reaches the panes, leaving the prompts shown.
+    fn answer_clipboard_prompts(&mut self, input_bytes: &[u8], pane_ids: &[PaneId]) -> bool {
+        let allow = match input_bytes {
+            b"y" | b"Y" => true,
+            b"n" | b"N" | b"\x1b" => false,
+            _ => return false,
+        };
+        let mut answered = false;
+        for pane_id in pane_ids {
+            if let Some((selection, base64_text)) = self.pending_clipboard_copies.remove(pane_id) {
+                if allow {
+                    self.copy_to_clipboard(&selection, base64_text);
+                }
+                if let Some(pane) = self.panes.get_mut(pane_id) {
+                    pane.render_full_viewport();
+                    pane.set_should_render(true);
+                }
+                answered = true;
+            }
+        }
+        if answered {
+            self.render();
+        }
+        answered
     }
-    pub fn write_to_pane_id(&mut self, input_bytes: Vec<u8>, pane_id: PaneId) {
+    pub fn write_to_pane_id(
+        &mut self,
+        input_bytes: Vec<u8>,
+        pane_id: PaneId,
+        client_id: Option<ClientId>,
+    ) {
         match pane_id {
             PaneId::Terminal(active_terminal_id) => {
                 let active_terminal = self.panes.get(&pane_id).unwrap();
                 let adjusted_input = active_terminal.adjust_input_to_terminal(input_bytes);
+                if adjusted_input.is_empty() {
+                    return;
+                }
                 self.os_api
                     .write_to_tty_stdin(active_terminal_id, &adjusted_input)
                     .expect("failed to write to terminal");
@@ -656,15 +843,22 @@ impl Tab {
             PaneId::Plugin(pid) => {
                 for key in parse_keys(&input_bytes) {
                     self.senders
-                        .send_to_plugin(PluginInstruction::Update(Some(pid), Event::KeyPress(key)))
+                        .send_to_plugin(PluginInstruction::Update(
+                            Some(pid),
+                            client_id,
+                            Event::KeyPress(key),
+                        ))
                         .unwrap()
                 }
             }
         }
     }
-    pub fn get_active_terminal_cursor_position(&self) -> Option<(usize, usize)> {
+    pub fn get_active_terminal_cursor_position(
+        &self,
+        client_id: ClientId,
+    ) -> Option<(usize, usize)> {
         // (x, y)
-        let active_terminal = &self.get_active_pane()?;
+        let active_terminal = &self.get_active_pane(client_id)?;
         active_terminal
             .cursor_coordinates()
             .map(|(x_in_terminal, y_in_terminal)| {
@@ -673,8 +867,28 @@ impl Tab {
                 (x, y)
             })
     }
-    pub fn toggle_active_pane_fullscreen(&mut self) {
-        if let Some(active_pane_id) = self.get_active_pane_id() {
+    pub fn toggle_active_pane_fullscreen(&mut self, client_id: ClientId) {
+        if let Some(active_pane_id) = self.get_active_pane_id(client_id) {
+            self.toggle_pane_fullscreen(active_pane_id);
+        }
+    }
+    /// Leaves fullscreen, regardless of which client made the pane fullscreen.
+    fn unset_fullscreen(&mut self) {
+        // while fullscreen is active, every pane but the fullscreen one is hidden
+        let fullscreen_pane_id = self
+            .get_pane_ids()
+            .into_iter()
+            .find(|pane_id| !self.panes_to_hide.contains(pane_id));
+        match fullscreen_pane_id {
+            Some(fullscreen_pane_id) => self.toggle_pane_fullscreen(fullscreen_pane_id),
+            None => {
+                self.panes_to_hide.clear();
+                self.fullscreen_is_active = false;
+            }
+        }
+    }
+    fn toggle_pane_fullscreen(&mut self, active_pane_id: PaneId) {
+        if self.panes.contains_key(&active_pane_id) {
             if self.fullscreen_is_active {
                 for terminal_id in self.panes_to_hide.iter() {
                     self.panes
@@ -708,6 +922,11 @@ impl Tab {
                         self.full_screen_ws.y,
                         &self.full_screen_ws,
                     );
+                    // the other panes are hidden, so every client looking at this tab
+                    // is now focused on the fullscreen pane
+                    for active_pane in self.active_panes.values_mut() {
+                        *active_pane = active_pane_id;
+                    }
                 }
             }
             let active_terminal = self.panes.get(&active_pane_id).unwrap();
@@ -735,23 +954,108 @@ impl Tab {
     pub fn is_sync_panes_active(&self) -> bool {
         self.synchronize_is_active
     }
+    pub fn is_fullscreen_active(&self) -> bool {
+        self.fullscreen_is_active
+    }
+    /// Describes the panes of this tab, along with the last `scrollback_lines` lines of each
+    /// terminal pane. The process information of terminal panes is left for the pty thread
+    /// to fill in.
+    pub fn pane_infos(&self, scrollback_lines: usize) -> Vec<PaneInfo> {
+        self.panes
+            .iter()
+            .map(|(pane_id, pane)| {
+                let (id, is_plugin) = match *pane_id {
+                    PaneId::Terminal(fd) => (fd as u32, false),
+                    PaneId::Plugin(plugin_id) => (plugin_id, true),
+                };
+                let focused_clients = self
+                    .active_panes
+                    .iter()
+                    .filter(|(_, active_pane_id)| *active_pane_id == pane_id)
+                    .map(|(client_id, _)| *client_id)
+                    .collect();
+                PaneInfo {
+                    id,
+                    is_plugin,
+                    x: pane.x(),
+                    y: pane.y(),
+                    rows: pane.rows(),
+                    cols: pane.columns(),
+                    selectable: pane.selectable(),
+                    focused_clients,
+                    plugin_location: pane.plugin_location(),
+                    name: pane.name(),
+                    scrollback: if scrollback_lines > 0 && !is_plugin {
+                        Some(pane.last_lines(scrollback_lines))
+                    } else {
+                        None
+                    },
+                    ..Default::default()
+                }
+            })
+            .collect()
+    }
+    /// The title of each pane, as shown over its boundaries
+    pub fn pane_titles(&self) -> Vec<(PaneId, Option<String>)> {
+        self.panes
+            .iter()
+            .map(|(&pane_id, pane)| {
+                (
+                    pane_id,
+                    pane_title(pane.as_ref(), &self.foreground_commands),
+                )
+            })
+            .collect()
+    }
+    /// Looks up the command in the foreground of the panes that are titled by it, returning
+    /// whether any of them changed.
+    pub fn update_foreground_commands(&mut self) -> bool {
+        let os_api = self.os_api.as_ref();
+        let foreground_commands: HashMap<PaneId, String> = self
+            .panes
+            .iter()
+            .filter(|(_, pane)| pane.name().is_none() && pane.title().is_none())
+            .filter_map(|(&pane_id, _)| match pane_id {
+                PaneId::Terminal(fd) => Some((pane_id, foreground_command(os_api, fd)?)),
+                PaneId::Plugin(_) => None,
+            })
+            .collect();
+        if foreground_commands == self.foreground_commands {
+            return false;
+        }
+        self.foreground_commands = foreground_commands;
+        true
+    }
     pub fn toggle_sync_panes_is_active(&mut self) {
         self.synchronize_is_active = !self.synchronize_is_active;
     }
     pub fn render(&mut self) {
-        if self.active_terminal.is_none()
-            || *self.session_state.read().unwrap() != SessionState::Attached
-        {
+        let client_ids: Vec<ClientId> = self
+            .connected_clients
+            .iter()
+            .copied()
+            .filter(|&client_id| self.get_active_pane(client_id).is_some())
+            .collect();
+        if client_ids.is_empty() || !self.session_state.read().unwrap().is_attached() {
             // we might not have an active terminal if we closed the last pane
             // in that case, we should not render as the app is exiting
-            // or if this session is not attached to a client, we do not have to render
+            // or if no client is looking at this tab, we do not have to render
             return;
         }
+        // terminal panes look the same for everyone, plugin panes, boundaries and the cursor
+        // depend on the client's mode and focus
         let mut output = String::new();
-        let mut boundaries = Boundaries::new(
-            self.full_screen_ws.cols as u16,
-            self.full_screen_ws.rows as u16,
-        );
+        let mut client_outputs: HashMap<ClientId, String> = HashMap::new();
+        let mut client_boundaries: HashMap<ClientId, Boundaries> = client_ids
+            .iter()
+            .map(|&client_id| {
+                let boundaries = Boundaries::new(
+                    self.full_screen_ws.cols as u16,
+                    self.full_screen_ws.rows as u16,
+                );
+                (client_id, boundaries)
+            })
+            .collect();
         let hide_cursor = "\u{1b}[?25l";
         output.push_str(hide_cursor);
         if self.should_clear_display_before_rendering {
@@ -761,54 +1065,105 @@ impl Tab {
         }
         for (kind, pane) in self.panes.iter_mut() {
             if !self.panes_to_hide.contains(&pane.pid()) {
-                match self.active_terminal.unwrap() == pane.pid() {
-                    true => {
-                        pane.set_active_at(Instant::now());
-                        boundaries.add_rect(pane.as_ref(), self.mode_info.mode, Some(self.colors))
+                let title = pane_title(pane.as_ref(), &self.foreground_commands);
+                for &client_id in &client_ids {
+                    let boundaries = client_boundaries.get_mut(&client_id).unwrap();
+                    let input_mode = self
+                        .mode_info
+                        .get(&client_id)
+                        .map(|mode_info| mode_info.mode)
+                        .unwrap_or(self.input_mode);
+                    let palette = match self.active_panes.get(&client_id) == Some(&pane.pid()) {
+                        true => {
+                            pane.set_active_at(Instant::now());
+                            Some(self.colors)
+                        }
+                        false => None,
+                    };
+                    boundaries.add_rect(pane.as_ref(), input_mode, palette);
+                    if let Some(title) = title.as_ref() {
+                        boundaries.add_title(pane.as_ref(), title, palette);
                     }
-                    false => boundaries.add_rect(pane.as_ref(), self.mode_info.mode, None),
                 }
-                if let Some(vte_output) = pane.render() {
-                    let vte_output = if let PaneId::Terminal(_) = kind {
-                        vte_output
-                    } else {
-                        adjust_to_size(&vte_output, pane.rows(), pane.columns())
-                    };
-                    // FIXME: Use Termion for cursor and style clearing?
-                    output.push_str(&format!(
-                        "\u{1b}[{};{}H\u{1b}[m{}",
-                        pane.y() + 1,
-                        pane.x() + 1,
-                        vte_output
-                    ));
+                match kind {
+                    PaneId::Terminal(_) => {
+                        if let Some(vte_output) = pane.render(None) {
+                            // FIXME: Use Termion for cursor and style clearing?
+                            output.push_str(&format!(
+                                "\u{1b}[{};{}H\u{1b}[m{}",
+                                pane.y() + 1,
+                                pane.x() + 1,
+                                vte_output
+                            ));
+                        }
+                    }
+                    PaneId::Plugin(_) => {
+                        for &client_id in &client_ids {
+                            if let Some(vte_output) = pane.render(Some(client_id)) {
+                                let vte_output =
+                                    adjust_to_size(&vte_output, pane.rows(), pane.columns());
+                                client_outputs
+                                    .entry(client_id)
+                                    .or_default()
+                                    .push_str(&format!(
+                                        "\u{1b}[{};{}H\u{1b}[m{}",
+                                        pane.y() + 1,
+                                        pane.x() + 1,
+                                        vte_output
+                                    ));
+                            }
+                        }
+                    }
                 }
             }
         }
 
-        // TODO: only render (and calculate) boundaries if there was a resize
-        output.push_str(&boundaries.vte_output());
-
-        match self.get_active_terminal_cursor_position() {
-            Some((cursor_position_x, cursor_position_y)) => {
-                let show_cursor = "\u{1b}[?25h";
-                let change_cursor_shape = self.get_active_pane().unwrap().cursor_shape_csi();
-                let goto_cursor_position = &format!(
-                    "\u{1b}[{};{}H\u{1b}[m{}",
-                    cursor_position_y + 1,
-                    cursor_position_x + 1,
-                    change_cursor_shape
-                ); // goto row/col
-                output.push_str(show_cursor);
-                output.push_str(goto_cursor_position);
+        for (pane_id, pane) in &self.panes {
+            if self.pending_clipboard_copies.contains_key(pane_id)
+                && !self.panes_to_hide.contains(pane_id)
+            {
+                let prompt: String = " Allow copying to the clipboard? (y/n) "
+                    .chars()
+                    .take(pane.columns())
+                    .collect();
+                output.push_str(&format!(
+                    "\u{1b}[{};{}H\u{1b}[m\u{1b}[7m{}\u{1b}[m",
+                    pane.y() + pane.rows(),
+                    pane.x() + 1,
+                    prompt
+                ));
             }
-            None => {
-                let hide_cursor = "\u{1b}[?25l";
-                output.push_str(hide_cursor);
+        }
+
+        for &client_id in &client_ids {
+            let client_output = client_outputs.entry(client_id).or_default();
+            // TODO: only render (and calculate) boundaries if there was a resize
+            client_output.push_str(&client_boundaries[&client_id].vte_output());
+
+            match self.get_active_terminal_cursor_position(client_id) {
+                Some((cursor_position_x, cursor_position_y)) => {
+                    let show_cursor = "\u{1b}[?25h";
+                    let change_cursor_shape =
+                        self.get_active_pane(client_id).unwrap().cursor_shape_csi();
+                    let goto_cursor_position = &format!(
+                        "\u{1b}[{};{}H\u{1b}[m{}",
+                        cursor_position_y + 1,
+                        cursor_position_x + 1,
+                        change_cursor_shape
+                    ); // goto row/col
+                    client_output.push_str(show_cursor);
+                    client_output.push_str(goto_cursor_position);
+                }
+                None => {
+                    let hide_cursor = "\u{1b}[?25l";
+                    client_output.push_str(hide_cursor);
+                }
             }
+            client_output.insert_str(0, &output);
         }
 
         self.senders
-            .send_to_server(ServerInstruction::Render(Some(output)))
+            .send_to_server(ServerInstruction::Render(Some(client_outputs)))
             .unwrap();
     }
     fn get_panes(&self) -> impl Iterator<Item = (&PaneId, &Box<dyn Pane>)> {
@@ -822,7 +1177,7 @@ impl Tab {
         let mut all_terminals = self.get_panes();
         all_terminals.next().is_some()
     }
-    fn has_selectable_panes(&self) -> bool {
+    pub fn has_selectable_panes(&self) -> bool {
         let mut all_terminals = self.get_selectable_panes();
         all_terminals.next().is_some()
     }
@@ -1741,7 +2096,7 @@ impl Tab {
     pub fn resize_whole_tab(&mut self, new_screen_size: PositionAndSize) {
         if self.fullscreen_is_active {
             // this is not ideal, we can improve this
-            self.toggle_active_pane_fullscreen();
+            self.unset_fullscreen();
         }
         if let Some((column_difference, row_difference)) =
             PaneResizer::new(&mut self.panes, &mut self.os_api)
@@ -1754,10 +2109,10 @@ impl Tab {
                 (self.full_screen_ws.rows as isize + row_difference) as usize;
         };
     }
-    pub fn resize_left(&mut self) {
+    pub fn resize_left(&mut self, client_id: ClientId) {
         // TODO: find out by how much we actually reduced and only reduce by that much
         let count = 10;
-        if let Some(active_pane_id) = self.get_active_pane_id() {
+        if let Some(active_pane_id) = self.get_active_pane_id(client_id) {
             if self.can_increase_pane_and_surroundings_left(&active_pane_id, count) {
                 self.increase_pane_and_surroundings_left(&active_pane_id, count);
             } else if self.can_reduce_pane_and_surroundings_left(&active_pane_id, count) {
@@ -1766,10 +2121,10 @@ impl Tab {
         }
         self.render();
     }
-    pub fn resize_right(&mut self) {
+    pub fn resize_right(&mut self, client_id: ClientId) {
         // TODO: find out by how much we actually reduced and only reduce by that much
         let count = 10;
-        if let Some(active_pane_id) = self.get_active_pane_id() {
+        if let Some(active_pane_id) = self.get_active_pane_id(client_id) {
             if self.can_increase_pane_and_surroundings_right(&active_pane_id, count) {
                 self.increase_pane_and_surroundings_right(&active_pane_id, count);
             } else if self.can_reduce_pane_and_surroundings_right(&active_pane_id, count) {
@@ -1778,10 +2133,10 @@ impl Tab {
         }
         self.render();
     }
-    pub fn resize_down(&mut self) {
+    pub fn resize_down(&mut self, client_id: ClientId) {
         // TODO: find out by how much we actually reduced and only reduce by that much
         let count = 2;
-        if let Some(active_pane_id) = self.get_active_pane_id() {
+        if let Some(active_pane_id) = self.get_active_pane_id(client_id) {
             if self.can_increase_pane_and_surroundings_down(&active_pane_id, count) {
                 self.increase_pane_and_surroundings_down(&active_pane_id, count);
             } else if self.can_reduce_pane_and_surroundings_down(&active_pane_id, count) {
@@ -1790,10 +2145,10 @@ impl Tab {
         }
         self.render();
     }
-    pub fn resize_up(&mut self) {
+    pub fn resize_up(&mut self, client_id: ClientId) {
         // TODO: find out by how much we actually reduced and only reduce by that much
         let count = 2;
-        if let Some(active_pane_id) = self.get_active_pane_id() {
+        if let Some(active_pane_id) = self.get_active_pane_id(client_id) {
             if self.can_increase_pane_and_surroundings_up(&active_pane_id, count) {
                 self.increase_pane_and_surroundings_up(&active_pane_id, count);
             } else if self.can_reduce_pane_and_surroundings_up(&active_pane_id, count) {
@@ -1802,14 +2157,14 @@ impl Tab {
         }
         self.render();
     }
-    pub fn move_focus(&mut self) {
+    pub fn move_focus(&mut self, client_id: ClientId) {
         if !self.has_selectable_panes() {
             return;
         }
         if self.fullscreen_is_active {
             return;
         }
-        let active_terminal_id = self.get_active_pane_id().unwrap();
+        let active_terminal_id = self.get_active_pane_id(client_id).unwrap();
         let terminal_ids: Vec<PaneId> = self.get_selectable_panes().map(|(&pid, _)| pid).collect(); // TODO: better, no allocations
         let first_terminal = terminal_ids.get(0).unwrap();
         let active_terminal_id_position = terminal_ids
@@ -1817,20 +2172,20 @@ impl Tab {
             .position(|id| id == &active_terminal_id)
             .unwrap();
         if let Some(next_terminal) = terminal_ids.get(active_terminal_id_position + 1) {
-            self.active_terminal = Some(*next_terminal);
+            self.active_panes.insert(client_id, *next_terminal);
         } else {
-            self.active_terminal = Some(*first_terminal);
+            self.active_panes.insert(client_id, *first_terminal);
         }
         self.render();
     }
-    pub fn focus_next_pane(&mut self) {
+    pub fn focus_next_pane(&mut self, client_id: ClientId) {
         if !self.has_selectable_panes() {
             return;
         }
         if self.fullscreen_is_active {
             return;
         }
-        let active_pane_id = self.get_active_pane_id().unwrap();
+        let active_pane_id = self.get_active_pane_id(client_id).unwrap();
         let mut panes: Vec<(&PaneId, &Box<dyn Pane>)> = self.get_selectable_panes().collect();
         panes.sort_by(|(_a_id, a_pane), (_b_id, b_pane)| {
             if a_pane.y() == b_pane.y() {
@@ -1845,20 +2200,20 @@ impl Tab {
             .position(|(id, _)| *id == &active_pane_id) // TODO: better
             .unwrap();
         if let Some(next_pane) = panes.get(active_pane_position + 1) {
-            self.active_terminal = Some(*next_pane.0);
+            self.active_panes.insert(client_id, *next_pane.0);
         } else {
-            self.active_terminal = Some(*first_pane.0);
+            self.active_panes.insert(client_id, *first_pane.0);
         }
         self.render();
     }
-    pub fn focus_previous_pane(&mut self) {
+    pub fn focus_previous_pane(&mut self, client_id: ClientId) {
         if !self.has_selectable_panes() {
             return;
         }
         if self.fullscreen_is_active {
             return;
         }
-        let active_pane_id = self.get_active_pane_id().unwrap();
+        let active_pane_id = self.get_active_pane_id(client_id).unwrap();
         let mut panes: Vec<(&PaneId, &Box<dyn Pane>)> = self.get_selectable_panes().collect();
         panes.sort_by(|(_a_id, a_pane), (_b_id, b_pane)| {
             if a_pane.y() == b_pane.y() {
@@ -1873,21 +2228,22 @@ impl Tab {
             .position(|(id, _)| *id == &active_pane_id) // TODO: better
             .unwrap();
         if active_pane_position == 0 {
-            self.active_terminal = Some(*last_pane.0);
+            self.active_panes.insert(client_id, *last_pane.0);
         } else {
-            self.active_terminal = Some(*panes.get(active_pane_position - 1).unwrap().0);
+            self.active_panes
+                .insert(client_id, *panes.get(active_pane_position - 1).unwrap().0);
         }
         self.render();
     }
     // returns a boolean that indicates whether the focus moved
-    pub fn move_focus_left(&mut self) -> bool {
+    pub fn move_focus_left(&mut self, client_id: ClientId) -> bool {
         if !self.has_selectable_panes() {
             return false;
         }
         if self.fullscreen_is_active {
             return false;
         }
-        let active_terminal = self.get_active_pane();
+        let active_terminal = self.get_active_pane(client_id);
         if let Some(active) = active_terminal {
             let terminals = self.get_selectable_panes();
             let next_index = terminals
@@ -1899,27 +2255,28 @@ impl Tab {
                 .map(|(_, (pid, _))| pid);
             match next_index {
                 Some(&p) => {
-                    self.active_terminal = Some(p);
+                    self.active_panes.insert(client_id, p);
                     self.render();
                     return true;
                 }
                 None => {
-                    self.active_terminal = Some(active.pid());
+                    self.active_panes.insert(client_id, active.pid());
                 }
             }
         } else {
-            self.active_terminal = Some(active_terminal.unwrap().pid());
+            self.active_panes
+                .insert(client_id, active_terminal.unwrap().pid());
         }
         false
     }
-    pub fn move_focus_down(&mut self) {
+    pub fn move_focus_down(&mut self, client_id: ClientId) {
         if !self.has_selectable_panes() {
             return;
         }
         if self.fullscreen_is_active {
             return;
         }
-        let active_terminal = self.get_active_pane();
+        let active_terminal = self.get_active_pane(client_id);
         if let Some(active) = active_terminal {
             let terminals = self.get_selectable_panes();
             let next_index = terminals
@@ -1931,25 +2288,26 @@ impl Tab {
                 .map(|(_, (pid, _))| pid);
             match next_index {
                 Some(&p) => {
-                    self.active_terminal = Some(p);
+                    self.active_panes.insert(client_id, p);
                 }
                 None => {
-                    self.active_terminal = Some(active.pid());
+                    self.active_panes.insert(client_id, active.pid());
                 }
             }
         } else {
-            self.active_terminal = Some(active_terminal.unwrap().pid());
+            self.active_panes
+                .insert(client_id, active_terminal.unwrap().pid());
         }
         self.render();
     }
-    pub fn move_focus_up(&mut self) {
+    pub fn move_focus_up(&mut self, client_id: ClientId) {
         if !self.has_selectable_panes() {
             return;
         }
         if self.fullscreen_is_active {
             return;
         }
-        let active_terminal = self.get_active_pane();
+        let active_terminal = self.get_active_pane(client_id);
         if let Some(active) = active_terminal {
             let terminals = self.get_selectable_panes();
             let next_index = terminals
@@ -1961,26 +2319,27 @@ impl Tab {
                 .map(|(_, (pid, _))| pid);
             match next_index {
                 Some(&p) => {
-                    self.active_terminal = Some(p);
+                    self.active_panes.insert(client_id, p);
                 }
                 None => {
-                    self.active_terminal = Some(active.pid());
+                    self.active_panes.insert(client_id, active.pid());
                 }
             }
         } else {
-            self.active_terminal = Some(active_terminal.unwrap().pid());
+            self.active_panes
+                .insert(client_id, active_terminal.unwrap().pid());
         }
         self.render();
     }
     // returns a boolean that indicates whether the focus moved
-    pub fn move_focus_right(&mut self) -> bool {
+    pub fn move_focus_right(&mut self, client_id: ClientId) -> bool {
         if !self.has_selectable_panes() {
             return false;
         }
         if self.fullscreen_is_active {
             return false;
         }
-        let active_terminal = self.get_active_pane();
+        let active_terminal = self.get_active_pane(client_id);
         if let Some(active) = active_terminal {
             let terminals = self.get_selectable_panes();
             let next_index = terminals
@@ -1992,16 +2351,17 @@ impl Tab {
                 .map(|(_, (pid, _))| pid);
             match next_index {
                 Some(&p) => {
-                    self.active_terminal = Some(p);
+                    self.active_panes.insert(client_id, p);
                     self.render();
                     return true;
                 }
                 None => {
-                    self.active_terminal = Some(active.pid());
+                    self.active_panes.insert(client_id, active.pid());
                 }
             }
         } else {
-            self.active_terminal = Some(active_terminal.unwrap().pid());
+            self.active_panes
+                .insert(client_id, active_terminal.unwrap().pid());
         }
         false
     }
@@ -2131,8 +2491,8 @@ impl Tab {
     pub fn set_pane_selectable(&mut self, id: PaneId, selectable: bool) {
         if let Some(pane) = self.panes.get_mut(&id) {
             pane.set_selectable(selectable);
-            if self.get_active_pane_id() == Some(id) && !selectable {
-                self.active_terminal = self.next_active_pane(self.get_pane_ids())
+            if !selectable {
+                self.move_clients_out_of_pane(id, self.get_pane_ids());
             }
         }
     }
@@ -2157,8 +2517,10 @@ impl Tab {
         }
     }
     pub fn close_pane_without_rerender(&mut self, id: PaneId) {
+        self.pending_clipboard_copies.remove(&id);
+        self.foreground_commands.remove(&id);
         if self.fullscreen_is_active {
-            self.toggle_active_pane_fullscreen();
+            self.unset_fullscreen();
         }
         if let Some(pane_to_close) = self.panes.get(&id) {
             let pane_to_close_width = pane_to_close.columns();
@@ -2173,9 +2535,7 @@ impl Tab {
                         // 1 for the border
                     }
                     self.panes.remove(&id);
-                    if self.active_terminal == Some(id) {
-                        self.active_terminal = self.next_active_pane(panes);
-                    }
+                    self.move_clients_out_of_pane(id, panes);
                     return;
                 }
             }
@@ -2189,9 +2549,7 @@ impl Tab {
                         // 1 for the border
                     }
                     self.panes.remove(&id);
-                    if self.active_terminal == Some(id) {
-                        self.active_terminal = self.next_active_pane(panes);
-                    }
+                    self.move_clients_out_of_pane(id, panes);
                     return;
                 }
             }
@@ -2205,9 +2563,7 @@ impl Tab {
                         // 1 for the border
                     }
                     self.panes.remove(&id);
-                    if self.active_terminal == Some(id) {
-                        self.active_terminal = self.next_active_pane(panes);
-                    }
+                    self.move_clients_out_of_pane(id, panes);
                     return;
                 }
             }
@@ -2221,27 +2577,49 @@ impl Tab {
                         // 1 for the border
                     }
                     self.panes.remove(&id);
-                    if self.active_terminal == Some(id) {
-                        self.active_terminal = self.next_active_pane(panes);
-                    }
+                    self.move_clients_out_of_pane(id, panes);
                     return;
                 }
             }
             // if we reached here, this is either the last pane or there's some sort of
             // configuration error (eg. we're trying to close a pane surrounded by fixed panes)
             self.panes.remove(&id);
+            self.move_clients_out_of_pane(id, self.get_pane_ids());
         }
     }
-    pub fn close_focused_pane(&mut self) {
-        if let Some(active_pane_id) = self.get_active_pane_id() {
+    /// Moves the clients focused on `pane_id` to one of the `candidates`, or to any other
+    /// selectable pane if none of the candidates can be focused.
+    fn move_clients_out_of_pane(&mut self, pane_id: PaneId, candidates: Vec<PaneId>) {
+        let next_active_pane = self
+            .next_active_pane(candidates)
+            .or_else(|| self.next_active_pane(self.get_pane_ids()));
+        let client_ids: Vec<ClientId> = self
+            .active_panes
+            .iter()
+            .filter(|(_, &active_pane_id)| active_pane_id == pane_id)
+            .map(|(&client_id, _)| client_id)
+            .collect();
+        for client_id in client_ids {
+            match next_active_pane {
+                Some(next_active_pane) => {
+                    self.active_panes.insert(client_id, next_active_pane);
+                }
+                None => {
+                    self.active_panes.remove(&client_id);
+                }
+            }
+        }
+    }
+    pub fn close_focused_pane(&mut self, client_id: ClientId) {
+        if let Some(active_pane_id) = self.get_active_pane_id(client_id) {
             self.close_pane(active_pane_id);
             self.senders
                 .send_to_pty(PtyInstruction::ClosePane(active_pane_id))
                 .unwrap();
         }
     }
-    pub fn scroll_active_terminal_up(&mut self) {
-        if let Some(active_terminal_id) = self.get_active_terminal_id() {
+    pub fn scroll_active_terminal_up(&mut self, client_id: ClientId) {
+        if let Some(active_terminal_id) = self.get_active_terminal_id(client_id) {
             let active_terminal = self
                 .panes
                 .get_mut(&PaneId::Terminal(active_terminal_id))
@@ -2250,8 +2628,8 @@ impl Tab {
             self.render();
         }
     }
-    pub fn scroll_active_terminal_down(&mut self) {
-        if let Some(active_terminal_id) = self.get_active_terminal_id() {
+    pub fn scroll_active_terminal_down(&mut self, client_id: ClientId) {
+        if let Some(active_terminal_id) = self.get_active_terminal_id(client_id) {
             let active_terminal = self
                 .panes
                 .get_mut(&PaneId::Terminal(active_terminal_id))
@@ -2260,8 +2638,8 @@ impl Tab {
             self.render();
         }
     }
-    pub fn scroll_active_terminal_up_page(&mut self) {
-        if let Some(active_terminal_id) = self.get_active_terminal_id() {
+    pub fn scroll_active_terminal_up_page(&mut self, client_id: ClientId) {
+        if let Some(active_terminal_id) = self.get_active_terminal_id(client_id) {
             let active_terminal = self
                 .panes
                 .get_mut(&PaneId::Terminal(active_terminal_id))
@@ -2272,8 +2650,8 @@ impl Tab {
             self.render();
         }
     }
-    pub fn scroll_active_terminal_down_page(&mut self) {
-        if let Some(active_terminal_id) = self.get_active_terminal_id() {
+    pub fn scroll_active_terminal_down_page(&mut self, client_id: ClientId) {
+        if let Some(active_terminal_id) = self.get_active_terminal_id(client_id) {
             let active_terminal = self
                 .panes
                 .get_mut(&PaneId::Terminal(active_terminal_id))
@@ -2284,8 +2662,8 @@ impl Tab {
             self.render();
         }
     }
-    pub fn clear_active_terminal_scroll(&mut self) {
-        if let Some(active_terminal_id) = self.get_active_terminal_id() {
+    pub fn clear_active_terminal_scroll(&mut self, client_id: ClientId) {
+        if let Some(active_terminal_id) = self.get_active_terminal_id(client_id) {
             let active_terminal = self
                 .panes
                 .get_mut(&PaneId::Terminal(active_terminal_id))
@@ -2293,54 +2671,54 @@ impl Tab {
             active_terminal.clear_scroll();
         }
     }
-    pub fn scroll_terminal_up(&mut self, point: &Position, lines: usize) {
-        if let Some(pane) = self.get_pane_at(point) {
+    pub fn scroll_terminal_up(&mut self, point: &Position, lines: usize, client_id: ClientId) {
+        if let Some(pane) = self.get_pane_at(point, client_id) {
             pane.scroll_up(lines);
             self.render();
         }
     }
-    pub fn scroll_terminal_down(&mut self, point: &Position, lines: usize) {
-        if let Some(pane) = self.get_pane_at(point) {
+    pub fn scroll_terminal_down(&mut self, point: &Position, lines: usize, client_id: ClientId) {
+        if let Some(pane) = self.get_pane_at(point, client_id) {
             pane.scroll_down(lines);
             self.render();
         }
     }
-    fn get_pane_at(&mut self, point: &Position) -> Option<&mut Box<dyn Pane>> {
-        if let Some(pane_id) = self.get_pane_id_at(point) {
+    fn get_pane_at(&mut self, point: &Position, client_id: ClientId) -> Option<&mut Box<dyn Pane>> {
+        if let Some(pane_id) = self.get_pane_id_at(point, client_id) {
             self.panes.get_mut(&pane_id)
         } else {
             None
         }
     }
-    fn get_pane_id_at(&self, point: &Position) -> Option<PaneId> {
+    fn get_pane_id_at(&self, point: &Position, client_id: ClientId) -> Option<PaneId> {
         if self.fullscreen_is_active {
-            return self.get_active_pane_id();
+            return self.get_active_pane_id(client_id);
         }
 
         self.get_selectable_panes()
             .find(|(_, p)| p.contains(point))
             .map(|(&id, _)| id)
     }
-    pub fn handle_left_click(&mut self, position: &Position) {
-        self.focus_pane_at(position);
+    pub fn handle_left_click(&mut self, position: &Position, client_id: ClientId) {
+        self.focus_pane_at(position, client_id);
 
-        if let Some(pane) = self.get_pane_at(position) {
+        if let Some(pane) = self.get_pane_at(position, client_id) {
             let relative_position = pane.relative_position(position);
             pane.start_selection(&relative_position);
             self.render();
         };
     }
-    fn focus_pane_at(&mut self, point: &Position) {
-        if let Some(clicked_pane) = self.get_pane_id_at(point) {
-            self.active_terminal = Some(clicked_pane);
+    fn focus_pane_at(&mut self, point: &Position, client_id: ClientId) {
+        if let Some(clicked_pane) = self.get_pane_id_at(point, client_id) {
+            self.active_panes.insert(client_id, clicked_pane);
             self.render();
         }
     }
-    pub fn handle_mouse_release(&mut self, position: &Position) {
-        let active_pane_id = self.get_active_pane_id();
+    pub fn handle_mouse_release(&mut self, position: &Position, client_id: ClientId) {
+        let active_pane_id = self.get_active_pane_id(client_id);
         // on release, get the selected text from the active pane, and reset it's selection
         let mut selected_text = None;
-        if active_pane_id != self.get_pane_id_at(position) {
+        if active_pane_id != self.get_pane_id_at(position, client_id) {
             if let Some(active_pane_id) = active_pane_id {
                 if let Some(active_pane) = self.panes.get_mut(&active_pane_id) {
                     active_pane.end_selection(None);
@@ -2349,7 +2727,7 @@ impl Tab {
                     self.render();
                 }
             }
-        } else if let Some(pane) = self.get_pane_at(position) {
+        } else if let Some(pane) = self.get_pane_at(position, client_id) {
             let relative_position = pane.relative_position(position);
             pane.end_selection(Some(&relative_position));
             selected_text = pane.get_selected_text();
@@ -2358,11 +2736,11 @@ impl Tab {
         }
 
         if let Some(selected_text) = selected_text {
-            self.write_selection_to_clipboard(&selected_text);
+            self.write_selection_to_clipboard(&selected_text, client_id);
         }
     }
-    pub fn handle_mouse_hold(&mut self, position: &Position) {
-        if let Some(active_pane_id) = self.get_active_pane_id() {
+    pub fn handle_mouse_hold(&mut self, position: &Position, client_id: ClientId) {
+        if let Some(active_pane_id) = self.get_active_pane_id(client_id) {
             if let Some(active_pane) = self.panes.get_mut(&active_pane_id) {
                 let relative_position = active_pane.relative_position(position);
                 active_pane.update_selection(&relative_position);
@@ -2371,19 +2749,143 @@ impl Tab {
         self.render();
     }
 
-    pub fn copy_selection(&self) {
-        let selected_text = self.get_active_pane().and_then(|p| p.get_selected_text());
+    /// Reports `event` to the program of the pane it happened in, if it tracks the mouse,
+    /// and otherwise selects or scrolls. Drags and releases happen in the focused pane, as
+    /// the press focuses the pane it happens in.
+    pub fn handle_mouse_event(
+        &mut self,
+        event: &MouseEvent,
+        modifiers: MouseModifiers,
+        client_id: ClientId,
+    ) {
+        let position = event.position();
+        let pane_id = match event {
+            MouseEvent::Press(..) => self.get_pane_id_at(&position, client_id),
+            MouseEvent::Release(_) | MouseEvent::Hold(_) => self.get_active_pane_id(client_id),
+        };
+        if let Some(PaneId::Terminal(fd)) = pane_id {
+            let pane = self.panes.get_mut(&PaneId::Terminal(fd)).unwrap();
+            let relative_event = event.with_position(pane.relative_position(&position));
+            if let Some(report) = pane.mouse_event_report(&relative_event, modifiers) {
+                if let MouseEvent::Press(..) = event {
+                    self.focus_pane_at(&position, client_id);
+                }
+                self.os_api
+                    .write_to_tty_stdin(fd, &report)
+                    .expect("failed to write to terminal");
+                self.os_api.tcdrain(fd).expect("failed to drain terminal");
+                return;
+            }
+        }
+        match *event {
+            MouseEvent::Press(MouseButton::Left, position) => {
+                self.handle_left_click(&position, client_id)
+            }
+            MouseEvent::Press(MouseButton::WheelUp, position) => {
+                self.scroll_terminal_up(&position, 3, client_id)
+            }
+            MouseEvent::Press(MouseButton::WheelDown, position) => {
+                self.scroll_terminal_down(&position, 3, client_id)
+            }
+            MouseEvent::Press(..) => {}
+            MouseEvent::Release(position) => self.handle_mouse_release(&position, client_id),
+            MouseEvent::Hold(position) => self.handle_mouse_hold(&position, client_id),
+        }
+    }
+    pub fn copy_selection(&self, client_id: ClientId) {
+        let selected_text = self
+            .get_active_pane(client_id)
+            .and_then(|p| p.get_selected_text());
         if let Some(selected_text) = selected_text {
-            self.write_selection_to_clipboard(&selected_text);
+            self.write_selection_to_clipboard(&selected_text, client_id);
         }
     }
 
-    fn write_selection_to_clipboard(&self, selection: &str) {
-        let output = format!("\u{1b}]52;c;{}\u{1b}\\", base64::encode(selection));
+    fn write_selection_to_clipboard(&self, selection: &str, client_id: ClientId) {
+        let base64_text = base64::encode(selection);
+        self.write_to_clipboard("c", &base64_text, &[client_id]);
+        self.session_state
+            .write()
+            .unwrap()
+            .set_clipboard(base64_text);
+    }
+    fn write_to_clipboard(&self, selection: &str, base64_text: &str, client_ids: &[ClientId]) {
+        let output = format!("\u{1b}]52;{};{}\u{1b}\\", selection, base64_text);
         self.senders
-            .send_to_server(ServerInstruction::Render(Some(output)))
+            .send_to_server(ServerInstruction::Render(Some(
+                client_ids
+                    .iter()
+                    .map(|&client_id| (client_id, output.clone()))
+                    .collect(),
+            )))
             .unwrap();
     }
+    /// Copies to the clipboard of the clients looking at this tab, or of all the clients
+    /// when none is, as a program yanking in a background tab still expects its copy to land.
+    fn copy_to_clipboard(&self, selection: &str, base64_text: String) {
+        let mut client_ids: Vec<ClientId> = self.connected_clients.iter().copied().collect();
+        if client_ids.is_empty() {
+            client_ids = self.session_state.read().unwrap().client_ids();
+        }
+        self.write_to_clipboard(selection, &base64_text, &client_ids);
+        self.session_state
+            .write()
+            .unwrap()
+            .set_clipboard(base64_text);
+    }
+    /// Copies what the program of `pane_id` asked to copy as `clipboard_access` allows, and
+    /// answers its queries with the text copied last in the session if it may read it.
+    fn handle_clipboard_request(&mut self, request: ClipboardRequest, pane_id: PaneId) {
+        let clipboard_access = self.session_state.read().unwrap().clipboard_access();
+        match request {
+            ClipboardRequest::Copy {
+                selection,
+                base64_text,
+            } => match clipboard_access {
+                ClipboardAccess::Allow => self.copy_to_clipboard(&selection, base64_text),
+                ClipboardAccess::Deny => {}
+                ClipboardAccess::Prompt => {
+                    self.pending_clipboard_copies
+                        .insert(pane_id, (selection, base64_text));
+                }
+            },
+            ClipboardRequest::Paste {
+                selection,
+                terminator,
+            } => {
+                // the clipboard holds what other panes copied too, so programs are only
+                // answered with an empty one unless reading it was allowed, so that they
+                // do not wait for a report
+                let base64_text = {
+                    let session_state = self.session_state.read().unwrap();
+                    if session_state.allow_clipboard_read() {
+                        session_state.clipboard().unwrap_or_default().to_owned()
+                    } else {
+                        String::new()
+                    }
+                };
+                let report = format!("\u{1b}]52;{};{}{}", selection, base64_text, terminator);
+                self.write_to_pane_id(report.into_bytes(), pane_id, None);
+            }
+        }
+    }
+}
+
+/// The title of `pane`: the name the layout gave it, the title set by the program running in it,
+/// or else the name of the command running in the foreground, as last looked up.
+fn pane_title(pane: &dyn Pane, foreground_commands: &HashMap<PaneId, String>) -> Option<String> {
+    pane.name()
+        .or_else(|| pane.title())
+        .or_else(|| foreground_commands.get(&pane.pid()).cloned())
+}
+
+/// The name of the command running in the foreground of the terminal `fd`.
+fn foreground_command(os_api: &dyn ServerOsApi, fd: RawFd) -> Option<String> {
+    let foreground_pid = os_api.get_foreground_pid(fd)?;
+    let command = os_api.get_cmdline(foreground_pid)?.into_iter().next()?;
+    // login shells are started as eg. `-bash`
+    let command = Path::new(command.trim_start_matches('-')).file_name()?;
+    Some(command.to_string_lossy().into_owned())
 }
 
 #[cfg(test)]
@@ -3,6 +3,7 @@ use zellij_utils::zellij_tile;
 use crate::tab::Pane;
 use ansi_term::Colour::{Fixed, RGB};
 use std::collections::HashMap;
+use unicode_width::UnicodeWidthChar;
 use zellij_tile::data::{InputMode, Palette, PaletteColor};
 use zellij_utils::shared::colors;
 
@@ -459,6 +460,8 @@ pub struct Boundaries {
     rows: usize,
     // boundary_characters: HashMap<Coordinates, BoundaryType>,
     boundary_characters: HashMap<Coordinates, BoundarySymbol>,
+    // the titles of the panes, drawn over their boundaries
+    titles: Vec<(Coordinates, String)>,
 }
 
 impl Boundaries {
@@ -469,6 +472,7 @@ impl Boundaries {
             columns,
             rows,
             boundary_characters: HashMap::new(),
+            titles: vec![],
         }
     }
     pub fn add_rect(&mut self, rect: &dyn Pane, input_mode: InputMode, palette: Option<Palette>) {
@@ -577,6 +581,46 @@ impl Boundaries {
             }
         }
     }
+    /// Draws the title of `rect` over the boundary above it, or, for the panes at the top of
+    /// the screen, right-aligned over the boundary below it, so that it does not meet the title
+    /// of the pane there. Either way it takes at most half of the width of the pane.
+    pub fn add_title(&mut self, rect: &dyn Pane, title: &str, palette: Option<Palette>) {
+        if rect.invisible_borders() {
+            return;
+        }
+        let max_width = (rect.columns() / 2).saturating_sub(2);
+        let mut width = 0;
+        let title: String = title
+            .chars()
+            .filter(|c| !c.is_control())
+            .take_while(|c| {
+                width += c.width().unwrap_or(0);
+                width <= max_width
+            })
+            .collect();
+        if title.is_empty() {
+            return;
+        }
+        // with the spaces around it
+        let title_width = title.chars().filter_map(|c| c.width()).sum::<usize>() + 2;
+        let coordinates = if rect.y() > 0 {
+            Coordinates::new(rect.x() + 1, rect.y() - 1)
+        } else if self.rect_bottom_boundary_is_before_screen_edge(rect) {
+            Coordinates::new(
+                rect.x() + rect.columns() - 1 - title_width,
+                rect.bottom_boundary_y_coords(),
+            )
+        } else {
+            return;
+        };
+        let title = format!(" {} ", title);
+        let title = match palette.map(|palette| palette.green) {
+            Some(PaletteColor::Rgb((r, g, b))) => RGB(r, g, b).bold().paint(title).to_string(),
+            Some(PaletteColor::EightBit(color)) => Fixed(color).bold().paint(title).to_string(),
+            None => title,
+        };
+        self.titles.push((coordinates, title));
+    }
     pub fn vte_output(&self) -> String {
         let mut vte_output = String::new();
         for (coordinates, boundary_character) in &self.boundary_characters {
@@ -587,6 +631,14 @@ impl Boundaries {
                 boundary_character
             )); // goto row/col + boundary character
         }
+        for (coordinates, title) in &self.titles {
+            vte_output.push_str(&format!(
+                "\u{1b}[{};{}H\u{1b}[m{}",
+                coordinates.y + 1,
+                coordinates.x + 1,
+                title
+            ));
+        }
         vte_output
     }
     fn rect_right_boundary_is_before_screen_edge(&self, rect: &dyn Pane) -> bool {
@@ -0,0 +1,16 @@
+use super::reader_action;
+use zellij_utils::input::actions::{Action, Direction};
+
+#[test]
+fn readers_can_only_detach() {
+    assert_eq!(reader_action(Action::Detach), Some(Action::Detach));
+    assert_eq!(
+        reader_action(Action::Quit),
+        Some(Action::Detach),
+        "Quitting only detaches the reader"
+    );
+    assert_eq!(reader_action(Action::Write(b"ls\n".to_vec())), None);
+    assert_eq!(reader_action(Action::NewPane(Some(Direction::Right))), None);
+    assert_eq!(reader_action(Action::CloseFocus), None);
+    assert_eq!(reader_action(Action::NewTab), None);
+}
@@ -2,21 +2,25 @@ use super::{Screen, ScreenInstruction};
 use crate::zellij_tile::data::{InputMode, ModeInfo, Palette};
 use crate::{
     os_input_output::{AsyncReader, Pid, ServerOsApi},
+    panes::PaneId,
     thread_bus::Bus,
-    SessionState,
+    ClientId, SessionState,
 };
 use std::sync::{Arc, RwLock};
-use zellij_utils::{input::command::TerminalAction, pane_size::PositionAndSize};
+use zellij_utils::{
+    input::command::TerminalAction,
+    pane_size::{PositionAndSize, SizeInPixels},
+};
 
 use std::os::unix::io::RawFd;
+use std::path::PathBuf;
 
 use zellij_utils::ipc::ClientAttributes;
 use zellij_utils::nix;
 
 use zellij_utils::{
-    errors::ErrorContext,
     interprocess::local_socket::LocalSocketStream,
-    ipc::{ClientToServerMsg, ServerToClientMsg},
+    ipc::{ClientToServerMsg, IpcReceiverWithContext, ServerToClientMsg},
 };
 
 #[derive(Clone)]
@@ -50,25 +54,32 @@ impl ServerOsApi for FakeInputOutput {
     fn kill(&self, _pid: Pid) -> Result<(), nix::Error> {
         unimplemented!()
     }
-    fn recv_from_client(&self) -> (ClientToServerMsg, ErrorContext) {
+    fn send_to_client(&self, _client_id: ClientId, _msg: ServerToClientMsg) {
         unimplemented!()
     }
-    fn send_to_client(&self, _msg: ServerToClientMsg) {
+    fn new_client(
+        &self,
+        _client_id: ClientId,
+        _stream: LocalSocketStream,
+    ) -> IpcReceiverWithContext<ClientToServerMsg> {
         unimplemented!()
     }
-    fn add_client_sender(&self) {
+    fn remove_client(&self, _client_id: ClientId) {
         unimplemented!()
     }
-    fn send_to_temp_client(&self, _msg: ServerToClientMsg) {
+    fn has_client(&self, _client_id: ClientId) -> bool {
         unimplemented!()
     }
-    fn remove_client_sender(&self) {
+    fn load_palette(&self) -> Palette {
         unimplemented!()
     }
-    fn update_receiver(&mut self, _stream: LocalSocketStream) {
+    fn get_foreground_pid(&self, _fd: RawFd) -> Option<Pid> {
+        None
+    }
+    fn get_cmdline(&self, _pid: Pid) -> Option<Vec<String>> {
         unimplemented!()
     }
-    fn load_palette(&self) -> Palette {
+    fn get_cwd(&self, _pid: Pid) -> Option<PathBuf> {
         unimplemented!()
     }
 }
@@ -82,7 +93,9 @@ fn create_new_screen(position_and_size: PositionAndSize) -> Screen {
     let max_panes = None;
     let mode_info = ModeInfo::default();
     let input_mode = InputMode::Normal;
-    let session_state = Arc::new(RwLock::new(SessionState::Attached));
+    let mut session_state = SessionState::default();
+    session_state.add_client(1, position_and_size);
+    let session_state = Arc::new(RwLock::new(session_state));
     Screen::new(
         bus,
         &client_attributes,
@@ -104,12 +117,12 @@ fn open_new_tab() {
     };
     let mut screen = create_new_screen(position_and_size);
 
-    screen.new_tab(1);
-    screen.new_tab(2);
+    screen.new_tab(1, 1);
+    screen.new_tab(2, 1);
 
     assert_eq!(screen.tabs.len(), 2, "Screen now has two tabs");
     assert_eq!(
-        screen.get_active_tab().unwrap().position,
+        screen.get_active_tab(1).unwrap().position,
         1,
         "Active tab switched to new tab"
     );
@@ -126,12 +139,12 @@ pub fn switch_to_prev_tab() {
     };
     let mut screen = create_new_screen(position_and_size);
 
-    screen.new_tab(1);
-    screen.new_tab(2);
-    screen.switch_tab_prev();
+    screen.new_tab(1, 1);
+    screen.new_tab(2, 1);
+    screen.switch_tab_prev(1);
 
     assert_eq!(
-        screen.get_active_tab().unwrap().position,
+        screen.get_active_tab(1).unwrap().position,
         0,
         "Active tab switched to previous tab"
     );
@@ -148,13 +161,13 @@ pub fn switch_to_next_tab() {
     };
     let mut screen = create_new_screen(position_and_size);
 
-    screen.new_tab(1);
-    screen.new_tab(2);
-    screen.switch_tab_prev();
-    screen.switch_tab_next();
+    screen.new_tab(1, 1);
+    screen.new_tab(2, 1);
+    screen.switch_tab_prev(1);
+    screen.switch_tab_next(1);
 
     assert_eq!(
-        screen.get_active_tab().unwrap().position,
+        screen.get_active_tab(1).unwrap().position,
         1,
         "Active tab switched to next tab"
     );
@@ -171,13 +184,13 @@ pub fn close_tab() {
     };
     let mut screen = create_new_screen(position_and_size);
 
-    screen.new_tab(1);
-    screen.new_tab(2);
-    screen.close_tab();
+    screen.new_tab(1, 1);
+    screen.new_tab(2, 1);
+    screen.close_tab(1);
 
     assert_eq!(screen.tabs.len(), 1, "Only one tab left");
     assert_eq!(
-        screen.get_active_tab().unwrap().position,
+        screen.get_active_tab(1).unwrap().position,
         0,
         "Active tab switched to previous tab"
     );
@@ -194,15 +207,15 @@ pub fn close_the_middle_tab() {
     };
     let mut screen = create_new_screen(position_and_size);
 
-    screen.new_tab(1);
-    screen.new_tab(2);
-    screen.new_tab(3);
-    screen.switch_tab_prev();
-    screen.close_tab();
+    screen.new_tab(1, 1);
+    screen.new_tab(2, 1);
+    screen.new_tab(3, 1);
+    screen.switch_tab_prev(1);
+    screen.close_tab(1);
 
     assert_eq!(screen.tabs.len(), 2, "Two tabs left");
     assert_eq!(
-        screen.get_active_tab().unwrap().position,
+        screen.get_active_tab(1).unwrap().position,
         0,
         "Active tab switched to previous tab"
     );
@@ -219,14 +232,14 @@ fn move_focus_left_at_left_screen_edge_changes_tab() {
     };
     let mut screen = create_new_screen(position_and_size);
 
-    screen.new_tab(1);
-    screen.new_tab(2);
-    screen.new_tab(3);
-    screen.switch_tab_prev();
-    screen.move_focus_left_or_previous_tab();
+    screen.new_tab(1, 1);
+    screen.new_tab(2, 1);
+    screen.new_tab(3, 1);
+    screen.switch_tab_prev(1);
+    screen.move_focus_left_or_previous_tab(1);
 
     assert_eq!(
-        screen.get_active_tab().unwrap().position,
+        screen.get_active_tab(1).unwrap().position,
         0,
         "Active tab switched to previous"
     );
@@ -243,15 +256,221 @@ fn move_focus_right_at_right_screen_edge_changes_tab() {
     };
     let mut screen = create_new_screen(position_and_size);
 
-    screen.new_tab(1);
-    screen.new_tab(2);
-    screen.new_tab(3);
-    screen.switch_tab_prev();
-    screen.move_focus_right_or_next_tab();
+    screen.new_tab(1, 1);
+    screen.new_tab(2, 1);
+    screen.new_tab(3, 1);
+    screen.switch_tab_prev(1);
+    screen.move_focus_right_or_next_tab(1);
 
     assert_eq!(
-        screen.get_active_tab().unwrap().position,
+        screen.get_active_tab(1).unwrap().position,
         2,
         "Active tab switched to next"
     );
 }
+
+#[test]
+fn clients_switch_tabs_independently() {
+    let position_and_size = PositionAndSize {
+        cols: 121,
+        rows: 20,
+        x: 0,
+        y: 0,
+        ..Default::default()
+    };
+    let mut screen = create_new_screen(position_and_size);
+
+    screen.new_tab(1, 1);
+    screen.new_tab(2, 1);
+    screen.add_client(2);
+    screen.switch_tab_prev(2);
+
+    assert_eq!(
+        screen.get_active_tab(1).unwrap().position,
+        1,
+        "First client stayed on its tab"
+    );
+    assert_eq!(
+        screen.get_active_tab(2).unwrap().position,
+        0,
+        "Second client switched to previous tab"
+    );
+}
+
+#[test]
+fn closing_tab_moves_all_of_its_clients_to_previous_tab() {
+    let position_and_size = PositionAndSize {
+        cols: 121,
+        rows: 20,
+        x: 0,
+        y: 0,
+        ..Default::default()
+    };
+    let mut screen = create_new_screen(position_and_size);
+
+    screen.new_tab(1, 1);
+    screen.new_tab(2, 1);
+    screen.add_client(2);
+    screen.close_tab(1);
+
+    assert_eq!(screen.tabs.len(), 1, "Only one tab left");
+    assert_eq!(
+        screen.get_active_tab(1).unwrap().position,
+        0,
+        "First client moved to the remaining tab"
+    );
+    assert_eq!(
+        screen.get_active_tab(2).unwrap().position,
+        0,
+        "Second client moved to the remaining tab"
+    );
+}
+
+#[test]
+fn session_info_describes_tabs_and_panes() {
+    let position_and_size = PositionAndSize {
+        cols: 121,
+        rows: 20,
+        x: 0,
+        y: 0,
+        ..Default::default()
+    };
+    let mut screen = create_new_screen(position_and_size);
+
+    screen.new_tab(1, 1);
+    screen.new_tab(2, 1);
+    screen
+        .get_active_tab_mut(1)
+        .unwrap()
+        .vertical_split(PaneId::Terminal(3), 1);
+    screen.add_client(2);
+    screen.switch_tab_prev(2);
+
+    let session_info = screen.session_info(0);
+    assert_eq!(session_info.tabs.len(), 2, "Both tabs are described");
+    assert_eq!(session_info.tabs[0].active_clients, vec![2]);
+    assert_eq!(session_info.tabs[1].active_clients, vec![1]);
+    assert_eq!(
+        session_info.tabs[1].panes.len(),
+        2,
+        "Split pane is described"
+    );
+    let focused_pane = session_info.tabs[1]
+        .panes
+        .iter()
+        .find(|pane| pane.focused_clients == vec![1])
+        .unwrap();
+    assert_eq!(focused_pane.id, 3, "New pane is focused");
+    assert_eq!(focused_pane.cols, 60);
+    assert_eq!(focused_pane.x, 61);
+}
+
+#[test]
+fn character_cell_size_is_updated_for_existing_tabs() {
+    let position_and_size = PositionAndSize {
+        cols: 121,
+        rows: 20,
+        x: 0,
+        y: 0,
+        ..Default::default()
+    };
+    let image = "\u{1b}P0;1q\"1;1;20;24#0;2;100;0;0#0!20~-!20~-!20~-!20~\u{1b}\\";
+    let mut screen = create_new_screen(position_and_size);
+    screen.new_tab(1, 1);
+
+    let tab = screen.get_active_tab_mut(1).unwrap();
+    tab.handle_pty_bytes(1, format!("ab{}", image).into_bytes());
+    assert_eq!(
+        tab.get_active_pane(1).unwrap().cursor_coordinates(),
+        Some((2, 0)),
+        "Images are dropped while the size of the character cells is unknown"
+    );
+
+    screen.set_character_cell_size(SizeInPixels {
+        width: 10,
+        height: 20,
+    });
+    let tab = screen.get_active_tab_mut(1).unwrap();
+    tab.handle_pty_bytes(1, format!("\r{}", image).into_bytes());
+    assert_eq!(
+        tab.get_active_pane(1).unwrap().cursor_coordinates(),
+        Some((0, 2)),
+        "The cursor is below the image"
+    );
+}
+
+#[test]
+fn instructions_of_clients_without_a_tab_are_ignored() {
+    let position_and_size = PositionAndSize {
+        cols: 121,
+        rows: 20,
+        x: 0,
+        y: 0,
+        ..Default::default()
+    };
+    let mut screen = create_new_screen(position_and_size);
+
+    screen.new_tab(1, 1);
+    screen.new_tab(2, 1);
+    screen.remove_client(1);
+    screen.switch_tab_next(1);
+    screen.switch_tab_prev(1);
+    screen.go_to_tab(1, 1);
+    screen.move_focus_left_or_previous_tab(1);
+    screen.move_focus_right_or_next_tab(1);
+    screen.update_active_tab_name("a".as_bytes().to_vec(), 1);
+    screen.close_tab(1);
+
+    assert_eq!(screen.tabs.len(), 2, "No tab was closed");
+    assert!(
+        screen.get_active_tab(1).is_none(),
+        "The removed client was not moved to a tab"
+    );
+
+    screen.session_state.write().unwrap().remove_client(1);
+    screen.new_tab(3, 1);
+    assert_eq!(
+        screen.tabs.len(),
+        3,
+        "Tabs are opened for clients that detached"
+    );
+    assert!(
+        screen.get_active_tab(1).is_none(),
+        "The detached client was not moved to the new tab"
+    );
+}
+
+#[test]
+fn clients_of_a_detached_session_start_on_the_first_tab() {
+    let position_and_size = PositionAndSize {
+        cols: 121,
+        rows: 20,
+        x: 0,
+        y: 0,
+        ..Default::default()
+    };
+    let mut screen = create_new_screen(position_and_size);
+
+    screen.new_tab(1, 1);
+    screen.new_tab(2, 1);
+    screen.new_tab(3, 1);
+    screen.close_tab_at_index(0);
+    screen.remove_client(1);
+    screen.add_client(2);
+
+    let active_tab = screen.get_active_tab(2).unwrap();
+    assert_eq!(active_tab.position, 0, "The client is on the first tab");
+    assert_eq!(
+        active_tab.get_active_pane(2).unwrap().pid(),
+        PaneId::Terminal(2),
+        "The client focuses the pane of the tab"
+    );
+
+    screen.go_to_tab(2, 2);
+    screen.add_client(2);
+    assert_eq!(
+        screen.get_active_tab(2).unwrap().position,
+        1,
+        "Adding a client again leaves it where it is"
+    );
+}
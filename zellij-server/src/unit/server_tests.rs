@@ -0,0 +1,213 @@
+use super::{
+    force_detach_clients, is_valid_session_name, rename_socket, ConnectedClient, ServerInstruction,
+    SessionMetaData, SessionState,
+};
+use crate::zellij_tile::data::Palette;
+use crate::{
+    os_input_output::{AsyncReader, Pid, ServerOsApi},
+    ClientId,
+};
+use std::collections::BTreeSet;
+use std::os::unix::io::RawFd;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, RwLock};
+
+use zellij_utils::{
+    input::{command::TerminalAction, options::Options},
+    interprocess::local_socket::LocalSocketStream,
+    ipc::{
+        ClientAttributes, ClientToServerMsg, ClientType, ExitReason, IpcReceiverWithContext,
+        ServerToClientMsg,
+    },
+    nix,
+    pane_size::PositionAndSize,
+};
+
+#[derive(Clone, Default)]
+struct FakeInputOutput {
+    sent_to_clients: Arc<Mutex<Vec<(ClientId, ServerToClientMsg)>>>,
+    removed_clients: Arc<Mutex<Vec<ClientId>>>,
+    client_senders: Arc<Mutex<BTreeSet<ClientId>>>,
+}
+
+impl ServerOsApi for FakeInputOutput {
+    fn set_terminal_size_using_fd(&self, _fd: RawFd, _cols: u16, _rows: u16) {
+        // noop
+    }
+    fn spawn_terminal(&self, _file_to_open: Option<TerminalAction>) -> (RawFd, Pid) {
+        unimplemented!()
+    }
+    fn read_from_tty_stdout(&self, _fd: RawFd, _buf: &mut [u8]) -> Result<usize, nix::Error> {
+        unimplemented!()
+    }
+    fn async_file_reader(&self, _fd: RawFd) -> Box<dyn AsyncReader> {
+        unimplemented!()
+    }
+    fn write_to_tty_stdin(&self, _fd: RawFd, _buf: &[u8]) -> Result<usize, nix::Error> {
+        unimplemented!()
+    }
+    fn tcdrain(&self, _fd: RawFd) -> Result<(), nix::Error> {
+        unimplemented!()
+    }
+    fn box_clone(&self) -> Box<dyn ServerOsApi> {
+        Box::new((*self).clone())
+    }
+    fn force_kill(&self, _pid: Pid) -> Result<(), nix::Error> {
+        unimplemented!()
+    }
+    fn kill(&self, _pid: Pid) -> Result<(), nix::Error> {
+        unimplemented!()
+    }
+    fn send_to_client(&self, client_id: ClientId, msg: ServerToClientMsg) {
+        self.sent_to_clients.lock().unwrap().push((client_id, msg));
+    }
+    fn new_client(
+        &self,
+        _client_id: ClientId,
+        _stream: LocalSocketStream,
+    ) -> IpcReceiverWithContext<ClientToServerMsg> {
+        unimplemented!()
+    }
+    fn remove_client(&self, client_id: ClientId) {
+        self.removed_clients.lock().unwrap().push(client_id);
+        self.client_senders.lock().unwrap().remove(&client_id);
+    }
+    fn has_client(&self, client_id: ClientId) -> bool {
+        self.client_senders.lock().unwrap().contains(&client_id)
+    }
+    fn load_palette(&self) -> Palette {
+        unimplemented!()
+    }
+    fn get_foreground_pid(&self, _fd: RawFd) -> Option<Pid> {
+        None
+    }
+    fn get_cmdline(&self, _pid: Pid) -> Option<Vec<String>> {
+        unimplemented!()
+    }
+    fn get_cwd(&self, _pid: Pid) -> Option<PathBuf> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn force_attaching_detaches_the_attached_clients() {
+    let os_input = FakeInputOutput::default();
+    let session_data: Arc<RwLock<Option<SessionMetaData>>> = Arc::new(RwLock::new(None));
+    let mut session_state = SessionState::default();
+    session_state.add_client(1, PositionAndSize::default());
+    session_state.add_client(2, PositionAndSize::default());
+    let session_state = Arc::new(RwLock::new(session_state));
+
+    force_detach_clients(&os_input, &session_data, &session_state);
+
+    assert!(!session_state.read().unwrap().is_attached());
+    assert_eq!(*os_input.removed_clients.lock().unwrap(), vec![1, 2]);
+    let sent_to_clients = os_input.sent_to_clients.lock().unwrap();
+    assert_eq!(sent_to_clients.len(), 2);
+    for (_, msg) in sent_to_clients.iter() {
+        assert!(
+            matches!(msg, ServerToClientMsg::Exit(ExitReason::ForceDetached)),
+            "The clients are told they were detached"
+        );
+    }
+}
+
+#[test]
+fn readers_cannot_force_attach() {
+    let attach = |client_type| {
+        ServerInstruction::from((
+            ClientToServerMsg::AttachClient(
+                ClientAttributes::default(),
+                true,
+                client_type,
+                Options::default(),
+            ),
+            1,
+        ))
+    };
+    assert!(matches!(
+        attach(ClientType::Writer),
+        ServerInstruction::AttachClient(_, true, ClientType::Writer, _, 1)
+    ));
+    assert!(
+        matches!(
+            attach(ClientType::Reader),
+            ServerInstruction::AttachClient(_, false, ClientType::Reader, _, 1)
+        ),
+        "Attaching a reader with --force leaves the other clients attached"
+    );
+}
+
+#[test]
+fn session_names_must_be_plain_file_names() {
+    assert!(is_valid_session_name("work"));
+    assert!(is_valid_session_name("my.session"));
+    assert!(!is_valid_session_name(""));
+    assert!(!is_valid_session_name("/"));
+    assert!(!is_valid_session_name("../work"));
+    assert!(!is_valid_session_name("."));
+    assert!(!is_valid_session_name(".."));
+}
+
+#[test]
+fn sessions_are_not_renamed_over_other_sessions() {
+    let dir = std::env::temp_dir().join(format!("zellij-rename-test-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let (work, taken, free) = (dir.join("work"), dir.join("taken"), dir.join("free"));
+    std::fs::write(&work, "work").unwrap();
+    std::fs::write(&taken, "taken").unwrap();
+
+    let err = rename_socket(&work, &taken).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::AlreadyExists);
+    assert_eq!(std::fs::read_to_string(&taken).unwrap(), "taken");
+    assert!(work.exists(), "The session keeps its name");
+
+    rename_socket(&work, &free).unwrap();
+    assert!(!work.exists());
+    assert_eq!(std::fs::read_to_string(&free).unwrap(), "work");
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn client_ids_are_reused_once_their_connection_ended() {
+    let os_input = FakeInputOutput::default();
+    let connected_client_ids = Arc::new(Mutex::new(BTreeSet::new()));
+    let mut session_state = SessionState::default();
+    let connect = |session_state: &SessionState| {
+        ConnectedClient::new(&connected_client_ids, &os_input, session_state).unwrap()
+    };
+    let first = connect(&session_state);
+    let second = connect(&session_state);
+    assert_eq!((first.client_id, second.client_id), (1, 2));
+
+    session_state.add_client(1, PositionAndSize::default());
+    drop(first);
+    let third = connect(&session_state);
+    assert_eq!(
+        third.client_id, 3,
+        "ids of clients the session did not remove yet are skipped"
+    );
+
+    session_state.remove_client(1);
+    os_input.client_senders.lock().unwrap().insert(1);
+    let fourth = connect(&session_state);
+    assert_eq!(
+        fourth.client_id, 4,
+        "ids of connections the server did not remove yet are skipped"
+    );
+
+    os_input.remove_client(1);
+    let fifth = connect(&session_state);
+    assert_eq!(fifth.client_id, 1);
+}
+
+#[test]
+fn connections_are_refused_once_every_client_id_is_taken() {
+    let connected_client_ids = Arc::new(Mutex::new((1..=ClientId::MAX).collect()));
+    assert!(ConnectedClient::new(
+        &connected_client_ids,
+        &FakeInputOutput::default(),
+        &SessionState::default()
+    )
+    .is_none());
+}
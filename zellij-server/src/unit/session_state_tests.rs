@@ -0,0 +1,85 @@
+use super::SessionState;
+use zellij_utils::{input::options::SessionSizePolicy, pane_size::PositionAndSize};
+
+fn size(cols: usize, rows: usize) -> PositionAndSize {
+    PositionAndSize {
+        cols,
+        rows,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn detached_session_has_no_size() {
+    let session_state = SessionState::default();
+    assert!(!session_state.is_attached());
+    assert!(session_state.session_size().is_none());
+}
+
+#[test]
+fn session_is_sized_to_smallest_client() {
+    let mut session_state = SessionState::default();
+    session_state.add_client(1, size(121, 20));
+    session_state.add_client(2, size(80, 40));
+    assert_eq!(
+        session_state.session_size(),
+        Some(size(80, 20)),
+        "Session uses the smallest columns and rows of all clients"
+    );
+}
+
+#[test]
+fn session_is_resized_when_smallest_client_leaves() {
+    let mut session_state = SessionState::default();
+    session_state.add_client(1, size(121, 20));
+    session_state.add_client(2, size(80, 40));
+    session_state.remove_client(2);
+    assert_eq!(session_state.client_ids(), vec![1]);
+    assert_eq!(session_state.session_size(), Some(size(121, 20)));
+}
+
+#[test]
+fn session_is_sized_to_latest_client() {
+    let mut session_state = SessionState::default();
+    session_state.set_size_policy(SessionSizePolicy::Latest);
+    session_state.add_client(1, size(121, 20));
+    session_state.add_client(2, size(80, 40));
+    assert_eq!(session_state.session_size(), Some(size(80, 40)));
+    session_state.set_client_size(1, size(100, 30));
+    assert_eq!(
+        session_state.session_size(),
+        Some(size(100, 30)),
+        "Session follows the client that resized last"
+    );
+    session_state.remove_client(1);
+    assert_eq!(session_state.session_size(), Some(size(80, 40)));
+}
+
+#[test]
+fn readers_do_not_size_the_session() {
+    let mut session_state = SessionState::default();
+    session_state.add_client(1, size(121, 20));
+    session_state.add_reader(2, size(80, 10));
+    assert_eq!(session_state.client_ids(), vec![1, 2]);
+    assert_eq!(session_state.session_size(), Some(size(121, 20)));
+    session_state.set_client_size(2, size(60, 5));
+    assert_eq!(
+        session_state.session_size(),
+        Some(size(121, 20)),
+        "Resizing a reader leaves the session as it is"
+    );
+}
+
+#[test]
+fn readers_are_never_the_latest_client() {
+    let mut session_state = SessionState::default();
+    session_state.set_size_policy(SessionSizePolicy::Latest);
+    session_state.add_client(1, size(121, 20));
+    session_state.add_reader(2, size(80, 40));
+    session_state.set_client_size(2, size(100, 30));
+    assert_eq!(session_state.latest_client(), Some(1));
+    assert_eq!(session_state.session_size(), Some(size(121, 20)));
+    session_state.remove_client(1);
+    assert_eq!(session_state.latest_client(), None);
+    assert_eq!(session_state.session_size(), None);
+}
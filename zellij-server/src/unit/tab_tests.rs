@@ -1,26 +1,35 @@
 use super::Tab;
-use crate::zellij_tile::data::{InputMode, ModeInfo, Palette};
+use crate::zellij_tile::data::{InputMode, Palette};
 use crate::{
     os_input_output::{AsyncReader, Pid, ServerOsApi},
     panes::PaneId,
     thread_bus::ThreadSenders,
-    SessionState,
+    ClientId, SessionState,
 };
-use std::sync::{Arc, RwLock};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, RwLock};
 use zellij_utils::pane_size::PositionAndSize;
 
 use std::os::unix::io::RawFd;
+use std::path::PathBuf;
 
 use zellij_utils::nix;
 
 use zellij_utils::{
-    errors::ErrorContext,
-    input::command::TerminalAction,
+    input::{command::TerminalAction, layout::Layout, options::ClipboardAccess},
     interprocess::local_socket::LocalSocketStream,
-    ipc::{ClientToServerMsg, ServerToClientMsg},
+    ipc::{ClientToServerMsg, IpcReceiverWithContext, ServerToClientMsg},
 };
 
-struct FakeInputOutput {}
+#[derive(Clone, Default)]
+struct FakeInputOutput {
+    // what was written to the panes, by their pid
+    tty_stdin_bytes: Arc<Mutex<BTreeMap<RawFd, Vec<u8>>>>,
+    // the command line of the program in the foreground of every pane
+    foreground_cmdline: Arc<Mutex<Option<Vec<String>>>>,
+}
 
 impl ServerOsApi for FakeInputOutput {
     fn set_terminal_size_using_fd(&self, _fd: RawFd, _cols: u16, _rows: u16) {
@@ -35,11 +44,17 @@ impl ServerOsApi for FakeInputOutput {
     fn async_file_reader(&self, _fd: RawFd) -> Box<dyn AsyncReader> {
         unimplemented!()
     }
-    fn write_to_tty_stdin(&self, _fd: RawFd, _buf: &[u8]) -> Result<usize, nix::Error> {
-        unimplemented!()
+    fn write_to_tty_stdin(&self, fd: RawFd, buf: &[u8]) -> Result<usize, nix::Error> {
+        self.tty_stdin_bytes
+            .lock()
+            .unwrap()
+            .entry(fd)
+            .or_default()
+            .extend_from_slice(buf);
+        Ok(buf.len())
     }
     fn tcdrain(&self, _fd: RawFd) -> Result<(), nix::Error> {
-        unimplemented!()
+        Ok(())
     }
     fn box_clone(&self) -> Box<dyn ServerOsApi> {
         unimplemented!()
@@ -50,42 +65,62 @@ impl ServerOsApi for FakeInputOutput {
     fn kill(&self, _pid: Pid) -> Result<(), nix::Error> {
         unimplemented!()
     }
-    fn recv_from_client(&self) -> (ClientToServerMsg, ErrorContext) {
+    fn send_to_client(&self, _client_id: ClientId, _msg: ServerToClientMsg) {
         unimplemented!()
     }
-    fn send_to_client(&self, _msg: ServerToClientMsg) {
+    fn new_client(
+        &self,
+        _client_id: ClientId,
+        _stream: LocalSocketStream,
+    ) -> IpcReceiverWithContext<ClientToServerMsg> {
         unimplemented!()
     }
-    fn add_client_sender(&self) {
+    fn remove_client(&self, _client_id: ClientId) {
         unimplemented!()
     }
-    fn send_to_temp_client(&self, _msg: ServerToClientMsg) {
+    fn has_client(&self, _client_id: ClientId) -> bool {
         unimplemented!()
     }
-    fn remove_client_sender(&self) {
+    fn load_palette(&self) -> Palette {
         unimplemented!()
     }
-    fn update_receiver(&mut self, _stream: LocalSocketStream) {
-        unimplemented!()
+    fn get_foreground_pid(&self, fd: RawFd) -> Option<Pid> {
+        self.foreground_cmdline
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|_| Pid::from_raw(fd))
     }
-    fn load_palette(&self) -> Palette {
+    fn get_cmdline(&self, _pid: Pid) -> Option<Vec<String>> {
+        self.foreground_cmdline.lock().unwrap().clone()
+    }
+    fn get_cwd(&self, _pid: Pid) -> Option<PathBuf> {
         unimplemented!()
     }
 }
 
 fn create_new_tab(position_and_size: PositionAndSize) -> Tab {
+    create_new_tab_with_os_input(position_and_size, FakeInputOutput::default())
+}
+
+fn create_new_tab_with_os_input(
+    position_and_size: PositionAndSize,
+    os_input: FakeInputOutput,
+) -> Tab {
     let index = 0;
     let position = 0;
     let name = String::new();
-    let os_api = Box::new(FakeInputOutput {});
+    let os_api = Box::new(os_input);
     let senders = ThreadSenders::default().silently_fail_on_send();
     let max_panes = None;
     let first_pane_id = Some(PaneId::Terminal(1));
-    let mode_info = ModeInfo::default();
+    let mode_info = BTreeMap::new();
     let input_mode = InputMode::Normal;
     let colors = Palette::default();
-    let session_state = Arc::new(RwLock::new(SessionState::Attached));
-    Tab::new(
+    let mut session_state = SessionState::default();
+    session_state.add_client(1, position_and_size);
+    let session_state = Arc::new(RwLock::new(session_state));
+    let mut tab = Tab::new(
         index,
         position,
         name,
@@ -98,7 +133,10 @@ fn create_new_tab(position_and_size: PositionAndSize) -> Tab {
         input_mode,
         colors,
         session_state,
-    )
+        Rc::new(RefCell::new(None)),
+    );
+    tab.add_client(1);
+    tab
 }
 
 #[test]
@@ -112,7 +150,7 @@ fn split_panes_vertically() {
     };
     let mut tab = create_new_tab(position_and_size);
     let new_pane_id = PaneId::Terminal(2);
-    tab.vertical_split(new_pane_id);
+    tab.vertical_split(new_pane_id, 1);
     assert_eq!(tab.panes.len(), 2, "The tab has two panes");
     assert_eq!(
         tab.panes
@@ -199,7 +237,7 @@ fn split_panes_horizontally() {
     };
     let mut tab = create_new_tab(position_and_size);
     let new_pane_id = PaneId::Terminal(2);
-    tab.horizontal_split(new_pane_id);
+    tab.horizontal_split(new_pane_id, 1);
     assert_eq!(tab.panes.len(), 2, "The tab has two panes");
 
     assert_eq!(
@@ -289,7 +327,7 @@ fn split_largest_pane() {
     let mut tab = create_new_tab(position_and_size);
     for i in 2..5 {
         let new_pane_id = PaneId::Terminal(i);
-        tab.new_pane(new_pane_id);
+        tab.new_pane(new_pane_id, 1);
     }
     assert_eq!(tab.panes.len(), 4, "The tab has four panes");
 
@@ -452,7 +490,7 @@ pub fn cannot_split_panes_vertically_when_active_terminal_is_too_small() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.vertical_split(PaneId::Terminal(2));
+    tab.vertical_split(PaneId::Terminal(2), 1);
     assert_eq!(tab.panes.len(), 1, "Tab still has only one pane");
 }
 
@@ -466,7 +504,7 @@ pub fn cannot_split_panes_vertically_when_active_pane_is_too_small() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.vertical_split(PaneId::Terminal(2));
+    tab.vertical_split(PaneId::Terminal(2), 1);
     assert_eq!(tab.panes.len(), 1, "Tab still has only one pane");
 }
 
@@ -480,7 +518,7 @@ pub fn cannot_split_panes_horizontally_when_active_pane_is_too_small() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
+    tab.horizontal_split(PaneId::Terminal(2), 1);
     assert_eq!(tab.panes.len(), 1, "Tab still has only one pane");
 }
 
@@ -494,7 +532,7 @@ pub fn cannot_split_largest_pane_when_there_is_no_room() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.new_pane(PaneId::Terminal(2));
+    tab.new_pane(PaneId::Terminal(2), 1);
     assert_eq!(tab.panes.len(), 1, "Tab still has only one pane");
 }
 
@@ -510,9 +548,9 @@ pub fn toggle_focused_pane_fullscreen() {
     let mut tab = create_new_tab(position_and_size);
     for i in 2..5 {
         let new_pane_id = PaneId::Terminal(i);
-        tab.new_pane(new_pane_id);
+        tab.new_pane(new_pane_id, 1);
     }
-    tab.toggle_active_pane_fullscreen();
+    tab.toggle_active_pane_fullscreen(1);
     assert_eq!(
         tab.panes.get(&PaneId::Terminal(4)).unwrap().x(),
         0,
@@ -533,7 +571,7 @@ pub fn toggle_focused_pane_fullscreen() {
         20,
         "Pane rows match fullscreen rows"
     );
-    tab.toggle_active_pane_fullscreen();
+    tab.toggle_active_pane_fullscreen(1);
     assert_eq!(
         tab.panes.get(&PaneId::Terminal(4)).unwrap().x(),
         61,
@@ -570,10 +608,10 @@ pub fn move_focus_is_disabled_in_fullscreen() {
     let mut tab = create_new_tab(position_and_size);
     for i in 2..5 {
         let new_pane_id = PaneId::Terminal(i);
-        tab.new_pane(new_pane_id);
+        tab.new_pane(new_pane_id, 1);
     }
-    tab.toggle_active_pane_fullscreen();
-    tab.move_focus_left();
+    tab.toggle_active_pane_fullscreen(1);
+    tab.move_focus_left(1);
     assert_eq!(
         tab.panes.get(&PaneId::Terminal(4)).unwrap().x(),
         0,
@@ -616,8 +654,8 @@ pub fn close_pane_with_another_pane_above_it() {
     };
     let mut tab = create_new_tab(position_and_size);
     let new_pane_id = PaneId::Terminal(2);
-    tab.horizontal_split(new_pane_id);
-    tab.close_focused_pane();
+    tab.horizontal_split(new_pane_id, 1);
+    tab.close_focused_pane(1);
     assert_eq!(tab.panes.len(), 1, "One pane left in tab");
 
     assert_eq!(
@@ -678,9 +716,9 @@ pub fn close_pane_with_another_pane_below_it() {
     };
     let mut tab = create_new_tab(position_and_size);
     let new_pane_id = PaneId::Terminal(2);
-    tab.horizontal_split(new_pane_id);
-    tab.move_focus_up();
-    tab.close_focused_pane();
+    tab.horizontal_split(new_pane_id, 1);
+    tab.move_focus_up(1);
+    tab.close_focused_pane(1);
     assert_eq!(tab.panes.len(), 1, "One pane left in tab");
 
     assert_eq!(
@@ -738,8 +776,8 @@ pub fn close_pane_with_another_pane_to_the_left() {
     };
     let mut tab = create_new_tab(position_and_size);
     let new_pane_id = PaneId::Terminal(2);
-    tab.vertical_split(new_pane_id);
-    tab.close_focused_pane();
+    tab.vertical_split(new_pane_id, 1);
+    tab.close_focused_pane(1);
     assert_eq!(tab.panes.len(), 1, "One pane left in tab");
 
     assert_eq!(
@@ -797,9 +835,9 @@ pub fn close_pane_with_another_pane_to_the_right() {
     };
     let mut tab = create_new_tab(position_and_size);
     let new_pane_id = PaneId::Terminal(2);
-    tab.vertical_split(new_pane_id);
-    tab.move_focus_left();
-    tab.close_focused_pane();
+    tab.vertical_split(new_pane_id, 1);
+    tab.move_focus_left(1);
+    tab.close_focused_pane(1);
     assert_eq!(tab.panes.len(), 1, "One pane left in tab");
 
     assert_eq!(
@@ -860,11 +898,11 @@ pub fn close_pane_with_multiple_panes_above_it() {
     let mut tab = create_new_tab(position_and_size);
     let new_pane_id_1 = PaneId::Terminal(2);
     let new_pane_id_2 = PaneId::Terminal(3);
-    tab.horizontal_split(new_pane_id_1);
-    tab.move_focus_up();
-    tab.vertical_split(new_pane_id_2);
-    tab.move_focus_down();
-    tab.close_focused_pane();
+    tab.horizontal_split(new_pane_id_1, 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(new_pane_id_2, 1);
+    tab.move_focus_down(1);
+    tab.close_focused_pane(1);
     assert_eq!(tab.panes.len(), 2, "Two panes left in tab");
 
     assert_eq!(
@@ -962,10 +1000,10 @@ pub fn close_pane_with_multiple_panes_below_it() {
     let mut tab = create_new_tab(position_and_size);
     let new_pane_id_1 = PaneId::Terminal(2);
     let new_pane_id_2 = PaneId::Terminal(3);
-    tab.horizontal_split(new_pane_id_1);
-    tab.vertical_split(new_pane_id_2);
-    tab.move_focus_up();
-    tab.close_focused_pane();
+    tab.horizontal_split(new_pane_id_1, 1);
+    tab.vertical_split(new_pane_id_2, 1);
+    tab.move_focus_up(1);
+    tab.close_focused_pane(1);
     assert_eq!(tab.panes.len(), 2, "Two panes left in tab");
 
     assert_eq!(
@@ -1063,11 +1101,11 @@ pub fn close_pane_with_multiple_panes_to_the_left() {
     let mut tab = create_new_tab(position_and_size);
     let new_pane_id_1 = PaneId::Terminal(2);
     let new_pane_id_2 = PaneId::Terminal(3);
-    tab.vertical_split(new_pane_id_1);
-    tab.move_focus_left();
-    tab.horizontal_split(new_pane_id_2);
-    tab.move_focus_right();
-    tab.close_focused_pane();
+    tab.vertical_split(new_pane_id_1, 1);
+    tab.move_focus_left(1);
+    tab.horizontal_split(new_pane_id_2, 1);
+    tab.move_focus_right(1);
+    tab.close_focused_pane(1);
     assert_eq!(tab.panes.len(), 2, "Two panes left in tab");
 
     assert_eq!(
@@ -1165,10 +1203,10 @@ pub fn close_pane_with_multiple_panes_to_the_right() {
     let mut tab = create_new_tab(position_and_size);
     let new_pane_id_1 = PaneId::Terminal(2);
     let new_pane_id_2 = PaneId::Terminal(3);
-    tab.vertical_split(new_pane_id_1);
-    tab.horizontal_split(new_pane_id_2);
-    tab.move_focus_left();
-    tab.close_focused_pane();
+    tab.vertical_split(new_pane_id_1, 1);
+    tab.horizontal_split(new_pane_id_2, 1);
+    tab.move_focus_left(1);
+    tab.close_focused_pane(1);
     assert_eq!(tab.panes.len(), 2, "Two panes left in tab");
 
     assert_eq!(
@@ -1271,21 +1309,21 @@ pub fn close_pane_with_multiple_panes_above_it_away_from_screen_edges() {
     let new_pane_id_5 = PaneId::Terminal(6);
     let new_pane_id_6 = PaneId::Terminal(7);
 
-    tab.vertical_split(new_pane_id_1);
-    tab.vertical_split(new_pane_id_2);
-    tab.move_focus_left();
-    tab.move_focus_left();
-    tab.horizontal_split(new_pane_id_3);
-    tab.move_focus_right();
-    tab.horizontal_split(new_pane_id_4);
-    tab.move_focus_right();
-    tab.horizontal_split(new_pane_id_5);
-    tab.move_focus_left();
-    tab.move_focus_up();
-    tab.resize_down();
-    tab.vertical_split(new_pane_id_6);
-    tab.move_focus_down();
-    tab.close_focused_pane();
+    tab.vertical_split(new_pane_id_1, 1);
+    tab.vertical_split(new_pane_id_2, 1);
+    tab.move_focus_left(1);
+    tab.move_focus_left(1);
+    tab.horizontal_split(new_pane_id_3, 1);
+    tab.move_focus_right(1);
+    tab.horizontal_split(new_pane_id_4, 1);
+    tab.move_focus_right(1);
+    tab.horizontal_split(new_pane_id_5, 1);
+    tab.move_focus_left(1);
+    tab.move_focus_up(1);
+    tab.resize_down(1);
+    tab.vertical_split(new_pane_id_6, 1);
+    tab.move_focus_down(1);
+    tab.close_focused_pane(1);
 
     assert_eq!(tab.panes.len(), 6, "Six panes left in tab");
 
@@ -1538,20 +1576,20 @@ pub fn close_pane_with_multiple_panes_below_it_away_from_screen_edges() {
     let new_pane_id_5 = PaneId::Terminal(6);
     let new_pane_id_6 = PaneId::Terminal(7);
 
-    tab.vertical_split(new_pane_id_1);
-    tab.vertical_split(new_pane_id_2);
-    tab.move_focus_left();
-    tab.move_focus_left();
-    tab.horizontal_split(new_pane_id_3);
-    tab.move_focus_right();
-    tab.horizontal_split(new_pane_id_4);
-    tab.move_focus_right();
-    tab.horizontal_split(new_pane_id_5);
-    tab.move_focus_left();
-    tab.resize_up();
-    tab.vertical_split(new_pane_id_6);
-    tab.move_focus_up();
-    tab.close_focused_pane();
+    tab.vertical_split(new_pane_id_1, 1);
+    tab.vertical_split(new_pane_id_2, 1);
+    tab.move_focus_left(1);
+    tab.move_focus_left(1);
+    tab.horizontal_split(new_pane_id_3, 1);
+    tab.move_focus_right(1);
+    tab.horizontal_split(new_pane_id_4, 1);
+    tab.move_focus_right(1);
+    tab.horizontal_split(new_pane_id_5, 1);
+    tab.move_focus_left(1);
+    tab.resize_up(1);
+    tab.vertical_split(new_pane_id_6, 1);
+    tab.move_focus_up(1);
+    tab.close_focused_pane(1);
 
     assert_eq!(tab.panes.len(), 6, "Six panes left in tab");
 
@@ -1806,21 +1844,21 @@ pub fn close_pane_with_multiple_panes_to_the_left_away_from_screen_edges() {
     let new_pane_id_5 = PaneId::Terminal(6);
     let new_pane_id_6 = PaneId::Terminal(7);
 
-    tab.horizontal_split(new_pane_id_1);
-    tab.horizontal_split(new_pane_id_2);
-    tab.move_focus_up();
-    tab.move_focus_up();
-    tab.vertical_split(new_pane_id_3);
-    tab.move_focus_down();
-    tab.vertical_split(new_pane_id_4);
-    tab.move_focus_down();
-    tab.vertical_split(new_pane_id_5);
-    tab.move_focus_up();
-    tab.move_focus_left();
-    tab.resize_right();
-    tab.horizontal_split(new_pane_id_6);
-    tab.move_focus_right();
-    tab.close_focused_pane();
+    tab.horizontal_split(new_pane_id_1, 1);
+    tab.horizontal_split(new_pane_id_2, 1);
+    tab.move_focus_up(1);
+    tab.move_focus_up(1);
+    tab.vertical_split(new_pane_id_3, 1);
+    tab.move_focus_down(1);
+    tab.vertical_split(new_pane_id_4, 1);
+    tab.move_focus_down(1);
+    tab.vertical_split(new_pane_id_5, 1);
+    tab.move_focus_up(1);
+    tab.move_focus_left(1);
+    tab.resize_right(1);
+    tab.horizontal_split(new_pane_id_6, 1);
+    tab.move_focus_right(1);
+    tab.close_focused_pane(1);
 
     assert_eq!(tab.panes.len(), 6, "Six panes left in tab");
 
@@ -2075,20 +2113,20 @@ pub fn close_pane_with_multiple_panes_to_the_right_away_from_screen_edges() {
     let new_pane_id_5 = PaneId::Terminal(6);
     let new_pane_id_6 = PaneId::Terminal(7);
 
-    tab.horizontal_split(new_pane_id_1);
-    tab.horizontal_split(new_pane_id_2);
-    tab.move_focus_up();
-    tab.move_focus_up();
-    tab.vertical_split(new_pane_id_3);
-    tab.move_focus_down();
-    tab.vertical_split(new_pane_id_4);
-    tab.move_focus_down();
-    tab.vertical_split(new_pane_id_5);
-    tab.move_focus_up();
-    tab.resize_left();
-    tab.horizontal_split(new_pane_id_6);
-    tab.move_focus_left();
-    tab.close_focused_pane();
+    tab.horizontal_split(new_pane_id_1, 1);
+    tab.horizontal_split(new_pane_id_2, 1);
+    tab.move_focus_up(1);
+    tab.move_focus_up(1);
+    tab.vertical_split(new_pane_id_3, 1);
+    tab.move_focus_down(1);
+    tab.vertical_split(new_pane_id_4, 1);
+    tab.move_focus_down(1);
+    tab.vertical_split(new_pane_id_5, 1);
+    tab.move_focus_up(1);
+    tab.resize_left(1);
+    tab.horizontal_split(new_pane_id_6, 1);
+    tab.move_focus_left(1);
+    tab.close_focused_pane(1);
 
     assert_eq!(tab.panes.len(), 6, "Six panes left in tab");
 
@@ -2327,12 +2365,12 @@ pub fn move_focus_down() {
     let mut tab = create_new_tab(position_and_size);
     let new_pane_id = PaneId::Terminal(2);
 
-    tab.horizontal_split(new_pane_id);
-    tab.move_focus_up();
-    tab.move_focus_down();
+    tab.horizontal_split(new_pane_id, 1);
+    tab.move_focus_up(1);
+    tab.move_focus_down(1);
 
     assert_eq!(
-        tab.get_active_pane().unwrap().y(),
+        tab.get_active_pane(1).unwrap().y(),
         11,
         "Active pane is the bottom one"
     );
@@ -2352,19 +2390,19 @@ pub fn move_focus_down_to_the_most_recently_used_pane() {
     let new_pane_id_2 = PaneId::Terminal(3);
     let new_pane_id_3 = PaneId::Terminal(4);
 
-    tab.horizontal_split(new_pane_id_1);
-    tab.vertical_split(new_pane_id_2);
-    tab.vertical_split(new_pane_id_3);
-    tab.move_focus_up();
-    tab.move_focus_down();
+    tab.horizontal_split(new_pane_id_1, 1);
+    tab.vertical_split(new_pane_id_2, 1);
+    tab.vertical_split(new_pane_id_3, 1);
+    tab.move_focus_up(1);
+    tab.move_focus_down(1);
 
     assert_eq!(
-        tab.get_active_pane().unwrap().y(),
+        tab.get_active_pane(1).unwrap().y(),
         11,
         "Active pane y position"
     );
     assert_eq!(
-        tab.get_active_pane().unwrap().x(),
+        tab.get_active_pane(1).unwrap().x(),
         92,
         "Active pane x position"
     );
@@ -2382,11 +2420,11 @@ pub fn move_focus_up() {
     let mut tab = create_new_tab(position_and_size);
     let new_pane_id = PaneId::Terminal(2);
 
-    tab.horizontal_split(new_pane_id);
-    tab.move_focus_up();
+    tab.horizontal_split(new_pane_id, 1);
+    tab.move_focus_up(1);
 
     assert_eq!(
-        tab.get_active_pane().unwrap().y(),
+        tab.get_active_pane(1).unwrap().y(),
         0,
         "Active pane is the top one"
     );
@@ -2406,20 +2444,20 @@ pub fn move_focus_up_to_the_most_recently_used_pane() {
     let new_pane_id_2 = PaneId::Terminal(3);
     let new_pane_id_3 = PaneId::Terminal(4);
 
-    tab.horizontal_split(new_pane_id_1);
-    tab.move_focus_up();
-    tab.vertical_split(new_pane_id_2);
-    tab.vertical_split(new_pane_id_3);
-    tab.move_focus_down();
-    tab.move_focus_up();
+    tab.horizontal_split(new_pane_id_1, 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(new_pane_id_2, 1);
+    tab.vertical_split(new_pane_id_3, 1);
+    tab.move_focus_down(1);
+    tab.move_focus_up(1);
 
     assert_eq!(
-        tab.get_active_pane().unwrap().y(),
+        tab.get_active_pane(1).unwrap().y(),
         0,
         "Active pane y position"
     );
     assert_eq!(
-        tab.get_active_pane().unwrap().x(),
+        tab.get_active_pane(1).unwrap().x(),
         92,
         "Active pane x position"
     );
@@ -2437,11 +2475,11 @@ pub fn move_focus_left() {
     let mut tab = create_new_tab(position_and_size);
     let new_pane_id = PaneId::Terminal(2);
 
-    tab.vertical_split(new_pane_id);
-    tab.move_focus_left();
+    tab.vertical_split(new_pane_id, 1);
+    tab.move_focus_left(1);
 
     assert_eq!(
-        tab.get_active_pane().unwrap().x(),
+        tab.get_active_pane(1).unwrap().x(),
         0,
         "Active pane is the left one"
     );
@@ -2461,20 +2499,20 @@ pub fn move_focus_left_to_the_most_recently_used_pane() {
     let new_pane_id_2 = PaneId::Terminal(3);
     let new_pane_id_3 = PaneId::Terminal(4);
 
-    tab.vertical_split(new_pane_id_1);
-    tab.move_focus_left();
-    tab.horizontal_split(new_pane_id_2);
-    tab.horizontal_split(new_pane_id_3);
-    tab.move_focus_right();
-    tab.move_focus_left();
+    tab.vertical_split(new_pane_id_1, 1);
+    tab.move_focus_left(1);
+    tab.horizontal_split(new_pane_id_2, 1);
+    tab.horizontal_split(new_pane_id_3, 1);
+    tab.move_focus_right(1);
+    tab.move_focus_left(1);
 
     assert_eq!(
-        tab.get_active_pane().unwrap().y(),
+        tab.get_active_pane(1).unwrap().y(),
         16,
         "Active pane y position"
     );
     assert_eq!(
-        tab.get_active_pane().unwrap().x(),
+        tab.get_active_pane(1).unwrap().x(),
         0,
         "Active pane x position"
     );
@@ -2492,12 +2530,12 @@ pub fn move_focus_right() {
     let mut tab = create_new_tab(position_and_size);
     let new_pane_id = PaneId::Terminal(2);
 
-    tab.vertical_split(new_pane_id);
-    tab.move_focus_left();
-    tab.move_focus_right();
+    tab.vertical_split(new_pane_id, 1);
+    tab.move_focus_left(1);
+    tab.move_focus_right(1);
 
     assert_eq!(
-        tab.get_active_pane().unwrap().x(),
+        tab.get_active_pane(1).unwrap().x(),
         61,
         "Active pane is the right one"
     );
@@ -2517,19 +2555,19 @@ pub fn move_focus_right_to_the_most_recently_used_pane() {
     let new_pane_id_2 = PaneId::Terminal(3);
     let new_pane_id_3 = PaneId::Terminal(4);
 
-    tab.vertical_split(new_pane_id_1);
-    tab.horizontal_split(new_pane_id_2);
-    tab.horizontal_split(new_pane_id_3);
-    tab.move_focus_left();
-    tab.move_focus_right();
+    tab.vertical_split(new_pane_id_1, 1);
+    tab.horizontal_split(new_pane_id_2, 1);
+    tab.horizontal_split(new_pane_id_3, 1);
+    tab.move_focus_left(1);
+    tab.move_focus_right(1);
 
     assert_eq!(
-        tab.get_active_pane().unwrap().y(),
+        tab.get_active_pane(1).unwrap().y(),
         16,
         "Active pane y position"
     );
     assert_eq!(
-        tab.get_active_pane().unwrap().x(),
+        tab.get_active_pane(1).unwrap().x(),
         61,
         "Active pane x position"
     );
@@ -2555,8 +2593,8 @@ pub fn resize_down_with_pane_above() {
     };
     let mut tab = create_new_tab(position_and_size);
     let new_pane_id = PaneId::Terminal(2);
-    tab.horizontal_split(new_pane_id);
-    tab.resize_down();
+    tab.horizontal_split(new_pane_id, 1);
+    tab.resize_down(1);
 
     assert_eq!(
         tab.panes.get(&new_pane_id).unwrap().position_and_size().x,
@@ -2644,9 +2682,9 @@ pub fn resize_down_with_pane_below() {
     };
     let mut tab = create_new_tab(position_and_size);
     let new_pane_id = PaneId::Terminal(2);
-    tab.horizontal_split(new_pane_id);
-    tab.move_focus_up();
-    tab.resize_down();
+    tab.horizontal_split(new_pane_id, 1);
+    tab.move_focus_up(1);
+    tab.resize_down(1);
 
     assert_eq!(
         tab.panes.get(&new_pane_id).unwrap().position_and_size().x,
@@ -2740,10 +2778,10 @@ pub fn resize_down_with_panes_above_and_below() {
     let first_pane_id = PaneId::Terminal(1);
     let new_pane_id_1 = PaneId::Terminal(2);
     let new_pane_id_2 = PaneId::Terminal(3);
-    tab.horizontal_split(new_pane_id_1);
-    tab.horizontal_split(new_pane_id_2);
-    tab.move_focus_up();
-    tab.resize_down();
+    tab.horizontal_split(new_pane_id_1, 1);
+    tab.horizontal_split(new_pane_id_2, 1);
+    tab.move_focus_up(1);
+    tab.resize_down(1);
 
     assert_eq!(
         tab.panes.get(&new_pane_id_1).unwrap().position_and_size().x,
@@ -2854,11 +2892,11 @@ pub fn resize_down_with_multiple_panes_above() {
     let first_pane_id = PaneId::Terminal(1);
     let new_pane_id_1 = PaneId::Terminal(2);
     let new_pane_id_2 = PaneId::Terminal(3);
-    tab.horizontal_split(new_pane_id_1);
-    tab.move_focus_up();
-    tab.vertical_split(new_pane_id_2);
-    tab.move_focus_down();
-    tab.resize_down();
+    tab.horizontal_split(new_pane_id_1, 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(new_pane_id_2, 1);
+    tab.move_focus_down(1);
+    tab.resize_down(1);
 
     assert_eq!(
         tab.panes.get(&new_pane_id_1).unwrap().position_and_size().x,
@@ -2970,12 +3008,12 @@ pub fn resize_down_with_panes_above_aligned_left_with_current_pane() {
     let pane_to_the_left = PaneId::Terminal(2);
     let focused_pane = PaneId::Terminal(3);
     let pane_above = PaneId::Terminal(4);
-    tab.horizontal_split(pane_to_the_left);
-    tab.vertical_split(focused_pane);
-    tab.move_focus_up();
-    tab.vertical_split(pane_above);
-    tab.move_focus_down();
-    tab.resize_down();
+    tab.horizontal_split(pane_to_the_left, 1);
+    tab.vertical_split(focused_pane, 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(pane_above, 1);
+    tab.move_focus_down(1);
+    tab.resize_down(1);
 
     assert_eq!(
         tab.panes.get(&focused_pane).unwrap().position_and_size().x,
@@ -3125,11 +3163,11 @@ pub fn resize_down_with_panes_below_aligned_left_with_current_pane() {
     let pane_below_and_left = PaneId::Terminal(2);
     let pane_below = PaneId::Terminal(3);
     let focused_pane = PaneId::Terminal(4);
-    tab.horizontal_split(pane_below_and_left);
-    tab.vertical_split(pane_below);
-    tab.move_focus_up();
-    tab.vertical_split(focused_pane);
-    tab.resize_down();
+    tab.horizontal_split(pane_below_and_left, 1);
+    tab.vertical_split(pane_below, 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(focused_pane, 1);
+    tab.resize_down(1);
 
     assert_eq!(
         tab.panes.get(&focused_pane).unwrap().position_and_size().x,
@@ -3279,13 +3317,13 @@ pub fn resize_down_with_panes_above_aligned_right_with_current_pane() {
     let focused_pane = PaneId::Terminal(2);
     let pane_to_the_right = PaneId::Terminal(3);
     let pane_above_and_right = PaneId::Terminal(4);
-    tab.horizontal_split(focused_pane);
-    tab.vertical_split(pane_to_the_right);
-    tab.move_focus_up();
-    tab.vertical_split(pane_above_and_right);
-    tab.move_focus_down();
-    tab.move_focus_left();
-    tab.resize_down();
+    tab.horizontal_split(focused_pane, 1);
+    tab.vertical_split(pane_to_the_right, 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(pane_above_and_right, 1);
+    tab.move_focus_down(1);
+    tab.move_focus_left(1);
+    tab.resize_down(1);
 
     assert_eq!(
         tab.panes.get(&focused_pane).unwrap().position_and_size().x,
@@ -3435,12 +3473,12 @@ pub fn resize_down_with_panes_below_aligned_right_with_current_pane() {
     let pane_below = PaneId::Terminal(2);
     let pane_below_and_right = PaneId::Terminal(3);
     let pane_to_the_right = PaneId::Terminal(4);
-    tab.horizontal_split(pane_below);
-    tab.vertical_split(pane_below_and_right);
-    tab.move_focus_up();
-    tab.vertical_split(pane_to_the_right);
-    tab.move_focus_left();
-    tab.resize_down();
+    tab.horizontal_split(pane_below, 1);
+    tab.vertical_split(pane_below_and_right, 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(pane_to_the_right, 1);
+    tab.move_focus_left(1);
+    tab.resize_down(1);
 
     assert_eq!(
         tab.panes.get(&focused_pane).unwrap().position_and_size().x,
@@ -3586,15 +3624,15 @@ pub fn resize_down_with_panes_above_aligned_left_and_right_with_current_pane() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.vertical_split(PaneId::Terminal(3));
-    tab.vertical_split(PaneId::Terminal(4));
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(5));
-    tab.vertical_split(PaneId::Terminal(6));
-    tab.move_focus_left();
-    tab.move_focus_down();
-    tab.resize_down();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.vertical_split(PaneId::Terminal(3), 1);
+    tab.vertical_split(PaneId::Terminal(4), 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(5), 1);
+    tab.vertical_split(PaneId::Terminal(6), 1);
+    tab.move_focus_left(1);
+    tab.move_focus_down(1);
+    tab.resize_down(1);
 
     assert_eq!(
         tab.panes
@@ -3838,14 +3876,14 @@ pub fn resize_down_with_panes_below_aligned_left_and_right_with_current_pane() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.vertical_split(PaneId::Terminal(3));
-    tab.vertical_split(PaneId::Terminal(4));
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(5));
-    tab.vertical_split(PaneId::Terminal(6));
-    tab.move_focus_left();
-    tab.resize_down();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.vertical_split(PaneId::Terminal(3), 1);
+    tab.vertical_split(PaneId::Terminal(4), 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(5), 1);
+    tab.vertical_split(PaneId::Terminal(6), 1);
+    tab.move_focus_left(1);
+    tab.resize_down(1);
 
     assert_eq!(
         tab.panes
@@ -4089,18 +4127,18 @@ pub fn resize_down_with_panes_above_aligned_left_and_right_with_panes_to_the_lef
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(3));
-    tab.vertical_split(PaneId::Terminal(4));
-    tab.move_focus_down();
-    tab.vertical_split(PaneId::Terminal(5));
-    tab.vertical_split(PaneId::Terminal(6));
-    tab.move_focus_left();
-    tab.vertical_split(PaneId::Terminal(7));
-    tab.vertical_split(PaneId::Terminal(8));
-    tab.move_focus_left();
-    tab.resize_down();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(3), 1);
+    tab.vertical_split(PaneId::Terminal(4), 1);
+    tab.move_focus_down(1);
+    tab.vertical_split(PaneId::Terminal(5), 1);
+    tab.vertical_split(PaneId::Terminal(6), 1);
+    tab.move_focus_left(1);
+    tab.vertical_split(PaneId::Terminal(7), 1);
+    tab.vertical_split(PaneId::Terminal(8), 1);
+    tab.move_focus_left(1);
+    tab.resize_down(1);
 
     assert_eq!(
         tab.panes
@@ -4418,20 +4456,20 @@ pub fn resize_down_with_panes_below_aligned_left_and_right_with_to_the_left_and_
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(3));
-    tab.vertical_split(PaneId::Terminal(4));
-    tab.move_focus_left();
-    tab.vertical_split(PaneId::Terminal(5));
-    tab.vertical_split(PaneId::Terminal(6));
-    tab.move_focus_down();
-    tab.vertical_split(PaneId::Terminal(7));
-    tab.vertical_split(PaneId::Terminal(8));
-    tab.move_focus_left();
-    tab.move_focus_up();
-    tab.move_focus_left();
-    tab.resize_down();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(3), 1);
+    tab.vertical_split(PaneId::Terminal(4), 1);
+    tab.move_focus_left(1);
+    tab.vertical_split(PaneId::Terminal(5), 1);
+    tab.vertical_split(PaneId::Terminal(6), 1);
+    tab.move_focus_down(1);
+    tab.vertical_split(PaneId::Terminal(7), 1);
+    tab.vertical_split(PaneId::Terminal(8), 1);
+    tab.move_focus_left(1);
+    tab.move_focus_up(1);
+    tab.move_focus_left(1);
+    tab.resize_down(1);
 
     assert_eq!(
         tab.panes
@@ -4747,9 +4785,9 @@ pub fn cannot_resize_down_when_pane_below_is_at_minimum_height() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.move_focus_up();
-    tab.resize_down();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.move_focus_up(1);
+    tab.resize_down(1);
 
     assert_eq!(
         tab.panes
@@ -4788,8 +4826,8 @@ pub fn resize_left_with_pane_to_the_left() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.vertical_split(PaneId::Terminal(2));
-    tab.resize_left();
+    tab.vertical_split(PaneId::Terminal(2), 1);
+    tab.resize_left(1);
 
     assert_eq!(
         tab.panes
@@ -4882,9 +4920,9 @@ pub fn resize_left_with_pane_to_the_right() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.vertical_split(PaneId::Terminal(2));
-    tab.move_focus_left();
-    tab.resize_left();
+    tab.vertical_split(PaneId::Terminal(2), 1);
+    tab.move_focus_left(1);
+    tab.resize_left(1);
 
     assert_eq!(
         tab.panes
@@ -4978,10 +5016,10 @@ pub fn resize_left_with_panes_to_the_left_and_right() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.vertical_split(PaneId::Terminal(2));
-    tab.vertical_split(PaneId::Terminal(3));
-    tab.move_focus_left();
-    tab.resize_left();
+    tab.vertical_split(PaneId::Terminal(2), 1);
+    tab.vertical_split(PaneId::Terminal(3), 1);
+    tab.move_focus_left(1);
+    tab.resize_left(1);
 
     assert_eq!(
         tab.panes
@@ -5111,11 +5149,11 @@ pub fn resize_left_with_multiple_panes_to_the_left() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.vertical_split(PaneId::Terminal(2));
-    tab.move_focus_left();
-    tab.horizontal_split(PaneId::Terminal(3));
-    tab.move_focus_right();
-    tab.resize_left();
+    tab.vertical_split(PaneId::Terminal(2), 1);
+    tab.move_focus_left(1);
+    tab.horizontal_split(PaneId::Terminal(3), 1);
+    tab.move_focus_right(1);
+    tab.resize_left(1);
 
     assert_eq!(
         tab.panes
@@ -5246,12 +5284,12 @@ pub fn resize_left_with_panes_to_the_left_aligned_top_with_current_pane() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.vertical_split(PaneId::Terminal(3));
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(4));
-    tab.move_focus_down();
-    tab.resize_left();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.vertical_split(PaneId::Terminal(3), 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(4), 1);
+    tab.move_focus_down(1);
+    tab.resize_left(1);
 
     assert_eq!(
         tab.panes
@@ -5419,13 +5457,13 @@ pub fn resize_left_with_panes_to_the_right_aligned_top_with_current_pane() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.vertical_split(PaneId::Terminal(3));
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(4));
-    tab.move_focus_down();
-    tab.move_focus_left();
-    tab.resize_left();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.vertical_split(PaneId::Terminal(3), 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(4), 1);
+    tab.move_focus_down(1);
+    tab.move_focus_left(1);
+    tab.resize_left(1);
 
     assert_eq!(
         tab.panes
@@ -5593,11 +5631,11 @@ pub fn resize_left_with_panes_to_the_left_aligned_bottom_with_current_pane() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.vertical_split(PaneId::Terminal(3));
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(4));
-    tab.resize_left();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.vertical_split(PaneId::Terminal(3), 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(4), 1);
+    tab.resize_left(1);
 
     assert_eq!(
         tab.panes
@@ -5765,12 +5803,12 @@ pub fn resize_left_with_panes_to_the_right_aligned_bottom_with_current_pane() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.vertical_split(PaneId::Terminal(3));
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(4));
-    tab.move_focus_left();
-    tab.resize_left();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.vertical_split(PaneId::Terminal(3), 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(4), 1);
+    tab.move_focus_left(1);
+    tab.resize_left(1);
 
     assert_eq!(
         tab.panes
@@ -5940,15 +5978,15 @@ pub fn resize_left_with_panes_to_the_left_aligned_top_and_bottom_with_current_pa
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.horizontal_split(PaneId::Terminal(3));
-    tab.vertical_split(PaneId::Terminal(4));
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(5));
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(6));
-    tab.move_focus_down();
-    tab.resize_left();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.horizontal_split(PaneId::Terminal(3), 1);
+    tab.vertical_split(PaneId::Terminal(4), 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(5), 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(6), 1);
+    tab.move_focus_down(1);
+    tab.resize_left(1);
 
     assert_eq!(
         tab.panes
@@ -6192,16 +6230,16 @@ pub fn resize_left_with_panes_to_the_right_aligned_top_and_bottom_with_current_p
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.horizontal_split(PaneId::Terminal(3));
-    tab.vertical_split(PaneId::Terminal(4));
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(5));
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(6));
-    tab.move_focus_down();
-    tab.move_focus_left();
-    tab.resize_left();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.horizontal_split(PaneId::Terminal(3), 1);
+    tab.vertical_split(PaneId::Terminal(4), 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(5), 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(6), 1);
+    tab.move_focus_down(1);
+    tab.move_focus_left(1);
+    tab.resize_left(1);
 
     assert_eq!(
         tab.panes
@@ -6445,18 +6483,18 @@ pub fn resize_left_with_panes_to_the_left_aligned_top_and_bottom_with_panes_abov
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.horizontal_split(PaneId::Terminal(3));
-    tab.vertical_split(PaneId::Terminal(4));
-    tab.move_focus_up();
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(5));
-    tab.move_focus_down();
-    tab.vertical_split(PaneId::Terminal(6));
-    tab.horizontal_split(PaneId::Terminal(7));
-    tab.horizontal_split(PaneId::Terminal(8));
-    tab.move_focus_up();
-    tab.resize_left();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.horizontal_split(PaneId::Terminal(3), 1);
+    tab.vertical_split(PaneId::Terminal(4), 1);
+    tab.move_focus_up(1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(5), 1);
+    tab.move_focus_down(1);
+    tab.vertical_split(PaneId::Terminal(6), 1);
+    tab.horizontal_split(PaneId::Terminal(7), 1);
+    tab.horizontal_split(PaneId::Terminal(8), 1);
+    tab.move_focus_up(1);
+    tab.resize_left(1);
 
     assert_eq!(
         tab.panes
@@ -6774,19 +6812,19 @@ pub fn resize_left_with_panes_to_the_right_aligned_top_and_bottom_with_panes_abo
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.horizontal_split(PaneId::Terminal(3));
-    tab.vertical_split(PaneId::Terminal(4));
-    tab.move_focus_up();
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(5));
-    tab.move_focus_down();
-    tab.vertical_split(PaneId::Terminal(6));
-    tab.move_focus_left();
-    tab.horizontal_split(PaneId::Terminal(7));
-    tab.horizontal_split(PaneId::Terminal(8));
-    tab.move_focus_up();
-    tab.resize_left();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.horizontal_split(PaneId::Terminal(3), 1);
+    tab.vertical_split(PaneId::Terminal(4), 1);
+    tab.move_focus_up(1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(5), 1);
+    tab.move_focus_down(1);
+    tab.vertical_split(PaneId::Terminal(6), 1);
+    tab.move_focus_left(1);
+    tab.horizontal_split(PaneId::Terminal(7), 1);
+    tab.horizontal_split(PaneId::Terminal(8), 1);
+    tab.move_focus_up(1);
+    tab.resize_left(1);
 
     assert_eq!(
         tab.panes
@@ -7102,8 +7140,8 @@ pub fn cannot_resize_left_when_pane_to_the_left_is_at_minimum_width() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.vertical_split(PaneId::Terminal(2));
-    tab.resize_left();
+    tab.vertical_split(PaneId::Terminal(2), 1);
+    tab.resize_left(1);
 
     assert_eq!(
         tab.panes
@@ -7142,8 +7180,8 @@ pub fn resize_right_with_pane_to_the_left() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.vertical_split(PaneId::Terminal(2));
-    tab.resize_right();
+    tab.vertical_split(PaneId::Terminal(2), 1);
+    tab.resize_right(1);
 
     assert_eq!(
         tab.panes
@@ -7237,9 +7275,9 @@ pub fn resize_right_with_pane_to_the_right() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.vertical_split(PaneId::Terminal(2));
-    tab.move_focus_left();
-    tab.resize_right();
+    tab.vertical_split(PaneId::Terminal(2), 1);
+    tab.move_focus_left(1);
+    tab.resize_right(1);
 
     assert_eq!(
         tab.panes
@@ -7333,10 +7371,10 @@ pub fn resize_right_with_panes_to_the_left_and_right() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.vertical_split(PaneId::Terminal(2));
-    tab.vertical_split(PaneId::Terminal(3));
-    tab.move_focus_left();
-    tab.resize_right();
+    tab.vertical_split(PaneId::Terminal(2), 1);
+    tab.vertical_split(PaneId::Terminal(3), 1);
+    tab.move_focus_left(1);
+    tab.resize_right(1);
 
     assert_eq!(
         tab.panes
@@ -7467,11 +7505,11 @@ pub fn resize_right_with_multiple_panes_to_the_left() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.vertical_split(PaneId::Terminal(2));
-    tab.move_focus_left();
-    tab.horizontal_split(PaneId::Terminal(3));
-    tab.move_focus_right();
-    tab.resize_right();
+    tab.vertical_split(PaneId::Terminal(2), 1);
+    tab.move_focus_left(1);
+    tab.horizontal_split(PaneId::Terminal(3), 1);
+    tab.move_focus_right(1);
+    tab.resize_right(1);
 
     assert_eq!(
         tab.panes
@@ -7602,12 +7640,12 @@ pub fn resize_right_with_panes_to_the_left_aligned_top_with_current_pane() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.vertical_split(PaneId::Terminal(2));
-    tab.move_focus_left();
-    tab.horizontal_split(PaneId::Terminal(3));
-    tab.move_focus_right();
-    tab.horizontal_split(PaneId::Terminal(4));
-    tab.resize_right();
+    tab.vertical_split(PaneId::Terminal(2), 1);
+    tab.move_focus_left(1);
+    tab.horizontal_split(PaneId::Terminal(3), 1);
+    tab.move_focus_right(1);
+    tab.horizontal_split(PaneId::Terminal(4), 1);
+    tab.resize_right(1);
 
     assert_eq!(
         tab.panes
@@ -7774,13 +7812,13 @@ pub fn resize_right_with_panes_to_the_right_aligned_top_with_current_pane() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.vertical_split(PaneId::Terminal(2));
-    tab.move_focus_left();
-    tab.horizontal_split(PaneId::Terminal(3));
-    tab.move_focus_right();
-    tab.horizontal_split(PaneId::Terminal(4));
-    tab.move_focus_left();
-    tab.resize_right();
+    tab.vertical_split(PaneId::Terminal(2), 1);
+    tab.move_focus_left(1);
+    tab.horizontal_split(PaneId::Terminal(3), 1);
+    tab.move_focus_right(1);
+    tab.horizontal_split(PaneId::Terminal(4), 1);
+    tab.move_focus_left(1);
+    tab.resize_right(1);
 
     assert_eq!(
         tab.panes
@@ -7948,13 +7986,13 @@ pub fn resize_right_with_panes_to_the_left_aligned_bottom_with_current_pane() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.vertical_split(PaneId::Terminal(2));
-    tab.move_focus_left();
-    tab.horizontal_split(PaneId::Terminal(3));
-    tab.move_focus_right();
-    tab.horizontal_split(PaneId::Terminal(4));
-    tab.move_focus_up();
-    tab.resize_right();
+    tab.vertical_split(PaneId::Terminal(2), 1);
+    tab.move_focus_left(1);
+    tab.horizontal_split(PaneId::Terminal(3), 1);
+    tab.move_focus_right(1);
+    tab.horizontal_split(PaneId::Terminal(4), 1);
+    tab.move_focus_up(1);
+    tab.resize_right(1);
 
     assert_eq!(
         tab.panes
@@ -8122,14 +8160,14 @@ pub fn resize_right_with_panes_to_the_right_aligned_bottom_with_current_pane() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.vertical_split(PaneId::Terminal(2));
-    tab.move_focus_left();
-    tab.horizontal_split(PaneId::Terminal(3));
-    tab.move_focus_right();
-    tab.horizontal_split(PaneId::Terminal(4));
-    tab.move_focus_up();
-    tab.move_focus_left();
-    tab.resize_right();
+    tab.vertical_split(PaneId::Terminal(2), 1);
+    tab.move_focus_left(1);
+    tab.horizontal_split(PaneId::Terminal(3), 1);
+    tab.move_focus_right(1);
+    tab.horizontal_split(PaneId::Terminal(4), 1);
+    tab.move_focus_up(1);
+    tab.move_focus_left(1);
+    tab.resize_right(1);
 
     assert_eq!(
         tab.panes
@@ -8299,15 +8337,15 @@ pub fn resize_right_with_panes_to_the_left_aligned_top_and_bottom_with_current_p
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.horizontal_split(PaneId::Terminal(3));
-    tab.vertical_split(PaneId::Terminal(4));
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(5));
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(6));
-    tab.move_focus_down();
-    tab.resize_right();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.horizontal_split(PaneId::Terminal(3), 1);
+    tab.vertical_split(PaneId::Terminal(4), 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(5), 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(6), 1);
+    tab.move_focus_down(1);
+    tab.resize_right(1);
 
     assert_eq!(
         tab.panes
@@ -8550,16 +8588,16 @@ pub fn resize_right_with_panes_to_the_right_aligned_top_and_bottom_with_current_
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.horizontal_split(PaneId::Terminal(3));
-    tab.vertical_split(PaneId::Terminal(4));
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(5));
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(6));
-    tab.move_focus_down();
-    tab.move_focus_left();
-    tab.resize_right();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.horizontal_split(PaneId::Terminal(3), 1);
+    tab.vertical_split(PaneId::Terminal(4), 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(5), 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(6), 1);
+    tab.move_focus_down(1);
+    tab.move_focus_left(1);
+    tab.resize_right(1);
 
     assert_eq!(
         tab.panes
@@ -8802,18 +8840,18 @@ pub fn resize_right_with_panes_to_the_left_aligned_top_and_bottom_with_panes_abo
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.horizontal_split(PaneId::Terminal(3));
-    tab.vertical_split(PaneId::Terminal(4));
-    tab.move_focus_up();
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(5));
-    tab.move_focus_down();
-    tab.vertical_split(PaneId::Terminal(6));
-    tab.horizontal_split(PaneId::Terminal(7));
-    tab.horizontal_split(PaneId::Terminal(8));
-    tab.move_focus_up();
-    tab.resize_right();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.horizontal_split(PaneId::Terminal(3), 1);
+    tab.vertical_split(PaneId::Terminal(4), 1);
+    tab.move_focus_up(1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(5), 1);
+    tab.move_focus_down(1);
+    tab.vertical_split(PaneId::Terminal(6), 1);
+    tab.horizontal_split(PaneId::Terminal(7), 1);
+    tab.horizontal_split(PaneId::Terminal(8), 1);
+    tab.move_focus_up(1);
+    tab.resize_right(1);
 
     assert_eq!(
         tab.panes
@@ -9130,19 +9168,19 @@ pub fn resize_right_with_panes_to_the_right_aligned_top_and_bottom_with_panes_ab
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.horizontal_split(PaneId::Terminal(3));
-    tab.vertical_split(PaneId::Terminal(4));
-    tab.move_focus_up();
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(5));
-    tab.move_focus_down();
-    tab.vertical_split(PaneId::Terminal(6));
-    tab.move_focus_left();
-    tab.horizontal_split(PaneId::Terminal(7));
-    tab.horizontal_split(PaneId::Terminal(8));
-    tab.move_focus_up();
-    tab.resize_right();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.horizontal_split(PaneId::Terminal(3), 1);
+    tab.vertical_split(PaneId::Terminal(4), 1);
+    tab.move_focus_up(1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(5), 1);
+    tab.move_focus_down(1);
+    tab.vertical_split(PaneId::Terminal(6), 1);
+    tab.move_focus_left(1);
+    tab.horizontal_split(PaneId::Terminal(7), 1);
+    tab.horizontal_split(PaneId::Terminal(8), 1);
+    tab.move_focus_up(1);
+    tab.resize_right(1);
 
     assert_eq!(
         tab.panes
@@ -9457,8 +9495,8 @@ pub fn cannot_resize_right_when_pane_to_the_left_is_at_minimum_width() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.vertical_split(PaneId::Terminal(2));
-    tab.resize_right();
+    tab.vertical_split(PaneId::Terminal(2), 1);
+    tab.resize_right(1);
 
     assert_eq!(
         tab.panes
@@ -9498,8 +9536,8 @@ pub fn resize_up_with_pane_above() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.resize_up();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.resize_up(1);
 
     assert_eq!(
         tab.panes
@@ -9594,9 +9632,9 @@ pub fn resize_up_with_pane_below() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.move_focus_up();
-    tab.resize_up();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.move_focus_up(1);
+    tab.resize_up(1);
 
     assert_eq!(
         tab.panes
@@ -9694,10 +9732,10 @@ pub fn resize_up_with_panes_above_and_below() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.horizontal_split(PaneId::Terminal(3));
-    tab.move_focus_up();
-    tab.resize_up();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.horizontal_split(PaneId::Terminal(3), 1);
+    tab.move_focus_up(1);
+    tab.resize_up(1);
 
     assert_eq!(
         tab.panes
@@ -9828,11 +9866,11 @@ pub fn resize_up_with_multiple_panes_above() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(3));
-    tab.move_focus_down();
-    tab.resize_up();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(3), 1);
+    tab.move_focus_down(1);
+    tab.resize_up(1);
 
     assert_eq!(
         tab.panes
@@ -9962,12 +10000,12 @@ pub fn resize_up_with_panes_above_aligned_left_with_current_pane() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(3));
-    tab.move_focus_down();
-    tab.vertical_split(PaneId::Terminal(4));
-    tab.resize_up();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(3), 1);
+    tab.move_focus_down(1);
+    tab.vertical_split(PaneId::Terminal(4), 1);
+    tab.resize_up(1);
 
     assert_eq!(
         tab.panes
@@ -10136,13 +10174,13 @@ pub fn resize_up_with_panes_below_aligned_left_with_current_pane() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(3));
-    tab.move_focus_down();
-    tab.vertical_split(PaneId::Terminal(4));
-    tab.move_focus_up();
-    tab.resize_up();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(3), 1);
+    tab.move_focus_down(1);
+    tab.vertical_split(PaneId::Terminal(4), 1);
+    tab.move_focus_up(1);
+    tab.resize_up(1);
 
     assert_eq!(
         tab.panes
@@ -10311,13 +10349,13 @@ pub fn resize_up_with_panes_above_aligned_right_with_current_pane() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(3));
-    tab.move_focus_down();
-    tab.vertical_split(PaneId::Terminal(4));
-    tab.move_focus_left();
-    tab.resize_up();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(3), 1);
+    tab.move_focus_down(1);
+    tab.vertical_split(PaneId::Terminal(4), 1);
+    tab.move_focus_left(1);
+    tab.resize_up(1);
 
     assert_eq!(
         tab.panes
@@ -10486,14 +10524,14 @@ pub fn resize_up_with_panes_below_aligned_right_with_current_pane() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(3));
-    tab.move_focus_down();
-    tab.vertical_split(PaneId::Terminal(4));
-    tab.move_focus_left();
-    tab.move_focus_up();
-    tab.resize_up();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(3), 1);
+    tab.move_focus_down(1);
+    tab.vertical_split(PaneId::Terminal(4), 1);
+    tab.move_focus_left(1);
+    tab.move_focus_up(1);
+    tab.resize_up(1);
 
     assert_eq!(
         tab.panes
@@ -10662,14 +10700,14 @@ pub fn resize_up_with_panes_above_aligned_left_and_right_with_current_pane() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.vertical_split(PaneId::Terminal(3));
-    tab.vertical_split(PaneId::Terminal(4));
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(5));
-    tab.vertical_split(PaneId::Terminal(6));
-    tab.move_focus_left();
-    tab.resize_up();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.vertical_split(PaneId::Terminal(3), 1);
+    tab.vertical_split(PaneId::Terminal(4), 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(5), 1);
+    tab.vertical_split(PaneId::Terminal(6), 1);
+    tab.move_focus_left(1);
+    tab.resize_up(1);
 
     assert_eq!(
         tab.panes
@@ -10912,15 +10950,15 @@ pub fn resize_up_with_panes_below_aligned_left_and_right_with_current_pane() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.vertical_split(PaneId::Terminal(3));
-    tab.vertical_split(PaneId::Terminal(4));
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(5));
-    tab.vertical_split(PaneId::Terminal(6));
-    tab.move_focus_left();
-    tab.move_focus_up();
-    tab.resize_up();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.vertical_split(PaneId::Terminal(3), 1);
+    tab.vertical_split(PaneId::Terminal(4), 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(5), 1);
+    tab.vertical_split(PaneId::Terminal(6), 1);
+    tab.move_focus_left(1);
+    tab.move_focus_up(1);
+    tab.resize_up(1);
 
     assert_eq!(
         tab.panes
@@ -11163,18 +11201,18 @@ pub fn resize_up_with_panes_above_aligned_left_and_right_with_panes_to_the_left_
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(3));
-    tab.vertical_split(PaneId::Terminal(4));
-    tab.move_focus_down();
-    tab.vertical_split(PaneId::Terminal(5));
-    tab.vertical_split(PaneId::Terminal(6));
-    tab.move_focus_left();
-    tab.vertical_split(PaneId::Terminal(7));
-    tab.vertical_split(PaneId::Terminal(8));
-    tab.move_focus_left();
-    tab.resize_up();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(3), 1);
+    tab.vertical_split(PaneId::Terminal(4), 1);
+    tab.move_focus_down(1);
+    tab.vertical_split(PaneId::Terminal(5), 1);
+    tab.vertical_split(PaneId::Terminal(6), 1);
+    tab.move_focus_left(1);
+    tab.vertical_split(PaneId::Terminal(7), 1);
+    tab.vertical_split(PaneId::Terminal(8), 1);
+    tab.move_focus_left(1);
+    tab.resize_up(1);
 
     assert_eq!(
         tab.panes
@@ -11491,19 +11529,19 @@ pub fn resize_up_with_panes_below_aligned_left_and_right_with_to_the_left_and_ri
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.move_focus_up();
-    tab.vertical_split(PaneId::Terminal(3));
-    tab.vertical_split(PaneId::Terminal(4));
-    tab.move_focus_down();
-    tab.vertical_split(PaneId::Terminal(5));
-    tab.vertical_split(PaneId::Terminal(6));
-    tab.move_focus_up();
-    tab.move_focus_left();
-    tab.vertical_split(PaneId::Terminal(7));
-    tab.vertical_split(PaneId::Terminal(8));
-    tab.move_focus_left();
-    tab.resize_up();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.move_focus_up(1);
+    tab.vertical_split(PaneId::Terminal(3), 1);
+    tab.vertical_split(PaneId::Terminal(4), 1);
+    tab.move_focus_down(1);
+    tab.vertical_split(PaneId::Terminal(5), 1);
+    tab.vertical_split(PaneId::Terminal(6), 1);
+    tab.move_focus_up(1);
+    tab.move_focus_left(1);
+    tab.vertical_split(PaneId::Terminal(7), 1);
+    tab.vertical_split(PaneId::Terminal(8), 1);
+    tab.move_focus_left(1);
+    tab.resize_up(1);
 
     assert_eq!(
         tab.panes
@@ -11819,8 +11857,8 @@ pub fn cannot_resize_up_when_pane_above_is_at_minimum_height() {
         ..Default::default()
     };
     let mut tab = create_new_tab(position_and_size);
-    tab.horizontal_split(PaneId::Terminal(2));
-    tab.resize_down();
+    tab.horizontal_split(PaneId::Terminal(2), 1);
+    tab.resize_down(1);
 
     assert_eq!(
         tab.panes
@@ -11841,3 +11879,258 @@ pub fn cannot_resize_up_when_pane_above_is_at_minimum_height() {
         "pane 2 height stayed the same"
     );
 }
+
+#[test]
+pub fn clients_move_focus_independently() {
+    let position_and_size = PositionAndSize {
+        cols: 121,
+        rows: 20,
+        x: 0,
+        y: 0,
+        ..Default::default()
+    };
+    let mut tab = create_new_tab(position_and_size);
+    tab.vertical_split(PaneId::Terminal(2), 1);
+    tab.add_client(2);
+    tab.move_focus_left(2);
+
+    assert_eq!(
+        tab.get_active_pane(1).unwrap().pid(),
+        PaneId::Terminal(2),
+        "first client is still focused on the new pane"
+    );
+    assert_eq!(
+        tab.get_active_pane(2).unwrap().pid(),
+        PaneId::Terminal(1),
+        "second client moved its focus to the left pane"
+    );
+}
+
+#[test]
+pub fn closing_focused_pane_moves_focus_of_all_its_clients() {
+    let position_and_size = PositionAndSize {
+        cols: 121,
+        rows: 20,
+        x: 0,
+        y: 0,
+        ..Default::default()
+    };
+    let mut tab = create_new_tab(position_and_size);
+    tab.vertical_split(PaneId::Terminal(2), 1);
+    tab.add_client(2);
+    tab.close_focused_pane(1);
+
+    assert_eq!(
+        tab.get_active_pane(1).unwrap().pid(),
+        PaneId::Terminal(1),
+        "first client focused on the remaining pane"
+    );
+    assert_eq!(
+        tab.get_active_pane(2).unwrap().pid(),
+        PaneId::Terminal(1),
+        "second client focused on the remaining pane"
+    );
+}
+
+#[test]
+pub fn layout_focuses_and_names_its_panes() {
+    let position_and_size = PositionAndSize {
+        cols: 121,
+        rows: 20,
+        x: 0,
+        y: 0,
+        ..Default::default()
+    };
+    let mut tab = create_new_tab(position_and_size);
+    let layout: Layout = zellij_utils::serde_yaml::from_str(
+        "
+direction: Vertical
+parts:
+  - direction: Horizontal
+    name: editor
+  - direction: Horizontal
+    name: logs
+    focus: true
+  - direction: Horizontal
+",
+    )
+    .unwrap();
+    tab.apply_layout(layout, vec![2, 3]);
+
+    let active_pane = tab.get_active_pane(1).unwrap();
+    assert_eq!(
+        active_pane.pid(),
+        PaneId::Terminal(2),
+        "the pane marked in the layout is focused"
+    );
+    assert_eq!(active_pane.name(), Some("logs".to_string()));
+    assert_eq!(
+        tab.panes.get(&PaneId::Terminal(1)).unwrap().name(),
+        Some("editor".to_string()),
+        "the pane that was already open is named too"
+    );
+}
+
+#[test]
+pub fn programs_copy_to_the_clipboard_as_allowed() {
+    let position_and_size = PositionAndSize {
+        cols: 121,
+        rows: 20,
+        x: 0,
+        y: 0,
+        ..Default::default()
+    };
+    let copy = "\u{1b}]52;c;eWFuaw==\u{7}".as_bytes().to_vec();
+
+    let mut tab = create_new_tab(position_and_size);
+    tab.session_state
+        .write()
+        .unwrap()
+        .set_clipboard_access(ClipboardAccess::Deny);
+    tab.handle_pty_bytes(1, copy.clone());
+    assert_eq!(tab.session_state.read().unwrap().clipboard(), None);
+
+    let mut tab = create_new_tab(position_and_size);
+    tab.session_state
+        .write()
+        .unwrap()
+        .set_clipboard_access(ClipboardAccess::Allow);
+    tab.handle_pty_bytes(1, copy.clone());
+    assert_eq!(
+        tab.session_state.read().unwrap().clipboard(),
+        Some("eWFuaw==")
+    );
+
+    let os_input = FakeInputOutput::default();
+    let mut tab = create_new_tab_with_os_input(position_and_size, os_input.clone());
+    tab.handle_pty_bytes(1, copy);
+    assert_eq!(
+        tab.session_state.read().unwrap().clipboard(),
+        None,
+        "copies are prompted for by default"
+    );
+    tab.write_to_active_terminal(b"a".to_vec(), 1);
+    assert_eq!(
+        os_input.tty_stdin_bytes.lock().unwrap().get(&1),
+        Some(&b"a".to_vec()),
+        "other input reaches the pane"
+    );
+    assert_eq!(tab.session_state.read().unwrap().clipboard(), None);
+    tab.write_to_active_terminal(b"y".to_vec(), 1);
+    assert_eq!(
+        tab.session_state.read().unwrap().clipboard(),
+        Some("eWFuaw=="),
+        "the copy happens once allowed"
+    );
+    assert_eq!(
+        os_input.tty_stdin_bytes.lock().unwrap().get(&1),
+        Some(&b"a".to_vec()),
+        "the answer does not reach the pane"
+    );
+}
+
+#[test]
+pub fn clipboard_prompts_are_answered_with_synced_panes() {
+    let position_and_size = PositionAndSize {
+        cols: 121,
+        rows: 20,
+        x: 0,
+        y: 0,
+        ..Default::default()
+    };
+    let copy = "\u{1b}]52;c;eWFuaw==\u{7}".as_bytes().to_vec();
+
+    let os_input = FakeInputOutput::default();
+    let mut tab = create_new_tab_with_os_input(position_and_size, os_input.clone());
+    tab.new_pane(PaneId::Terminal(2), 1);
+    tab.toggle_sync_panes_is_active();
+    tab.handle_pty_bytes(1, copy);
+    tab.write_to_terminals_on_current_tab(b"\x1b".to_vec(), 1);
+    assert!(
+        os_input.tty_stdin_bytes.lock().unwrap().is_empty(),
+        "the answer does not reach the panes"
+    );
+    tab.write_to_terminals_on_current_tab(b"y".to_vec(), 1);
+    assert_eq!(
+        tab.session_state.read().unwrap().clipboard(),
+        None,
+        "the copy was dropped"
+    );
+    assert_eq!(
+        os_input.tty_stdin_bytes.lock().unwrap().get(&2),
+        Some(&b"y".to_vec()),
+        "input reaches the panes once the prompt is answered"
+    );
+}
+
+#[test]
+pub fn programs_read_the_clipboard_only_when_allowed() {
+    let position_and_size = PositionAndSize {
+        cols: 121,
+        rows: 20,
+        x: 0,
+        y: 0,
+        ..Default::default()
+    };
+    let query = "\u{1b}]52;c;?\u{7}".as_bytes().to_vec();
+
+    let os_input = FakeInputOutput::default();
+    let mut tab = create_new_tab_with_os_input(position_and_size, os_input.clone());
+    tab.session_state
+        .write()
+        .unwrap()
+        .set_clipboard("eWFuaw==".into());
+    tab.handle_pty_bytes(1, query.clone());
+    assert_eq!(
+        os_input.tty_stdin_bytes.lock().unwrap().get(&1),
+        Some(&"\u{1b}]52;c;\u{7}".as_bytes().to_vec()),
+        "the clipboard reads as empty by default"
+    );
+
+    let os_input = FakeInputOutput::default();
+    let mut tab = create_new_tab_with_os_input(position_and_size, os_input.clone());
+    {
+        let mut session_state = tab.session_state.write().unwrap();
+        session_state.set_clipboard("eWFuaw==".into());
+        session_state.set_allow_clipboard_read(true);
+    }
+    tab.handle_pty_bytes(1, query);
+    assert_eq!(
+        os_input.tty_stdin_bytes.lock().unwrap().get(&1),
+        Some(&"\u{1b}]52;c;eWFuaw==\u{7}".as_bytes().to_vec())
+    );
+}
+
+#[test]
+pub fn panes_are_titled_by_the_foreground_command_as_last_looked_up() {
+    let position_and_size = PositionAndSize {
+        cols: 121,
+        rows: 20,
+        x: 0,
+        y: 0,
+        ..Default::default()
+    };
+    let os_input = FakeInputOutput::default();
+    *os_input.foreground_cmdline.lock().unwrap() = Some(vec!["-bash".into()]);
+    let mut tab = create_new_tab_with_os_input(position_and_size, os_input.clone());
+    assert_eq!(tab.pane_titles(), vec![(PaneId::Terminal(1), None)]);
+
+    assert!(tab.update_foreground_commands());
+    assert_eq!(
+        tab.pane_titles(),
+        vec![(PaneId::Terminal(1), Some("bash".into()))]
+    );
+
+    *os_input.foreground_cmdline.lock().unwrap() = Some(vec!["/usr/bin/vim".into()]);
+    assert_eq!(
+        tab.pane_titles(),
+        vec![(PaneId::Terminal(1), Some("bash".into()))],
+        "titles are not looked up when rendering"
+    );
+    assert!(tab.update_foreground_commands());
+    assert!(!tab.update_foreground_commands());
+    assert_eq!(
+        tab.pane_titles(),
+        vec![(PaneId::Terminal(1), Some("vim".into()))]
+    );
+}
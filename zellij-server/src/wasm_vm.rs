@@ -1,4 +1,4 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 use std::path::PathBuf;
 use std::process;
@@ -20,6 +20,7 @@ use crate::{
     pty::PtyInstruction,
     screen::ScreenInstruction,
     thread_bus::{Bus, ThreadSenders},
+    ClientId,
 };
 use zellij_utils::errors::{ContextType, PluginContext};
 use zellij_utils::{input::command::TerminalAction, serde, zellij_tile};
@@ -27,9 +28,11 @@ use zellij_utils::{input::command::TerminalAction, serde, zellij_tile};
 #[derive(Clone, Debug)]
 pub(crate) enum PluginInstruction {
     Load(Sender<u32>, PathBuf),
-    Update(Option<u32>, Event), // Focused plugin / broadcast, event data
-    Render(Sender<String>, u32, usize, usize), // String buffer, plugin id, rows, cols
+    Update(Option<u32>, Option<ClientId>, Event), // Focused plugin / broadcast, client / all clients, event data
+    Render(Sender<String>, u32, ClientId, usize, usize), // String buffer, plugin id, client id, rows, cols
     Unload(u32),
+    AddClient(ClientId),
+    RemoveClient(ClientId),
     Exit,
 }
 
@@ -40,6 +43,8 @@ impl From<&PluginInstruction> for PluginContext {
             PluginInstruction::Update(..) => PluginContext::Update,
             PluginInstruction::Render(..) => PluginContext::Render,
             PluginInstruction::Unload(_) => PluginContext::Unload,
+            PluginInstruction::AddClient(_) => PluginContext::AddClient,
+            PluginInstruction::RemoveClient(_) => PluginContext::RemoveClient,
             PluginInstruction::Exit => PluginContext::Exit,
         }
     }
@@ -48,6 +53,7 @@ impl From<&PluginInstruction> for PluginContext {
 #[derive(WasmerEnv, Clone)]
 pub(crate) struct PluginEnv {
     pub plugin_id: u32,
+    pub client_id: ClientId,
     pub senders: ThreadSenders,
     pub wasi_env: WasiEnv,
     pub subscriptions: Arc<Mutex<HashSet<EventType>>>,
@@ -56,7 +62,12 @@ pub(crate) struct PluginEnv {
 // Thread main --------------------------------------------------------------------------------------------------------
 pub(crate) fn wasm_thread_main(bus: Bus<PluginInstruction>, store: Store, data_dir: PathBuf) {
     let mut plugin_id = 0;
-    let mut plugin_map = HashMap::new();
+    // every client gets its own instance of each plugin, so that it can keep its own state
+    // (eg. the input mode displayed in the status bar)
+    let mut plugin_map: HashMap<(u32, ClientId), (Instance, PluginEnv)> = HashMap::new();
+    // the compiled plugins, kept around in order to start them for clients that attach later
+    let mut plugin_modules: BTreeMap<u32, Module> = BTreeMap::new();
+    let mut connected_clients: Vec<ClientId> = vec![];
     loop {
         let (event, mut err_ctx) = bus.recv().expect("failed to receive event on channel");
         err_ctx.add_call(ContextType::Plugin((&event).into()));
@@ -71,50 +82,23 @@ pub(crate) fn wasm_thread_main(bus: Bus<PluginInstruction>, store: Store, data_d
                 // FIXME: Cache this compiled module on disk. I could use `(de)serialize_to_file()` for that
                 let module = Module::new(&store, &wasm_bytes).unwrap();
 
-                let output = Pipe::new();
-                let input = Pipe::new();
-                let mut wasi_env = WasiState::new("Zellij")
-                    .env("CLICOLOR_FORCE", "1")
-                    .preopen(|p| {
-                        p.directory(".") // FIXME: Change this to a more meaningful dir
-                            .alias(".")
-                            .read(true)
-                            .write(true)
-                            .create(true)
-                    })
-                    .unwrap()
-                    .stdin(Box::new(input))
-                    .stdout(Box::new(output))
-                    .finalize()
-                    .unwrap();
-
-                let wasi = wasi_env.import_object(&module).unwrap();
-
-                let plugin_env = PluginEnv {
-                    plugin_id,
-                    senders: bus.senders.clone(),
-                    wasi_env,
-                    subscriptions: Arc::new(Mutex::new(HashSet::new())),
-                };
-
-                let zellij = zellij_exports(&store, &plugin_env);
-                let instance = Instance::new(&module, &zellij.chain_back(wasi)).unwrap();
-
-                let start = instance.exports.get_function("_start").unwrap();
-
-                // This eventually calls the `.load()` method
-                start.call(&[]).unwrap();
-
-                plugin_map.insert(plugin_id, (instance, plugin_env));
+                for &client_id in &connected_clients {
+                    let plugin = start_plugin(&store, &module, plugin_id, client_id, &bus.senders);
+                    plugin_map.insert((plugin_id, client_id), plugin);
+                }
+                plugin_modules.insert(plugin_id, module);
                 pid_tx.send(plugin_id).unwrap();
                 plugin_id += 1;
             }
-            PluginInstruction::Update(pid, event) => {
-                for (&i, (instance, plugin_env)) in &plugin_map {
+            PluginInstruction::Update(pid, cid, event) => {
+                for (&(i, client_id), (instance, plugin_env)) in &plugin_map {
                     let subs = plugin_env.subscriptions.lock().unwrap();
                     // FIXME: This is very janky... Maybe I should write my own macro for Event -> EventType?
                     let event_type = EventType::from_str(&event.to_string()).unwrap();
-                    if (pid.is_none() || pid == Some(i)) && subs.contains(&event_type) {
+                    if (pid.is_none() || pid == Some(i))
+                        && (cid.is_none() || cid == Some(client_id))
+                        && subs.contains(&event_type)
+                    {
                         let update = instance.exports.get_function("update").unwrap();
                         wasi_write_object(&plugin_env.wasi_env, &event);
                         update.call(&[]).unwrap();
@@ -122,23 +106,87 @@ pub(crate) fn wasm_thread_main(bus: Bus<PluginInstruction>, store: Store, data_d
                 }
                 drop(bus.senders.send_to_screen(ScreenInstruction::Render));
             }
-            PluginInstruction::Render(buf_tx, pid, rows, cols) => {
-                let (instance, plugin_env) = plugin_map.get(&pid).unwrap();
-
-                let render = instance.exports.get_function("render").unwrap();
+            PluginInstruction::Render(buf_tx, pid, cid, rows, cols) => {
+                // the client might have been removed while this render was on its way
+                let output = match plugin_map.get(&(pid, cid)) {
+                    Some((instance, plugin_env)) => {
+                        let render = instance.exports.get_function("render").unwrap();
 
-                render
-                    .call(&[Value::I32(rows as i32), Value::I32(cols as i32)])
-                    .unwrap();
+                        render
+                            .call(&[Value::I32(rows as i32), Value::I32(cols as i32)])
+                            .unwrap();
 
-                buf_tx.send(wasi_read_string(&plugin_env.wasi_env)).unwrap();
+                        wasi_read_string(&plugin_env.wasi_env)
+                    }
+                    None => String::new(),
+                };
+                buf_tx.send(output).unwrap();
+            }
+            PluginInstruction::Unload(pid) => {
+                plugin_modules.remove(&pid);
+                plugin_map.retain(|&(i, _), _| i != pid);
+            }
+            PluginInstruction::AddClient(client_id) => {
+                for (&pid, module) in &plugin_modules {
+                    let plugin = start_plugin(&store, module, pid, client_id, &bus.senders);
+                    plugin_map.insert((pid, client_id), plugin);
+                }
+                connected_clients.push(client_id);
+            }
+            PluginInstruction::RemoveClient(client_id) => {
+                plugin_map.retain(|&(_, cid), _| cid != client_id);
+                connected_clients.retain(|&cid| cid != client_id);
             }
-            PluginInstruction::Unload(pid) => drop(plugin_map.remove(&pid)),
             PluginInstruction::Exit => break,
         }
     }
 }
 
+fn start_plugin(
+    store: &Store,
+    module: &Module,
+    plugin_id: u32,
+    client_id: ClientId,
+    senders: &ThreadSenders,
+) -> (Instance, PluginEnv) {
+    let output = Pipe::new();
+    let input = Pipe::new();
+    let mut wasi_env = WasiState::new("Zellij")
+        .env("CLICOLOR_FORCE", "1")
+        .preopen(|p| {
+            p.directory(".") // FIXME: Change this to a more meaningful dir
+                .alias(".")
+                .read(true)
+                .write(true)
+                .create(true)
+        })
+        .unwrap()
+        .stdin(Box::new(input))
+        .stdout(Box::new(output))
+        .finalize()
+        .unwrap();
+
+    let wasi = wasi_env.import_object(module).unwrap();
+
+    let plugin_env = PluginEnv {
+        plugin_id,
+        client_id,
+        senders: senders.clone(),
+        wasi_env,
+        subscriptions: Arc::new(Mutex::new(HashSet::new())),
+    };
+
+    let zellij = zellij_exports(store, &plugin_env);
+    let instance = Instance::new(module, &zellij.chain_back(wasi)).unwrap();
+
+    let start = instance.exports.get_function("_start").unwrap();
+
+    // This eventually calls the `.load()` method
+    start.call(&[]).unwrap();
+
+    (instance, plugin_env)
+}
+
 // Plugin API ---------------------------------------------------------------------------------------------------------
 
 pub(crate) fn zellij_exports(store: &Store, plugin_env: &PluginEnv) -> ImportObject {
@@ -234,9 +282,10 @@ fn host_open_file(plugin_env: &PluginEnv) {
     let path: PathBuf = wasi_read_object(&plugin_env.wasi_env);
     plugin_env
         .senders
-        .send_to_pty(PtyInstruction::SpawnTerminal(Some(
-            TerminalAction::OpenFile(path),
-        )))
+        .send_to_pty(PtyInstruction::SpawnTerminal(
+            Some(TerminalAction::OpenFile(path)),
+            plugin_env.client_id,
+        ))
         .unwrap();
 }
 
@@ -252,6 +301,7 @@ fn host_set_timeout(plugin_env: &PluginEnv, secs: f64) {
     // But that's a lot of code, and this is a few lines:
     let send_plugin_instructions = plugin_env.senders.to_plugin.clone();
     let update_target = Some(plugin_env.plugin_id);
+    let client_id = Some(plugin_env.client_id);
     thread::spawn(move || {
         let start_time = Instant::now();
         thread::sleep(Duration::from_secs_f64(secs));
@@ -263,6 +313,7 @@ fn host_set_timeout(plugin_env: &PluginEnv, secs: f64) {
             .unwrap()
             .send(PluginInstruction::Update(
                 update_target,
+                client_id,
                 Event::Timer(elapsed_time),
             ))
             .unwrap();
@@ -33,6 +33,10 @@ pub enum Event {
     TabUpdate(Vec<TabInfo>),
     KeyPress(Key),
     Timer(f64),
+    /// The session was renamed
+    SessionNameUpdate(String),
+    /// The panes of the session, sent whenever a pane is opened, closed or changes its title
+    PaneUpdate(Vec<PaneInfo>),
 }
 
 /// Describes the different input modes, which change the way that keystrokes will be interpreted.
@@ -159,6 +163,18 @@ pub struct TabInfo {
     pub is_sync_panes_active: bool,
 }
 
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct PaneInfo {
+    /* subset of fields to publish to plugins */
+    pub id: u32,
+    pub is_plugin: bool,
+    /// The position of the tab of the pane
+    pub tab_position: usize,
+    /// The name given to the pane by its layout, the title set by the program running in it,
+    /// or else the name of the command running in it
+    pub title: Option<String>,
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub struct PluginIds {
     pub plugin_id: u32,
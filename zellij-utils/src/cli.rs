@@ -1,4 +1,5 @@
 use crate::consts::{ZELLIJ_CONFIG_DIR_ENV, ZELLIJ_CONFIG_FILE_ENV};
+use crate::input::actions::Direction;
 use crate::input::options::Options;
 use crate::setup::Setup;
 use serde::{Deserialize, Serialize};
@@ -32,6 +33,15 @@ pub struct CliArgs {
     #[structopt(long, parse(from_os_str))]
     pub layout_path: Option<PathBuf>,
 
+    /// Parameter of the layout, as `name=value`, replacing `{{name}}` in the layout file
+    #[structopt(
+        long = "layout-arg",
+        value_name = "NAME=VALUE",
+        number_of_values = 1,
+        parse(try_from_str = parse_layout_arg)
+    )]
+    pub layout_args: Vec<(String, String)>,
+
     /// Change where zellij looks for the configuration file
     #[structopt(short, long, env=ZELLIJ_CONFIG_FILE_ENV, parse(from_os_str))]
     pub config: Option<PathBuf>,
@@ -47,6 +57,13 @@ pub struct CliArgs {
     pub debug: bool,
 }
 
+fn parse_layout_arg(arg: &str) -> Result<(String, String), String> {
+    match arg.split_once('=') {
+        Some((name, value)) if !name.is_empty() => Ok((name.to_string(), value.to_string())),
+        _ => Err(format!("expected `name=value`, got `{}`", arg)),
+    }
+}
+
 #[derive(Debug, StructOpt, Clone, Serialize, Deserialize)]
 pub enum Command {
     /// Change the behaviour of zellij
@@ -60,13 +77,22 @@ pub enum Command {
     /// Explore existing zellij sessions
     #[structopt(flatten)]
     Sessions(Sessions),
+
+    /// Send actions to a running session (the current one when run from inside zellij,
+    /// otherwise the one given with `--session` or the only active one)
+    #[structopt(name = "action")]
+    Action(CliAction),
 }
 
 #[derive(Debug, StructOpt, Clone, Serialize, Deserialize)]
 pub enum Sessions {
-    /// List active sessions
+    /// List active sessions, removing the sockets of dead ones
     #[structopt(alias = "ls")]
-    ListSessions,
+    ListSessions {
+        /// Print the sessions and the removed sockets as JSON
+        #[structopt(long)]
+        json: bool,
+    },
 
     /// Attach to session
     #[structopt(alias = "a")]
@@ -75,8 +101,130 @@ pub enum Sessions {
         session_name: Option<String>,
 
         /// Force attach- session will detach from the other
-        /// zellij client (if any) and attach to this.
+        /// zellij clients (if any) and attach to this.
         #[structopt(long, short)]
         force: bool,
+
+        /// Attach as an observer- the session is rendered,
+        /// but any input other than detaching is ignored.
+        #[structopt(long)]
+        read_only: bool,
+
+        /// If the session is gone, start it again from the
+        /// tabs and panes it last had.
+        #[structopt(long, requires = "session-name")]
+        resurrect: bool,
+
+        /// Create the session if it does not exist, using the
+        /// layout given to zellij (if any).
+        #[structopt(long, short, requires = "session-name")]
+        create: bool,
+    },
+
+    /// Kill the session, terminating the processes running in its panes
+    #[structopt(alias = "k")]
+    KillSession {
+        /// Name of the session to kill
+        name: String,
+    },
+
+    /// Rename the session given with `--session`, the current one by default
+    RenameSession {
+        /// The new name of the session
+        new_name: String,
+    },
+
+    /// Kill all sessions
+    #[structopt(alias = "ka")]
+    KillAllSessions {
+        /// Do not ask for confirmation
+        #[structopt(short, long)]
+        yes: bool,
+    },
+
+    /// Describe the tabs and panes of a running session
+    Query {
+        /// Name of the session to query, the current one by default.
+        session_name: Option<String>,
+
+        /// Print the description as JSON
+        #[structopt(long)]
+        json: bool,
+    },
+}
+
+/// Actions that can be performed on a running session from the command line.
+/// They are performed on behalf of the client that was most recently active in the session.
+#[derive(Debug, StructOpt, Clone, Serialize, Deserialize)]
+pub enum CliAction {
+    /// Write bytes to the focused pane
+    Write {
+        #[structopt(required = true)]
+        bytes: Vec<u8>,
+    },
+    /// Write characters to the focused pane,
+    /// interpreting the escapes \n, \r, \t and \\
+    WriteChars { chars: String },
+    /// Resize the focused pane in the specified direction [right|left|up|down]
+    Resize { direction: Direction },
+    /// Change focus to the next pane
+    FocusNextPane,
+    /// Change focus to the previous pane
+    FocusPreviousPane,
+    /// Move focus to the pane in the specified direction [right|left|up|down]
+    MoveFocus { direction: Direction },
+    /// Scroll up in the focused pane
+    ScrollUp,
+    /// Scroll down in the focused pane
+    ScrollDown,
+    /// Scroll up one page in the focused pane
+    PageScrollUp,
+    /// Scroll down one page in the focused pane
+    PageScrollDown,
+    /// Toggle between fullscreen focused pane and normal layout
+    ToggleFullscreen,
+    /// Toggle sending text commands to all panes on the current tab
+    ToggleActiveSyncTab,
+    /// Open a new pane in the specified direction [right|left|up|down],
+    /// or in the biggest available space if no direction is given
+    NewPane {
+        #[structopt(short, long)]
+        direction: Option<Direction>,
+    },
+    /// Run a command in a new pane, eg. `zellij action run -d right -- cargo test`
+    Run {
+        #[structopt(short, long)]
+        direction: Option<Direction>,
+        #[structopt(required = true)]
+        command: Vec<String>,
+    },
+    /// Close the focused pane
+    ClosePane,
+    /// Create a new tab
+    NewTab,
+    /// Go to the next tab
+    GoToNextTab,
+    /// Go to the previous tab
+    GoToPreviousTab,
+    /// Close the current tab
+    CloseTab,
+    /// Go to the tab at the given position, starting from 1
+    GoToTab { index: u32 },
+    /// Rename the current tab
+    RenameTab { name: String },
+    /// Print the layout of the current tab, which can be loaded back with `--layout-path`
+    DumpLayout,
+    /// Open a layout in a new tab, by its path or its name in the layout directory
+    NewTabWithLayout {
+        #[structopt(parse(from_os_str))]
+        layout: PathBuf,
+        /// Parameter of the layout, as `name=value`, replacing `{{name}}` in the layout file
+        #[structopt(
+            long = "layout-arg",
+            value_name = "NAME=VALUE",
+            number_of_values = 1,
+            parse(try_from_str = parse_layout_arg)
+        )]
+        layout_args: Vec<(String, String)>,
     },
 }
@@ -221,11 +221,17 @@ pub enum ScreenContext {
     GoToTab,
     UpdateTabName,
     TerminalResize,
+    SetCharacterCellSize,
     ChangeMode,
     LeftClick,
     MouseRelease,
     MouseHold,
+    MouseEvent,
     Copy,
+    AddClient,
+    RemoveClient,
+    QuerySession,
+    UpdateForegroundCommands,
 }
 
 /// Stack call representations corresponding to the different types of [`PtyInstruction`]s.
@@ -235,8 +241,11 @@ pub enum PtyContext {
     SpawnTerminalVertically,
     SpawnTerminalHorizontally,
     NewTab,
+    NewTabWithLayout,
     ClosePane,
     CloseTab,
+    FillSessionInfo,
+    ResurrectTab,
     Exit,
 }
 
@@ -247,6 +256,8 @@ pub enum PluginContext {
     Update,
     Render,
     Unload,
+    AddClient,
+    RemoveClient,
     Exit,
 }
 
@@ -267,7 +278,11 @@ pub enum ServerContext {
     Render,
     UnblockInputThread,
     ClientExit,
+    RemoveClient,
     Error,
     DetachSession,
     AttachClient,
+    SessionInfo,
+    KillSession,
+    RenameSession,
 }
@@ -1,10 +1,14 @@
 //! Definition of the actions that can be bound to keys.
 
 use super::command::RunCommandAction;
+use crate::cli::CliAction;
 use crate::input::options::OnForceClose;
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::str::FromStr;
 use zellij_tile::data::InputMode;
 
+use crate::input::mouse::{MouseEvent, MouseModifiers};
 use crate::position::Position;
 
 /// The four directions (left, right, up, down).
@@ -16,6 +20,23 @@ pub enum Direction {
     Down,
 }
 
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "left" | "Left" => Ok(Direction::Left),
+            "right" | "Right" => Ok(Direction::Right),
+            "up" | "Up" => Ok(Direction::Up),
+            "down" | "Down" => Ok(Direction::Down),
+            e => Err(format!(
+                "Unknown direction: {}, expected one of: left, right, up, down",
+                e
+            )),
+        }
+    }
+}
+
 // As these actions are bound to the default config, please
 // do take care when refactoring - or renaming.
 // They might need to be adjusted in the default config
@@ -77,9 +98,22 @@ pub enum Action {
     Run(RunCommandAction),
     /// Detach session and exit
     Detach,
+    /// Rename the session
+    RenameSession(String),
+    /// Open the layout with the given name in the layout directory, or at the given path,
+    /// in a new tab, or in a tab for each of its `tabs`, with the given layout args.
+    NewTabWithLayout(PathBuf, Vec<(String, String)>),
+    /// Copy the layout of the current tab to the clipboard,
+    /// or print it when performed from the command line
+    DumpLayout,
+    /// Mouse actions handled by Zellij itself, to select and scroll,
+    /// even over panes whose program tracks the mouse
     LeftClick(Position),
     MouseRelease(Position),
     MouseHold(Position),
+    /// A mouse event, reported to the program of the pane under it if it tracks the mouse,
+    /// and otherwise handled like the mouse actions above
+    MouseEvent(MouseEvent, MouseModifiers),
     Copy,
 }
 
@@ -91,3 +125,81 @@ impl From<OnForceClose> for Action {
         }
     }
 }
+
+impl From<CliAction> for Vec<Action> {
+    fn from(cli_action: CliAction) -> Vec<Action> {
+        match cli_action {
+            CliAction::Write { bytes } => vec![Action::Write(bytes)],
+            CliAction::WriteChars { chars } => vec![Action::Write(unescape(&chars).into_bytes())],
+            CliAction::Resize { direction } => vec![Action::Resize(direction)],
+            CliAction::FocusNextPane => vec![Action::FocusNextPane],
+            CliAction::FocusPreviousPane => vec![Action::FocusPreviousPane],
+            CliAction::MoveFocus { direction } => vec![Action::MoveFocus(direction)],
+            CliAction::ScrollUp => vec![Action::ScrollUp],
+            CliAction::ScrollDown => vec![Action::ScrollDown],
+            CliAction::PageScrollUp => vec![Action::PageScrollUp],
+            CliAction::PageScrollDown => vec![Action::PageScrollDown],
+            CliAction::ToggleFullscreen => vec![Action::ToggleFocusFullscreen],
+            CliAction::ToggleActiveSyncTab => vec![Action::ToggleActiveSyncTab],
+            CliAction::NewPane { direction } => vec![Action::NewPane(direction)],
+            CliAction::Run { direction, command } => {
+                let mut command = command.into_iter();
+                vec![Action::Run(RunCommandAction {
+                    command: command.next().unwrap_or_default().into(),
+                    args: command.collect(),
+                    direction,
+                })]
+            }
+            CliAction::ClosePane => vec![Action::CloseFocus],
+            CliAction::NewTab => vec![Action::NewTab],
+            CliAction::GoToNextTab => vec![Action::GoToNextTab],
+            CliAction::GoToPreviousTab => vec![Action::GoToPreviousTab],
+            CliAction::CloseTab => vec![Action::CloseTab],
+            CliAction::GoToTab { index } => vec![Action::GoToTab(index)],
+            // a zero byte clears the name before the new one is typed in
+            CliAction::RenameTab { name } => vec![
+                Action::TabNameInput(vec![0]),
+                Action::TabNameInput(name.into_bytes()),
+            ],
+            CliAction::DumpLayout => vec![Action::DumpLayout],
+            // the server looks up relative paths in the layout directory
+            // if they are not relative to the directory of the command
+            CliAction::NewTabWithLayout {
+                layout,
+                layout_args,
+            } => vec![Action::NewTabWithLayout(
+                layout.canonicalize().unwrap_or(layout),
+                layout_args,
+            )],
+        }
+    }
+}
+
+/// Replaces the escape sequences `\n`, `\r`, `\t` and `\\` in `chars`,
+/// so they can be typed on the command line. Any other backslash is left as is.
+fn unescape(chars: &str) -> String {
+    let mut unescaped = String::with_capacity(chars.len());
+    let mut chars = chars.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some('t') => unescaped.push('\t'),
+            Some('\\') => unescaped.push('\\'),
+            Some(other) => {
+                unescaped.push('\\');
+                unescaped.push(other);
+            }
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+#[cfg(test)]
+#[path = "./unit/actions_test.rs"]
+mod actions_test;
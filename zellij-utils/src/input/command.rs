@@ -1,6 +1,7 @@
 //! Trigger a command
 use super::actions::Direction;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 #[derive(Debug, Clone)]
@@ -15,6 +16,12 @@ pub struct RunCommand {
     pub command: PathBuf,
     #[serde(default)]
     pub args: Vec<String>,
+    /// The directory to start the command in, the server's working directory by default
+    #[serde(default)]
+    pub cwd: Option<PathBuf>,
+    /// Variables to add to the environment the command is started with
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
 }
 
 /// Intermediate representation
@@ -33,6 +40,7 @@ impl From<RunCommandAction> for RunCommand {
         RunCommand {
             command: action.command,
             args: action.args,
+            ..Default::default()
         }
     }
 }
@@ -45,6 +45,8 @@ pub enum ConfigError {
     IoPath(io::Error, PathBuf),
     // Internal Deserialization Error
     FromUtf8(std::string::FromUtf8Error),
+    // Error in the parameters or includes of a layout
+    LayoutTemplate(String),
 }
 
 impl Default for Config {
@@ -138,6 +140,7 @@ impl Display for ConfigError {
             }
             ConfigError::Serde(ref err) => write!(formatter, "Deserialization error: {}", err),
             ConfigError::FromUtf8(ref err) => write!(formatter, "FromUtf8Error: {}", err),
+            ConfigError::LayoutTemplate(ref err) => write!(formatter, "Layout error: {}", err),
         }
     }
 }
@@ -149,6 +152,7 @@ impl std::error::Error for ConfigError {
             ConfigError::IoPath(ref err, _) => Some(err),
             ConfigError::Serde(ref err) => Some(err),
             ConfigError::FromUtf8(ref err) => Some(err),
+            ConfigError::LayoutTemplate(_) => None,
         }
     }
 }
@@ -10,14 +10,17 @@
 //  then [`zellij-utils`] could be a proper place.
 use crate::{
     input::{command::RunCommand, config::ConfigError},
+    ipc::TabInfo,
     pane_size::PositionAndSize,
     setup,
 };
 use crate::{serde, serde_yaml};
 
 use serde::{Deserialize, Serialize};
+use serde_yaml::Value;
+use std::collections::BTreeMap;
+use std::fs;
 use std::path::{Path, PathBuf};
-use std::{fs::File, io::prelude::*};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(crate = "self::serde")]
@@ -50,28 +53,104 @@ pub struct Layout {
     pub parts: Vec<Layout>,
     pub split_size: Option<SplitSize>,
     pub run: Option<Run>,
+    /// The name of the tab, for the layouts in `tabs`, or of the pane, for the layouts
+    /// without parts
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Whether the tab is the one focused at startup, for the layouts in `tabs`,
+    /// or the pane is the one focused when its tab opens, for the layouts without parts
+    #[serde(default)]
+    pub focus: bool,
+    /// The tabs to open at startup, each with its own pane tree.
+    /// Tabs opened later on use the pane tree of this layout.
+    #[serde(default)]
+    pub tabs: Vec<Layout>,
+    /// The directory the panes are started in, inherited by the parts and tabs.
+    /// A relative path is relative to the `cwd` of the parent, or to the layout file.
+    #[serde(default)]
+    pub cwd: Option<PathBuf>,
+    /// Variables to add to the environment of the panes, inherited by the parts and tabs
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
 }
 
 type LayoutResult = Result<Layout, ConfigError>;
 
 impl Layout {
     pub fn new(layout_path: &Path) -> LayoutResult {
-        let mut layout_file = File::open(&layout_path)
-            .or_else(|_| File::open(&layout_path.with_extension("yaml")))
-            .map_err(|e| ConfigError::IoPath(e, layout_path.into()))?;
+        Self::from_template(layout_path, None, &[])
+    }
 
-        let mut layout = String::new();
-        layout_file.read_to_string(&mut layout)?;
-        let layout: Layout = serde_yaml::from_str(&layout)?;
+    /// Loads the layout at `layout_path`, replacing each `{{name}}` in its strings with the
+    /// value of `name` in `layout_args`, and each node with an `include` key with the layout
+    /// fragment it names, looked up in `layout_dir` and then next to the including file.
+    pub fn from_template(
+        layout_path: &Path,
+        layout_dir: Option<&Path>,
+        layout_args: &[(String, String)],
+    ) -> LayoutResult {
+        let template = LayoutTemplate {
+            layout_dir,
+            layout_args,
+        };
+        let layout = template.load(layout_path, &mut vec![])?;
+        let mut layout: Layout = serde_yaml::from_value(layout)?;
+        if let Some(layout_dir) = layout_path.parent() {
+            layout.resolve_cwd_and_env(layout_dir);
+        }
         Ok(layout)
     }
 
+    /// Makes the `cwd` of every part absolute, relative to `layout_dir` if no parent sets one,
+    /// and passes the `cwd` and `env` down the tree to the commands of the panes.
+    pub fn resolve_cwd_and_env(&mut self, layout_dir: &Path) {
+        self.inherit_cwd_and_env(layout_dir, None, &BTreeMap::new());
+    }
+
+    pub(crate) fn inherit_cwd_and_env(
+        &mut self,
+        layout_dir: &Path,
+        parent_cwd: Option<&Path>,
+        parent_env: &BTreeMap<String, String>,
+    ) {
+        self.cwd = match self.cwd.take() {
+            Some(cwd) => Some(parent_cwd.unwrap_or(layout_dir).join(cwd)),
+            None => parent_cwd.map(Path::to_path_buf),
+        };
+        let mut env = parent_env.clone();
+        env.append(&mut self.env);
+        self.env = env;
+        if let Some(Run::Command(command)) = &mut self.run {
+            command.cwd = match (command.cwd.take(), &self.cwd) {
+                (Some(cwd), Some(pane_cwd)) => Some(pane_cwd.join(cwd)),
+                (Some(cwd), None) => Some(layout_dir.join(cwd)),
+                (None, pane_cwd) => pane_cwd.clone(),
+            };
+            let mut env = self.env.clone();
+            env.append(&mut command.env);
+            command.env = env;
+        }
+        for part in self.parts.iter_mut().chain(self.tabs.iter_mut()) {
+            part.inherit_cwd_and_env(layout_dir, self.cwd.as_deref(), &self.env);
+        }
+    }
+
     // It wants to use Path here, but that doesn't compile.
     #[allow(clippy::ptr_arg)]
-    pub fn from_dir(layout: &PathBuf, layout_dir: Option<&PathBuf>) -> LayoutResult {
+    pub fn from_dir(
+        layout: &PathBuf,
+        layout_dir: Option<&PathBuf>,
+        layout_args: &[(String, String)],
+    ) -> LayoutResult {
         match layout_dir {
-            Some(dir) => Self::new(&dir.join(layout))
-                .or_else(|_| Self::from_default_assets(layout.as_path())),
+            Some(dir) => {
+                let layout_path = dir.join(layout);
+                if layout_path.exists() || layout_path.with_extension("yaml").exists() {
+                    Self::from_template(&layout_path, Some(dir), layout_args)
+                } else {
+                    Self::from_default_assets(layout.as_path())
+                }
+            }
             None => Self::from_default_assets(layout.as_path()),
         }
     }
@@ -80,14 +159,18 @@ impl Layout {
         layout: Option<&PathBuf>,
         layout_path: Option<&PathBuf>,
         layout_dir: Option<PathBuf>,
+        layout_args: &[(String, String)],
     ) -> Option<Layout> {
         let layout_result = layout
-            .map(|p| Layout::from_dir(p, layout_dir.as_ref()))
-            .or_else(|| layout_path.map(|p| Layout::new(p)))
+            .map(|p| Layout::from_dir(p, layout_dir.as_ref(), layout_args))
+            .or_else(|| {
+                layout_path.map(|p| Layout::from_template(p, layout_dir.as_deref(), layout_args))
+            })
             .or_else(|| {
                 Some(Layout::from_dir(
                     &std::path::PathBuf::from("default"),
                     layout_dir.as_ref(),
+                    layout_args,
                 ))
             });
 
@@ -96,6 +179,7 @@ impl Layout {
             Some(Ok(layout)) => Some(layout),
             Some(Err(e)) => {
                 eprintln!("There was an error in the layout file:\n{}", e);
+                eprintln!("Run `zellij setup --check-layout <file>` to see all of its problems.");
                 std::process::exit(1);
             }
         }
@@ -136,6 +220,72 @@ impl Layout {
         Ok(layout)
     }
 
+    /// Describes the panes of `tab` as a layout whose terminal panes run, in their working
+    /// directory, the command in their foreground if `foreground_commands` is set, or else the
+    /// one they were launched with. Also returns the scrollback the panes were described with,
+    /// in the order of [`Layout::extract_run_instructions`].
+    pub fn from_tab_info(tab: &TabInfo, foreground_commands: bool) -> (Layout, Vec<Vec<String>>) {
+        let panes = tab
+            .panes
+            .iter()
+            .map(|pane| {
+                let position_and_size = PositionAndSize {
+                    x: pane.x,
+                    y: pane.y,
+                    rows: pane.rows,
+                    cols: pane.cols,
+                    ..Default::default()
+                };
+                let command_line = if foreground_commands {
+                    pane.command.as_ref().or(pane.launched_command.as_ref())
+                } else {
+                    pane.launched_command.as_ref()
+                };
+                let run = if pane.is_plugin {
+                    Some(Run::Plugin(pane.plugin_location.clone()))
+                } else {
+                    command_line.and_then(|command_line| {
+                        let (command, args) = command_line.split_first()?;
+                        Some(Run::Command(RunCommand {
+                            command: PathBuf::from(command),
+                            args: args.to_vec(),
+                            cwd: pane.cwd.clone(),
+                            ..Default::default()
+                        }))
+                    })
+                };
+                let scrollback = pane.scrollback.clone().unwrap_or_default();
+                let focused = !pane.focused_clients.is_empty();
+                (
+                    position_and_size,
+                    run,
+                    (scrollback, pane.name.clone(), focused),
+                )
+            })
+            .collect();
+        let (mut layout, pane_data) = Layout::from_panes(panes);
+        // a tab with a single pane has nothing to focus
+        let has_several_panes = pane_data.len() > 1;
+        let mut focus_set = false;
+        let mut scrollback = Vec::with_capacity(pane_data.len());
+        for (leaf, (pane_scrollback, name, focused)) in
+            layout.leaves_mut().into_iter().zip(pane_data)
+        {
+            leaf.name = name;
+            leaf.focus = focused && has_several_panes && !focus_set;
+            focus_set |= leaf.focus;
+            scrollback.push(pane_scrollback);
+        }
+        (layout, scrollback)
+    }
+
+    /// Serializes the layout, leaving out the fields that are not set.
+    pub fn to_yaml(&self) -> Result<String, ConfigError> {
+        let mut layout = serde_yaml::to_value(self)?;
+        remove_unset_fields(&mut layout);
+        Ok(serde_yaml::to_string(&layout)?)
+    }
+
     pub fn total_terminal_panes(&self) -> usize {
         let mut total_panes = 0;
         total_panes += self.parts.len();
@@ -162,12 +312,164 @@ impl Layout {
         run_instructions
     }
 
+    /// The layouts of the panes, in the same order as [`Layout::extract_run_instructions`]
+    pub fn extract_panes(&self) -> Vec<&Layout> {
+        if self.parts.is_empty() {
+            return vec![self];
+        }
+        self.parts.iter().flat_map(Layout::extract_panes).collect()
+    }
+
+    fn leaves_mut(&mut self) -> Vec<&mut Layout> {
+        if self.parts.is_empty() {
+            return vec![self];
+        }
+        self.parts.iter_mut().flat_map(Layout::leaves_mut).collect()
+    }
+
     pub fn position_panes_in_space(
         &self,
         space: &PositionAndSize,
     ) -> Vec<(Layout, PositionAndSize)> {
         split_space(space, self)
     }
+
+    /// Reconstructs the layout splitting the space covered by `panes` between them,
+    /// returning it along with the data attached to each pane, in the order of
+    /// [`Layout::extract_run_instructions`].
+    /// Parts made of a single plugin pane keep their size, the others are sized in percent,
+    /// except for the biggest, which grows into the space left over by rounding.
+    pub fn from_panes<T>(panes: Vec<(PositionAndSize, Option<Run>, T)>) -> (Layout, Vec<T>) {
+        let mut pane_data = Vec::with_capacity(panes.len());
+        let layout = layout_from_panes(panes, &mut pane_data);
+        (layout, pane_data)
+    }
+}
+
+/// A pane of a layout being reconstructed, along with the data attached to it
+type PaneWithData<T> = (PositionAndSize, Option<Run>, T);
+
+fn bounding_space<T>(panes: &[PaneWithData<T>]) -> PositionAndSize {
+    let x = panes.iter().map(|(pane, ..)| pane.x).min().unwrap_or(0);
+    let y = panes.iter().map(|(pane, ..)| pane.y).min().unwrap_or(0);
+    let right = panes.iter().map(|(pane, ..)| pane.x + pane.cols).max();
+    let bottom = panes.iter().map(|(pane, ..)| pane.y + pane.rows).max();
+    PositionAndSize {
+        x,
+        y,
+        cols: right.unwrap_or(x) - x,
+        rows: bottom.unwrap_or(y) - y,
+        ..Default::default()
+    }
+}
+
+/// Groups `panes` into the parts of a split along one axis, where `extent` returns the
+/// start and the size of a pane along it. A part ends where none of its panes reaches further.
+fn split_panes<T>(
+    mut panes: Vec<PaneWithData<T>>,
+    extent: fn(&PositionAndSize) -> (usize, usize),
+) -> Vec<Vec<PaneWithData<T>>> {
+    panes.sort_by_key(|(pane, ..)| extent(pane));
+    let mut parts: Vec<Vec<PaneWithData<T>>> = Vec::new();
+    let mut part_end = 0;
+    for pane in panes {
+        let (start, size) = extent(&pane.0);
+        match parts.last_mut() {
+            Some(part) if start < part_end => part.push(pane),
+            _ => parts.push(vec![pane]),
+        }
+        part_end = part_end.max(start + size);
+    }
+    parts
+}
+
+fn layout_from_panes<T>(mut panes: Vec<PaneWithData<T>>, pane_data: &mut Vec<T>) -> Layout {
+    if panes.len() == 1 {
+        let (_, run, data) = panes.pop().unwrap();
+        pane_data.push(data);
+        return Layout {
+            direction: Direction::Horizontal,
+            parts: vec![],
+            split_size: None,
+            run,
+            name: None,
+            focus: false,
+            tabs: vec![],
+            cwd: None,
+            env: BTreeMap::new(),
+        };
+    }
+    let space = bounding_space(&panes);
+    let columns = split_panes(panes, |pane| (pane.x, pane.cols));
+    let (direction, parts) = if columns.len() > 1 {
+        (Direction::Vertical, columns)
+    } else {
+        let panes = columns.into_iter().flatten().collect();
+        let rows = split_panes(panes, |pane| (pane.y, pane.rows));
+        if rows.len() > 1 {
+            (Direction::Horizontal, rows)
+        } else {
+            // the panes cannot be separated by straight cuts, so stack them
+            let mut panes: Vec<_> = rows.into_iter().flatten().collect();
+            panes.sort_by_key(|(pane, ..)| (pane.y, pane.x));
+            (
+                Direction::Horizontal,
+                panes.into_iter().map(|pane| vec![pane]).collect(),
+            )
+        }
+    };
+    let total_size = match direction {
+        Direction::Vertical => space.cols,
+        Direction::Horizontal => space.rows,
+    }
+    .saturating_sub(parts.len() - 1)
+    .max(1);
+    let parts: Vec<(usize, bool, Vec<PaneWithData<T>>)> = parts
+        .into_iter()
+        .map(|part| {
+            let part_space = bounding_space(&part);
+            let size = match direction {
+                Direction::Vertical => part_space.cols,
+                Direction::Horizontal => part_space.rows,
+            };
+            let is_plugin = part.len() == 1 && matches!(part[0].1, Some(Run::Plugin(_)));
+            (size, is_plugin, part)
+        })
+        .collect();
+    let growing_part = parts
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, is_plugin, _))| !is_plugin)
+        .max_by_key(|(_, (size, ..))| *size)
+        .map(|(index, _)| index);
+    let parts = parts
+        .into_iter()
+        .enumerate()
+        .map(|(index, (size, is_plugin, part))| {
+            let mut layout = layout_from_panes(part, pane_data);
+            layout.split_size = if is_plugin {
+                Some(SplitSize::Fixed(size as u16))
+            } else if Some(index) == growing_part {
+                None
+            } else {
+                // round up, as positioning the layout rounds the percentage down
+                let percent = (size * 100 + total_size - 1) / total_size;
+                Some(SplitSize::Percent(percent.max(1).min(100) as u8))
+            };
+            layout
+        })
+        .collect();
+    Layout {
+        direction,
+        parts,
+        split_size: None,
+        run: None,
+        name: None,
+        focus: false,
+        tabs: vec![],
+        cwd: None,
+        env: BTreeMap::new(),
+    }
 }
 
 fn split_space_to_parts_vertically(
@@ -179,6 +481,7 @@ fn split_space_to_parts_vertically(
     let mut current_width = 0;
     let max_width = space_to_split.cols - (sizes.len() - 1); // minus space for gaps
 
+    // by index, as the positions of the parts change once they grow
     let mut parts_to_grow = Vec::new();
 
     // First fit in the parameterized sizes
@@ -189,7 +492,7 @@ fn split_space_to_parts_vertically(
             } // TODO: round properly
             Some(SplitSize::Fixed(size)) => size as usize,
             None => {
-                parts_to_grow.push(current_x_position);
+                parts_to_grow.push(split_parts.len());
                 1 // This is grown later on
             }
         };
@@ -211,10 +514,10 @@ fn split_space_to_parts_vertically(
     let mut last_flexible_index = split_parts.len() - 1;
     if let Some(new_columns) = (max_width - current_width).checked_div(parts_to_grow.len()) {
         current_width = 0;
-        current_x_position = 0;
+        current_x_position = space_to_split.x;
         for (idx, part) in split_parts.iter_mut().enumerate() {
             part.x = current_x_position;
-            if parts_to_grow.contains(&part.x) {
+            if parts_to_grow.contains(&idx) {
                 part.cols = new_columns;
                 last_flexible_index = idx;
             }
@@ -244,6 +547,7 @@ fn split_space_to_parts_horizontally(
     let mut current_height = 0;
     let max_height = space_to_split.rows - (sizes.len() - 1); // minus space for gaps
 
+    // by index, as the positions of the parts change once they grow
     let mut parts_to_grow = Vec::new();
 
     for size in sizes {
@@ -253,7 +557,7 @@ fn split_space_to_parts_horizontally(
             } // TODO: round properly
             Some(SplitSize::Fixed(size)) => size as usize,
             None => {
-                parts_to_grow.push(current_y_position);
+                parts_to_grow.push(split_parts.len());
                 1 // This is grown later on
             }
         };
@@ -275,11 +579,11 @@ fn split_space_to_parts_horizontally(
     let mut last_flexible_index = split_parts.len() - 1;
     if let Some(new_rows) = (max_height - current_height).checked_div(parts_to_grow.len()) {
         current_height = 0;
-        current_y_position = 0;
+        current_y_position = space_to_split.y;
 
         for (idx, part) in split_parts.iter_mut().enumerate() {
             part.y = current_y_position;
-            if parts_to_grow.contains(&part.y) {
+            if parts_to_grow.contains(&idx) {
                 part.rows = new_rows;
                 last_flexible_index = idx;
             }
@@ -300,10 +604,202 @@ fn split_space_to_parts_horizontally(
     split_parts
 }
 
+/// Removes the null, false and empty values from the mappings of `value`,
+/// which deserialize to the same defaults.
+fn remove_unset_fields(value: &mut Value) {
+    match value {
+        Value::Mapping(mapping) => {
+            let unset_keys: Vec<Value> = mapping
+                .iter()
+                .filter(|(_, value)| match value {
+                    Value::Null | Value::Bool(false) => true,
+                    Value::Sequence(sequence) => sequence.is_empty(),
+                    Value::Mapping(mapping) => mapping.is_empty(),
+                    _ => false,
+                })
+                .map(|(key, _)| key.clone())
+                .collect();
+            for key in unset_keys {
+                mapping.remove(&key);
+            }
+            for (_, value) in mapping.iter_mut() {
+                remove_unset_fields(value);
+            }
+        }
+        Value::Sequence(sequence) => sequence.iter_mut().for_each(remove_unset_fields),
+        _ => {}
+    }
+}
+
+/// The key of the layout nodes that are replaced by a layout fragment
+const INCLUDE_KEY: &str = "include";
+/// The key of the parameters a layout file requires a value for
+const PARAMS_KEY: &str = "params";
+
+/// The parameters and include directory a layout file is loaded with
+pub(crate) struct LayoutTemplate<'a> {
+    pub layout_dir: Option<&'a Path>,
+    pub layout_args: &'a [(String, String)],
+}
+
+impl LayoutTemplate<'_> {
+    /// Loads the layout at `layout_path` with its parameters replaced and its includes
+    /// expanded, `included_from` holding the files that (transitively) include it.
+    pub(crate) fn load(
+        &self,
+        layout_path: &Path,
+        included_from: &mut Vec<PathBuf>,
+    ) -> Result<Value, ConfigError> {
+        let layout_path = if layout_path.exists() {
+            layout_path.to_path_buf()
+        } else {
+            layout_path.with_extension("yaml")
+        };
+        if included_from.contains(&layout_path) {
+            return Err(ConfigError::LayoutTemplate(format!(
+                "{} includes itself",
+                layout_path.display()
+            )));
+        }
+        let template = fs::read_to_string(&layout_path)
+            .map_err(|e| ConfigError::IoPath(e, layout_path.clone()))?;
+        let mut layout: Value = serde_yaml::from_str(&template)?;
+        take_params(&mut layout)
+            .and_then(|params| self.substitute_args(&mut layout, &params))
+            .map_err(|e| {
+                ConfigError::LayoutTemplate(format!("{}, File: {}", e, layout_path.display()))
+            })?;
+        included_from.push(layout_path.clone());
+        self.expand_includes(&mut layout, &layout_path, included_from)?;
+        included_from.pop();
+        Ok(layout)
+    }
+
+    /// Replaces the parameters in the strings of `node`, failing if one of the declared
+    /// `params` has no value. Other `{{...}}`, eg. of the templates of commands, are kept.
+    fn substitute_args(&self, node: &mut Value, params: &[String]) -> Result<(), String> {
+        match node {
+            Value::String(text) => *text = self.substitute_args_in(text, params)?,
+            Value::Mapping(mapping) => {
+                for (_, value) in mapping.iter_mut() {
+                    self.substitute_args(value, params)?;
+                }
+            }
+            Value::Sequence(sequence) => {
+                for value in sequence.iter_mut() {
+                    self.substitute_args(value, params)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn substitute_args_in(&self, text: &str, params: &[String]) -> Result<String, String> {
+        let mut substituted = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find("{{") {
+            let end = match rest[start..].find("}}") {
+                Some(end) => start + end,
+                None => break,
+            };
+            let name = rest[start + 2..end].trim();
+            let value = self.layout_args.iter().find(|(arg, _)| arg == name);
+            substituted.push_str(&rest[..start]);
+            match value {
+                Some((_, value)) => substituted.push_str(value),
+                None if params.iter().any(|param| param == name) => {
+                    return Err(format!("no value given for `{{{{{}}}}}`", name));
+                }
+                None => substituted.push_str(&rest[start..end + 2]),
+            }
+            rest = &rest[end + 2..];
+        }
+        substituted.push_str(rest);
+        Ok(substituted)
+    }
+
+    fn expand_includes(
+        &self,
+        node: &mut Value,
+        layout_path: &Path,
+        included_from: &mut Vec<PathBuf>,
+    ) -> Result<(), ConfigError> {
+        match node {
+            Value::Mapping(mapping) => {
+                if let Some(include) = mapping.remove(&Value::from(INCLUDE_KEY)) {
+                    let fragment_path = self.fragment_path(&include, layout_path)?;
+                    // the other keys of the node override the ones of the fragment
+                    let overrides = std::mem::take(mapping);
+                    *node = self.load(&fragment_path, included_from)?;
+                    match node {
+                        Value::Mapping(fragment) => fragment.extend(overrides),
+                        _ => {
+                            return Err(ConfigError::LayoutTemplate(format!(
+                                "{} is not a layout node",
+                                fragment_path.display()
+                            )))
+                        }
+                    }
+                    return Ok(());
+                }
+                for (_, value) in mapping.iter_mut() {
+                    self.expand_includes(value, layout_path, included_from)?;
+                }
+            }
+            Value::Sequence(sequence) => {
+                for value in sequence.iter_mut() {
+                    self.expand_includes(value, layout_path, included_from)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn fragment_path(&self, include: &Value, layout_path: &Path) -> Result<PathBuf, ConfigError> {
+        let include = include.as_str().ok_or_else(|| {
+            ConfigError::LayoutTemplate(format!(
+                "`{}` expects the name of a layout, File: {}",
+                INCLUDE_KEY,
+                layout_path.display()
+            ))
+        })?;
+        let next_to_layout = layout_path.parent().map(|dir| dir.join(include));
+        Ok(self
+            .layout_dir
+            .map(|dir| dir.join(include))
+            .filter(|path| path.exists() || path.with_extension("yaml").exists())
+            .or(next_to_layout)
+            .unwrap_or_else(|| PathBuf::from(include)))
+    }
+}
+
+/// Removes the names of the parameters declared with `params` from the root of `layout`
+fn take_params(layout: &mut Value) -> Result<Vec<String>, String> {
+    let params = match layout {
+        Value::Mapping(mapping) => mapping.remove(&Value::from(PARAMS_KEY)),
+        _ => None,
+    };
+    match params {
+        None => Ok(vec![]),
+        Some(Value::Sequence(params)) => params
+            .iter()
+            .map(|param| param.as_str().map(str::to_string))
+            .collect::<Option<_>>()
+            .ok_or_else(|| format!("`{}` expects a list of names", PARAMS_KEY)),
+        Some(_) => Err(format!("`{}` expects a list of names", PARAMS_KEY)),
+    }
+}
+
 fn split_space(
     space_to_split: &PositionAndSize,
     layout: &Layout,
 ) -> Vec<(Layout, PositionAndSize)> {
+    if layout.parts.is_empty() {
+        // a layout without parts is a single pane taking up all of the space
+        return vec![(layout.clone(), *space_to_split)];
+    }
     let mut pane_positions = Vec::new();
     let sizes: Vec<Option<SplitSize>> = layout.parts.iter().map(|part| part.split_size).collect();
 
@@ -322,3 +818,7 @@ fn split_space(
     }
     pane_positions
 }
+
+#[cfg(test)]
+#[path = "./unit/layout_test.rs"]
+mod layout_test;
@@ -0,0 +1,345 @@
+//! Checks layout files for the mistakes that would only show once the layout is opened,
+//! locating each one by its path in the layout and its line in the layout file.
+use crate::input::{
+    config::ConfigError,
+    layout::{Layout, LayoutTemplate, Run, SplitSize},
+};
+use crate::serde_yaml::{self, Value};
+
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::Path;
+use yaml_rust::parser::{Event, MarkedEventReceiver, Parser};
+use yaml_rust::scanner::Marker;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayoutProblem {
+    /// The path of the problematic field in the layout, eg. `parts[1].split_size`
+    pub path: String,
+    /// The line of the field in the layout file, if it is not part of an included layout
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl Display for LayoutProblem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let path = if self.path.is_empty() {
+            "(root)"
+        } else {
+            &self.path
+        };
+        match self.line {
+            Some(line) => write!(f, "line {}: {}: {}", line, path, self.message),
+            None => write!(f, "{}: {}", path, self.message),
+        }
+    }
+}
+
+/// Checks the layout at `layout_path`, loaded the way [`Layout::from_template`] does,
+/// for fields that cannot be deserialized, sizes that do not fit, and plugins or commands
+/// that cannot be found. Fails if the layout cannot be loaded at all.
+pub fn check_layout(
+    layout_path: &Path,
+    layout_dir: Option<&Path>,
+    layout_args: &[(String, String)],
+    plugin_dir: &Path,
+) -> Result<Vec<LayoutProblem>, ConfigError> {
+    let template = LayoutTemplate {
+        layout_dir,
+        layout_args,
+    };
+    let layout = template.load(layout_path, &mut vec![])?;
+
+    // an included layout is located by the node that includes it
+    let layout_path = if layout_path.exists() {
+        layout_path.to_path_buf()
+    } else {
+        layout_path.with_extension("yaml")
+    };
+    let text = fs::read_to_string(&layout_path)
+        .map_err(|e| ConfigError::IoPath(e, layout_path.clone()))?;
+    let mut lines = YamlLines::default();
+    Parser::new(text.chars())
+        .load(&mut lines, false)
+        .map_err(|e| ConfigError::LayoutTemplate(e.to_string()))?;
+
+    let mut checker = LayoutChecker {
+        layout_dir: layout_path.parent().unwrap_or_else(|| Path::new(".")),
+        plugin_dir,
+        lines: lines.lines,
+        problems: vec![],
+    };
+    checker.check_node(&layout, String::new(), None, &BTreeMap::new());
+    Ok(checker.problems)
+}
+
+struct LayoutChecker<'a> {
+    layout_dir: &'a Path,
+    plugin_dir: &'a Path,
+    lines: HashMap<String, usize>,
+    problems: Vec<LayoutProblem>,
+}
+
+impl LayoutChecker<'_> {
+    /// Checks `node` on its own, then its parts and tabs, returning its split size
+    fn check_node(
+        &mut self,
+        node: &Value,
+        path: String,
+        parent_cwd: Option<&Path>,
+        parent_env: &BTreeMap<String, String>,
+    ) -> Option<SplitSize> {
+        // the parts and tabs are checked on their own, so that their problems are located
+        let mut shallow_node = node.clone();
+        let (parts, tabs) = match &mut shallow_node {
+            Value::Mapping(mapping) => (
+                mapping.remove(&Value::from("parts")),
+                mapping.remove(&Value::from("tabs")),
+            ),
+            _ => (None, None),
+        };
+        let mut layout: Option<Layout> = match serde_yaml::from_value(shallow_node) {
+            Ok(layout) => Some(layout),
+            Err(e) => {
+                self.report(&path, e.to_string());
+                None
+            }
+        };
+        if let Some(layout) = layout.as_mut() {
+            layout.inherit_cwd_and_env(self.layout_dir, parent_cwd, parent_env);
+            self.check_split_size(layout, &path);
+            self.check_run(layout, &path);
+        }
+        let (cwd, env) = layout
+            .as_ref()
+            .map(|layout| (layout.cwd.clone(), layout.env.clone()))
+            .unwrap_or_default();
+
+        let parts_path = join(&path, "parts");
+        let part_sizes: Vec<Option<SplitSize>> = as_sequence(parts.as_ref())
+            .iter()
+            .enumerate()
+            .map(|(index, part)| {
+                self.check_node(
+                    part,
+                    format!("{}[{}]", parts_path, index),
+                    cwd.as_deref(),
+                    &env,
+                )
+            })
+            .collect();
+        self.check_percentages(&part_sizes, &parts_path);
+        let tabs_path = join(&path, "tabs");
+        for (index, tab) in as_sequence(tabs.as_ref()).iter().enumerate() {
+            self.check_node(
+                tab,
+                format!("{}[{}]", tabs_path, index),
+                cwd.as_deref(),
+                &env,
+            );
+        }
+        layout.and_then(|layout| layout.split_size)
+    }
+
+    fn check_split_size(&mut self, layout: &Layout, path: &str) {
+        if let Some(SplitSize::Percent(percent)) = layout.split_size {
+            if !(1..=100).contains(&percent) {
+                self.report(
+                    &join(path, "split_size"),
+                    format!("Percent must be between 1 and 100, got {}", percent),
+                );
+            }
+        }
+    }
+
+    fn check_percentages(&mut self, part_sizes: &[Option<SplitSize>], path: &str) {
+        let total: u32 = part_sizes
+            .iter()
+            .map(|size| match size {
+                Some(SplitSize::Percent(percent)) => *percent as u32,
+                _ => 0,
+            })
+            .sum();
+        let has_percentages = part_sizes
+            .iter()
+            .any(|size| matches!(size, Some(SplitSize::Percent(_))));
+        let has_flexible_parts = part_sizes.iter().any(Option::is_none);
+        if !has_percentages {
+            return;
+        }
+        if has_flexible_parts && total >= 100 {
+            self.report(
+                path,
+                format!(
+                    "the percentages of the parts add up to {}, leaving no room for the parts without a split_size",
+                    total
+                ),
+            );
+        } else if !has_flexible_parts && total != 100 {
+            self.report(
+                path,
+                format!("the percentages of the parts add up to {}, not 100", total),
+            );
+        }
+    }
+
+    fn check_run(&mut self, layout: &Layout, path: &str) {
+        let run_path = join(path, "run");
+        match &layout.run {
+            Some(Run::Plugin(Some(plugin))) => {
+                // looked up the way the plugins are loaded
+                let found = plugin.exists()
+                    || plugin.with_extension("wasm").exists()
+                    || self.plugin_dir.join(plugin).with_extension("wasm").exists();
+                if !found {
+                    self.report(
+                        &join(&run_path, "plugin"),
+                        format!(
+                            "plugin {} was not found in {}",
+                            plugin.display(),
+                            self.plugin_dir.display()
+                        ),
+                    );
+                }
+            }
+            Some(Run::Command(command)) => {
+                let found = if command.command.components().count() > 1 {
+                    let cwd = command.cwd.as_deref().unwrap_or_else(|| Path::new(""));
+                    cwd.join(&command.command).is_file()
+                } else {
+                    env::var_os("PATH")
+                        .map(|paths| {
+                            env::split_paths(&paths).any(|dir| dir.join(&command.command).is_file())
+                        })
+                        .unwrap_or(false)
+                };
+                if !found {
+                    self.report(
+                        &join(&run_path, "command"),
+                        format!("command {} was not found", command.command.display()),
+                    );
+                }
+                if let Some(cwd) = command.cwd.as_ref().filter(|cwd| !cwd.is_dir()) {
+                    self.report(
+                        &join(&run_path, "command"),
+                        format!("directory {} does not exist", cwd.display()),
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn report(&mut self, path: &str, message: String) {
+        // the fields of included layouts are located by the node including them
+        let mut located_path = path;
+        let line = loop {
+            if let Some(&line) = self.lines.get(located_path) {
+                break Some(line);
+            }
+            match located_path.rfind(|c| c == '.' || c == '[') {
+                Some(end) => located_path = &located_path[..end],
+                None => break self.lines.get("").copied(),
+            }
+        };
+        self.problems.push(LayoutProblem {
+            path: path.to_string(),
+            line,
+            message,
+        });
+    }
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+fn as_sequence(value: Option<&Value>) -> &[Value] {
+    match value {
+        Some(Value::Sequence(sequence)) => sequence,
+        _ => &[],
+    }
+}
+
+/// The line of each node of a YAML document, keyed by its path
+#[derive(Default)]
+struct YamlLines {
+    lines: HashMap<String, usize>,
+    stack: Vec<YamlFrame>,
+}
+
+enum YamlFrame {
+    /// A mapping, with the key of the value being read
+    Mapping(Option<String>),
+    /// A sequence, with the index of the item being read
+    Sequence(usize),
+}
+
+impl YamlLines {
+    fn path(&self) -> String {
+        let mut path = String::new();
+        for frame in &self.stack {
+            match frame {
+                YamlFrame::Mapping(Some(key)) => path = join(&path, key),
+                YamlFrame::Mapping(None) => {}
+                YamlFrame::Sequence(index) => path.push_str(&format!("[{}]", index)),
+            }
+        }
+        path
+    }
+
+    fn record(&mut self, marker: Marker) {
+        let path = self.path();
+        self.lines.entry(path).or_insert_with(|| marker.line());
+    }
+
+    /// Moves on to the next key or item, once a value was read
+    fn next_value(&mut self) {
+        match self.stack.last_mut() {
+            Some(YamlFrame::Mapping(key)) => *key = None,
+            Some(YamlFrame::Sequence(index)) => *index += 1,
+            None => {}
+        }
+    }
+}
+
+impl MarkedEventReceiver for YamlLines {
+    fn on_event(&mut self, event: Event, marker: Marker) {
+        match event {
+            Event::Scalar(key, ..)
+                if matches!(self.stack.last(), Some(YamlFrame::Mapping(None))) =>
+            {
+                self.stack.pop();
+                self.stack.push(YamlFrame::Mapping(Some(key)));
+                self.record(marker);
+            }
+            Event::Scalar(..) | Event::Alias(_) => {
+                self.record(marker);
+                self.next_value();
+            }
+            Event::MappingStart(_) => {
+                self.record(marker);
+                self.stack.push(YamlFrame::Mapping(None));
+            }
+            Event::SequenceStart(_) => {
+                self.record(marker);
+                self.stack.push(YamlFrame::Sequence(0));
+            }
+            Event::MappingEnd | Event::SequenceEnd => {
+                self.stack.pop();
+                self.next_value();
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "./unit/layout_check_test.rs"]
+mod layout_check_test;
@@ -5,6 +5,7 @@ pub mod command;
 pub mod config;
 pub mod keybinds;
 pub mod layout;
+pub mod layout_check;
 pub mod mouse;
 pub mod options;
 pub mod theme;
@@ -19,6 +19,62 @@ pub enum MouseEvent {
     Hold(Position),
 }
 
+impl MouseEvent {
+    pub fn position(&self) -> Position {
+        match *self {
+            MouseEvent::Press(_, position)
+            | MouseEvent::Release(position)
+            | MouseEvent::Hold(position) => position,
+        }
+    }
+
+    /// The same event, happening at `position`
+    pub fn with_position(&self, position: Position) -> Self {
+        match *self {
+            MouseEvent::Press(button, _) => MouseEvent::Press(button, position),
+            MouseEvent::Release(_) => MouseEvent::Release(position),
+            MouseEvent::Hold(_) => MouseEvent::Hold(position),
+        }
+    }
+
+    /// Parses an SGR (1006) mouse report, `ESC [ < Cb ; Cx ; Cy M` or `m` for releases,
+    /// along with the keys held during the event, which termion does not support.
+    pub fn from_sgr_report(bytes: &[u8]) -> Option<(MouseEvent, MouseModifiers)> {
+        let report = bytes.strip_prefix(b"\x1b[<")?;
+        let (&last, params) = report.split_last()?;
+        let mut params = std::str::from_utf8(params)
+            .ok()?
+            .split(';')
+            .map(|param| param.parse::<u16>().ok());
+        let (code, x, y) = (params.next()??, params.next()??, params.next()??);
+        let position = Position::new(y.saturating_sub(1) as i32, x.saturating_sub(1));
+        let modifiers = MouseModifiers {
+            shift: code & 4 != 0,
+            alt: code & 8 != 0,
+            ctrl: code & 16 != 0,
+        };
+        let event = match (last, code & !0b11100) {
+            (b'm', _) | (b'M', 3) => MouseEvent::Release(position),
+            (b'M', 32..=35) => MouseEvent::Hold(position),
+            (b'M', 0) => MouseEvent::Press(MouseButton::Left, position),
+            (b'M', 1) => MouseEvent::Press(MouseButton::Middle, position),
+            (b'M', 2) => MouseEvent::Press(MouseButton::Right, position),
+            (b'M', 64) => MouseEvent::Press(MouseButton::WheelUp, position),
+            (b'M', 65) => MouseEvent::Press(MouseButton::WheelDown, position),
+            _ => return None,
+        };
+        Some((event, modifiers))
+    }
+}
+
+/// The keys held during a mouse event
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct MouseModifiers {
+    pub shift: bool,
+    pub alt: bool,
+    pub ctrl: bool,
+}
+
 impl From<termion::event::MouseEvent> for MouseEvent {
     fn from(event: termion::event::MouseEvent) -> Self {
         match event {
@@ -67,3 +123,7 @@ impl From<termion::event::MouseButton> for MouseButton {
         }
     }
 }
+
+#[cfg(test)]
+#[path = "./unit/mouse_test.rs"]
+mod mouse_test;
@@ -32,6 +32,69 @@ impl FromStr for OnForceClose {
     }
 }
 
+/// How the size of a session is determined when several clients
+/// with differently sized terminals are attached to it
+#[derive(Copy, Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub enum SessionSizePolicy {
+    /// Use the size of the smallest attached client
+    #[serde(alias = "smallest")]
+    Smallest,
+    /// Use the size of the client that attached or resized most recently
+    #[serde(alias = "latest")]
+    Latest,
+}
+
+impl Default for SessionSizePolicy {
+    fn default() -> Self {
+        Self::Smallest
+    }
+}
+
+impl FromStr for SessionSizePolicy {
+    type Err = Box<dyn std::error::Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "smallest" => Ok(Self::Smallest),
+            "latest" => Ok(Self::Latest),
+            e => Err(e.to_string().into()),
+        }
+    }
+}
+
+/// What happens when a program running in a pane sets the clipboard (OSC 52)
+#[derive(Copy, Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub enum ClipboardAccess {
+    /// Copy to the clipboard of the attached terminals
+    #[serde(alias = "allow")]
+    Allow,
+    /// Ignore the copy
+    #[serde(alias = "deny")]
+    Deny,
+    /// Ask in the pane before copying
+    #[serde(alias = "prompt")]
+    Prompt,
+}
+
+impl Default for ClipboardAccess {
+    fn default() -> Self {
+        Self::Prompt
+    }
+}
+
+impl FromStr for ClipboardAccess {
+    type Err = Box<dyn std::error::Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "allow" => Ok(Self::Allow),
+            "deny" => Ok(Self::Deny),
+            "prompt" => Ok(Self::Prompt),
+            e => Err(e.to_string().into()),
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug, PartialEq, Deserialize, Serialize, StructOpt)]
 /// Options that can be set either through the config file,
 /// or cli flags - cli flags should take precedence over the config file
@@ -58,9 +121,35 @@ pub struct Options {
     #[serde(default)]
     /// Disable handling of mouse events
     pub disable_mouse_mode: bool,
+    #[structopt(long)]
+    #[serde(default)]
+    /// Create the session when attaching to one that does not exist,
+    /// as `zellij attach --create` does
+    pub create_on_attach: bool,
     /// Set behaviour on force close (quit or detach)
     #[structopt(long)]
     pub on_force_close: Option<OnForceClose>,
+    /// Set how the session is sized when multiple clients
+    /// are attached (smallest or latest)
+    #[structopt(long)]
+    pub session_size_policy: Option<SessionSizePolicy>,
+    /// Set how often the layout of sessions is serialized so that they can be
+    /// resurrected, in seconds (0 disables serialization)
+    #[structopt(long)]
+    pub serialization_interval: Option<u64>,
+    /// Set how many lines of the scrollback of each pane are serialized
+    /// along with the layout of sessions
+    #[structopt(long)]
+    pub scrollback_lines_to_serialize: Option<usize>,
+    /// Set whether programs running in panes can copy to the clipboard
+    /// (allow, deny or prompt)
+    #[structopt(long)]
+    pub clipboard_access: Option<ClipboardAccess>,
+    #[structopt(long)]
+    #[serde(default)]
+    /// Let programs running in panes read the clipboard of the session,
+    /// which holds what any pane copied or selected last
+    pub allow_clipboard_read: bool,
 }
 
 impl Options {
@@ -80,12 +169,21 @@ impl Options {
 
         let simplified_ui = merge_bool(other.simplified_ui, self.simplified_ui);
         let disable_mouse_mode = merge_bool(other.disable_mouse_mode, self.disable_mouse_mode);
+        let create_on_attach = merge_bool(other.create_on_attach, self.create_on_attach);
+        let allow_clipboard_read =
+            merge_bool(other.allow_clipboard_read, self.allow_clipboard_read);
 
         let default_mode = other.default_mode.or(self.default_mode);
         let default_shell = other.default_shell.or_else(|| self.default_shell.clone());
         let layout_dir = other.layout_dir.or_else(|| self.layout_dir.clone());
         let theme = other.theme.or_else(|| self.theme.clone());
         let on_force_close = other.on_force_close.or(self.on_force_close);
+        let session_size_policy = other.session_size_policy.or(self.session_size_policy);
+        let serialization_interval = other.serialization_interval.or(self.serialization_interval);
+        let scrollback_lines_to_serialize = other
+            .scrollback_lines_to_serialize
+            .or(self.scrollback_lines_to_serialize);
+        let clipboard_access = other.clipboard_access.or(self.clipboard_access);
 
         Options {
             simplified_ui,
@@ -94,7 +192,13 @@ impl Options {
             default_shell,
             layout_dir,
             disable_mouse_mode,
+            create_on_attach,
             on_force_close,
+            session_size_policy,
+            serialization_interval,
+            scrollback_lines_to_serialize,
+            clipboard_access,
+            allow_clipboard_read,
         }
     }
 
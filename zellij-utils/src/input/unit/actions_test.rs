@@ -0,0 +1,82 @@
+use super::super::actions::*;
+use crate::cli::CliAction;
+
+#[test]
+fn direction_from_str() {
+    assert_eq!("left".parse(), Ok(Direction::Left));
+    assert_eq!("Right".parse(), Ok(Direction::Right));
+    assert_eq!("up".parse(), Ok(Direction::Up));
+    assert_eq!("down".parse(), Ok(Direction::Down));
+    assert!("sideways".parse::<Direction>().is_err());
+}
+
+#[test]
+fn write_chars_interprets_escapes() {
+    let actions: Vec<Action> = CliAction::WriteChars {
+        chars: "make test\\n\\tfoo\\\\bar\\x".into(),
+    }
+    .into();
+    assert_eq!(
+        actions,
+        vec![Action::Write(
+            "make test\n\tfoo\\bar\\x".as_bytes().to_vec()
+        )]
+    );
+}
+
+#[test]
+fn run_splits_command_and_args() {
+    let actions: Vec<Action> = CliAction::Run {
+        direction: Some(Direction::Right),
+        command: vec!["cargo".into(), "test".into(), "--all".into()],
+    }
+    .into();
+    assert_eq!(
+        actions,
+        vec![Action::Run(RunCommandAction {
+            command: "cargo".into(),
+            args: vec!["test".into(), "--all".into()],
+            direction: Some(Direction::Right),
+        })]
+    );
+}
+
+#[test]
+fn rename_tab_clears_the_previous_name() {
+    let actions: Vec<Action> = CliAction::RenameTab {
+        name: "logs".into(),
+    }
+    .into();
+    assert_eq!(
+        actions,
+        vec![
+            Action::TabNameInput(vec![0]),
+            Action::TabNameInput("logs".as_bytes().to_vec()),
+        ]
+    );
+}
+
+#[test]
+fn new_tab_with_layout_resolves_paths_relative_to_the_command() {
+    let layout_in_cwd = std::env::current_dir().unwrap().join("Cargo.toml");
+    let actions: Vec<Action> = CliAction::NewTabWithLayout {
+        layout: "Cargo.toml".into(),
+        layout_args: vec![],
+    }
+    .into();
+    assert_eq!(
+        actions,
+        vec![Action::NewTabWithLayout(layout_in_cwd, vec![])]
+    );
+    let layout_args = vec![("project_dir".to_string(), "/srv/billing".to_string())];
+    let actions: Vec<Action> = CliAction::NewTabWithLayout {
+        layout: "debugging".into(),
+        layout_args: layout_args.clone(),
+    }
+    .into();
+    assert_eq!(
+        actions,
+        vec![Action::NewTabWithLayout("debugging".into(), layout_args)],
+        "Names of layouts in the layout directory are kept as is"
+    );
+}
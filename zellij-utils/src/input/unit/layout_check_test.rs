@@ -0,0 +1,98 @@
+use super::*;
+use tempfile::tempdir;
+
+fn check(layout: &str) -> Vec<LayoutProblem> {
+    let tmp = tempdir().unwrap();
+    let layout_path = tmp.path().join("layout.yaml");
+    fs::write(&layout_path, layout).unwrap();
+    fs::write(tmp.path().join("plugin.wasm"), "").unwrap();
+    check_layout(&layout_path, None, &[], tmp.path()).unwrap()
+}
+
+#[test]
+fn well_defined_layout_has_no_problems() {
+    let problems = check(
+        "---
+direction: Horizontal
+parts:
+  - direction: Vertical
+    split_size:
+      Fixed: 1
+    run:
+      plugin: plugin
+  - direction: Vertical
+    split_size:
+      Percent: 40
+  - direction: Vertical
+",
+    );
+    assert_eq!(problems, vec![]);
+}
+
+#[test]
+fn problems_are_located_by_path_and_line() {
+    let problems = check(
+        "---
+direction: Horizontal
+parts:
+  - direction: Vertical
+    split_size:
+      Percent: 120
+  - direction: Sideways
+  - direction: Vertical
+    run:
+      plugin: missing-plugin
+  - direction: Vertical
+    run:
+      command: {cmd: surely-not-an-installed-command}
+",
+    );
+    let located: Vec<_> = problems
+        .iter()
+        .map(|problem| (problem.path.as_str(), problem.line))
+        .collect();
+    assert_eq!(
+        located,
+        vec![
+            ("parts[0].split_size", Some(5)),
+            ("parts[1]", Some(7)),
+            ("parts[2].run.plugin", Some(10)),
+            ("parts[3].run.command", Some(13)),
+            ("parts", Some(3)),
+        ]
+    );
+}
+
+#[test]
+fn percentages_must_add_up_to_100() {
+    let problems = check(
+        "---
+direction: Horizontal
+parts:
+  - direction: Vertical
+    split_size:
+      Percent: 50
+  - direction: Vertical
+    split_size:
+      Percent: 30
+",
+    );
+    assert_eq!(problems.len(), 1);
+    assert_eq!(problems[0].path, "parts");
+    assert!(problems[0].message.contains("80"));
+}
+
+#[test]
+fn templates_that_are_not_parameters_are_kept() {
+    let problems = check(
+        "---
+# docker ps --format '{{.Names}}'
+direction: Horizontal
+parts:
+  - direction: Sideways
+",
+    );
+    assert_eq!(problems.len(), 1);
+    assert_eq!(problems[0].path, "parts[0]");
+    assert_eq!(problems[0].line, Some(5));
+}
@@ -0,0 +1,406 @@
+use super::super::layout::*;
+use crate::input::config::ConfigError;
+use crate::ipc::{PaneInfo, TabInfo};
+use crate::pane_size::PositionAndSize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::tempdir;
+
+fn space(cols: usize, rows: usize) -> PositionAndSize {
+    PositionAndSize {
+        cols,
+        rows,
+        ..Default::default()
+    }
+}
+
+/// Positions `layout` in `space`, rebuilds a layout from the resulting panes
+/// and positions that one in the same space
+fn round_trip(layout: &Layout, space: &PositionAndSize) -> (Vec<PositionAndSize>, Vec<usize>) {
+    let panes = layout
+        .position_panes_in_space(space)
+        .into_iter()
+        .enumerate()
+        .map(|(index, (part, position))| (position, part.run, index))
+        .collect();
+    let (rebuilt, pane_indices) = Layout::from_panes(panes);
+    let positions = rebuilt
+        .position_panes_in_space(space)
+        .into_iter()
+        .map(|(_, position)| position)
+        .collect();
+    (positions, pane_indices)
+}
+
+fn positions(layout: &Layout, space: &PositionAndSize) -> Vec<PositionAndSize> {
+    layout
+        .position_panes_in_space(space)
+        .into_iter()
+        .map(|(_, position)| position)
+        .collect()
+}
+
+#[test]
+fn default_layout_is_rebuilt_from_its_panes() {
+    let layout = Layout::default_from_assets().unwrap();
+    let space = space(121, 30);
+    let (rebuilt_positions, pane_indices) = round_trip(&layout, &space);
+    assert_eq!(rebuilt_positions, positions(&layout, &space));
+    assert_eq!(pane_indices, vec![0, 1, 2]);
+}
+
+#[test]
+fn nested_splits_are_rebuilt_from_their_panes() {
+    let layout: Layout = crate::serde_yaml::from_str(
+        "---
+direction: Vertical
+parts:
+  - direction: Horizontal
+    split_size:
+      Percent: 30
+    parts:
+      - direction: Vertical
+      - direction: Vertical
+  - direction: Horizontal
+    parts:
+      - direction: Vertical
+        split_size:
+          Percent: 20
+      - direction: Vertical
+        parts:
+          - direction: Horizontal
+          - direction: Horizontal
+",
+    )
+    .unwrap();
+    let space = space(121, 40);
+    let (rebuilt_positions, pane_indices) = round_trip(&layout, &space);
+    assert_eq!(rebuilt_positions, positions(&layout, &space));
+    assert_eq!(pane_indices, vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn plugin_panes_keep_their_size() {
+    let layout = Layout::default_from_assets().unwrap();
+    let panes = layout
+        .position_panes_in_space(&space(121, 30))
+        .into_iter()
+        .map(|(part, position)| (position, part.run, ()))
+        .collect();
+    let (rebuilt, _) = Layout::from_panes(panes);
+    let rebuilt_positions = positions(&rebuilt, &space(80, 50));
+    assert_eq!(rebuilt_positions[0].rows, 1, "Tab bar kept its size");
+    assert_eq!(rebuilt_positions[2].rows, 2, "Status bar kept its size");
+}
+
+#[test]
+fn tabs_have_their_own_pane_tree() {
+    let layout: Layout = crate::serde_yaml::from_str(
+        "---
+direction: Horizontal
+tabs:
+  - direction: Horizontal
+    name: editor
+  - direction: Vertical
+    name: logs
+    focus: true
+    parts:
+      - direction: Horizontal
+      - direction: Horizontal
+",
+    )
+    .unwrap();
+    assert_eq!(
+        layout.extract_run_instructions().len(),
+        1,
+        "New tabs get one pane"
+    );
+    let names: Vec<_> = layout.tabs.iter().map(|tab| tab.name.clone()).collect();
+    assert_eq!(names, vec![Some("editor".into()), Some("logs".into())]);
+    let focused: Vec<_> = layout.tabs.iter().map(|tab| tab.focus).collect();
+    assert_eq!(focused, vec![false, true]);
+    assert_eq!(layout.tabs[1].extract_run_instructions().len(), 2);
+    let editor_panes = positions(&layout.tabs[0], &space(121, 40));
+    assert_eq!(
+        editor_panes,
+        vec![space(121, 40)],
+        "Tab without parts is one pane"
+    );
+}
+
+#[test]
+fn cwd_and_env_are_inherited_down_the_tree() {
+    let mut layout: Layout = crate::serde_yaml::from_str(
+        "---
+direction: Vertical
+cwd: services
+env:
+  RUST_LOG: info
+parts:
+  - direction: Horizontal
+    cwd: api
+    env:
+      PORT: \"8080\"
+    run:
+      command:
+        cmd: cargo
+        args: [\"run\"]
+        env:
+          RUST_LOG: debug
+  - direction: Horizontal
+    cwd: /var/log
+  - direction: Horizontal
+",
+    )
+    .unwrap();
+    layout.resolve_cwd_and_env(Path::new("/monorepo"));
+    let panes = layout.extract_panes();
+    let cwds: Vec<_> = panes.iter().map(|pane| pane.cwd.clone()).collect();
+    assert_eq!(
+        cwds,
+        vec![
+            Some(PathBuf::from("/monorepo/services/api")),
+            Some(PathBuf::from("/var/log")),
+            Some(PathBuf::from("/monorepo/services")),
+        ]
+    );
+    match &panes[0].run {
+        Some(Run::Command(command)) => {
+            assert_eq!(command.cwd, Some(PathBuf::from("/monorepo/services/api")));
+            assert_eq!(
+                command.env["RUST_LOG"], "debug",
+                "Command env overrides the pane's"
+            );
+            assert_eq!(command.env["PORT"], "8080");
+        }
+        _ => panic!("The command of the pane was lost"),
+    }
+    assert_eq!(
+        panes[2].env.get("RUST_LOG").map(String::as_str),
+        Some("info")
+    );
+    assert!(
+        panes[2].env.get("PORT").is_none(),
+        "Env is not shared between siblings"
+    );
+}
+
+#[test]
+fn layout_args_and_includes_are_resolved() {
+    let tmp = tempdir().unwrap();
+    let layout_dir = tmp.path().join("layouts");
+    fs::create_dir(&layout_dir).unwrap();
+    fs::write(
+        layout_dir.join("service.yaml"),
+        "---
+direction: Horizontal
+run:
+  command: {cmd: \"{{ service }}-server\"}
+",
+    )
+    .unwrap();
+    let layout_path = tmp.path().join("svc.yaml");
+    fs::write(
+        &layout_path,
+        "---
+params: [project_dir]
+direction: Vertical
+parts:
+  - include: service
+    split_size:
+      Percent: 30
+  - direction: Horizontal
+    cwd: \"{{project_dir}}\"
+  # docker ps --format '{{.Names}}'
+  - direction: Horizontal
+    run:
+      command: {cmd: docker, args: [ps, --format, \"{{.Names}}\"]}
+",
+    )
+    .unwrap();
+    let args = [
+        ("service".to_string(), "billing".to_string()),
+        ("project_dir".to_string(), "/srv/billing: #2".to_string()),
+    ];
+    let layout = Layout::from_template(&layout_path, Some(&layout_dir), &args).unwrap();
+    match &layout.parts[0].run {
+        Some(Run::Command(command)) => {
+            assert_eq!(command.command, PathBuf::from("billing-server"))
+        }
+        _ => panic!("The included fragment was not expanded"),
+    }
+    assert!(
+        matches!(layout.parts[0].split_size, Some(SplitSize::Percent(30))),
+        "The including node overrides the fragment"
+    );
+    assert_eq!(
+        layout.parts[1].cwd,
+        Some(PathBuf::from("/srv/billing: #2")),
+        "Values are substituted into strings, not into the YAML document"
+    );
+    match &layout.parts[2].run {
+        Some(Run::Command(command)) => assert_eq!(
+            command.args[2], "{{.Names}}",
+            "Templates that are not parameters of the layout are kept"
+        ),
+        _ => panic!("The command was not loaded"),
+    }
+
+    let missing_arg = Layout::from_template(&layout_path, Some(&layout_dir), &args[..1]);
+    assert!(matches!(missing_arg, Err(ConfigError::LayoutTemplate(_))));
+
+    fs::write(layout_dir.join("service.yaml"), "include: service\n").unwrap();
+    let cycle = Layout::from_template(&layout_path, Some(&layout_dir), &args);
+    assert!(matches!(cycle, Err(ConfigError::LayoutTemplate(_))));
+}
+
+#[test]
+fn dumped_tab_loads_back_as_the_same_layout() {
+    let pane = |id, x, cols, command: &[&str]| PaneInfo {
+        id,
+        x,
+        rows: 20,
+        cols,
+        command: Some(command.iter().map(|arg| arg.to_string()).collect()),
+        launched_command: Some(vec!["/bin/bash".into()]),
+        cwd: Some(PathBuf::from("/srv")),
+        ..Default::default()
+    };
+    let tab = TabInfo {
+        panes: vec![
+            pane(1, 0, 60, &["vim", "main.rs"]),
+            PaneInfo {
+                name: Some("shell".into()),
+                focused_clients: vec![1],
+                ..pane(2, 61, 59, &["/bin/bash"])
+            },
+        ],
+        ..Default::default()
+    };
+    let (layout, _) = Layout::from_tab_info(&tab, true);
+    let yaml = layout.to_yaml().unwrap();
+    assert!(!yaml.contains("tabs"), "Unset fields are left out");
+    let loaded: Layout = crate::serde_yaml::from_str(&yaml).unwrap();
+    let space = space(120, 20);
+    assert_eq!(positions(&loaded, &space), positions(&layout, &space));
+    match &loaded.extract_run_instructions()[0] {
+        Some(Run::Command(command)) => {
+            assert_eq!(command.command, PathBuf::from("vim"));
+            assert_eq!(command.args, vec!["main.rs".to_string()]);
+            assert_eq!(command.cwd, Some(PathBuf::from("/srv")));
+        }
+        _ => panic!("The command of the pane was not dumped"),
+    }
+    let panes = loaded.extract_panes();
+    assert_eq!(panes[1].name.as_deref(), Some("shell"));
+    assert!(panes[1].focus && !panes[0].focus);
+}
+
+fn at(x: usize, y: usize, cols: usize, rows: usize) -> PositionAndSize {
+    PositionAndSize {
+        x,
+        y,
+        cols,
+        rows,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn splits_with_one_growing_part_keep_their_positions() {
+    let layout: Layout = crate::serde_yaml::from_str(
+        "---
+direction: Horizontal
+parts:
+  - direction: Vertical
+    split_size:
+      Fixed: 1
+  - direction: Vertical
+  - direction: Vertical
+    split_size:
+      Fixed: 2
+",
+    )
+    .unwrap();
+    assert_eq!(
+        positions(&layout, &space(121, 40)),
+        vec![at(0, 0, 121, 1), at(0, 2, 121, 35), at(0, 38, 121, 2)]
+    );
+    let layout: Layout = crate::serde_yaml::from_str(
+        "---
+direction: Vertical
+parts:
+  - direction: Horizontal
+    split_size:
+      Percent: 20
+  - direction: Horizontal
+  - direction: Horizontal
+    split_size:
+      Fixed: 10
+",
+    )
+    .unwrap();
+    assert_eq!(
+        positions(&layout, &space(121, 40)),
+        vec![at(0, 0, 23, 40), at(24, 0, 86, 40), at(111, 0, 10, 40)]
+    );
+}
+
+#[test]
+fn growing_parts_share_the_space_left() {
+    let layout: Layout = crate::serde_yaml::from_str(
+        "---
+direction: Horizontal
+parts:
+  - direction: Vertical
+    split_size:
+      Fixed: 1
+  - direction: Vertical
+  - direction: Vertical
+    split_size:
+      Percent: 30
+  - direction: Vertical
+",
+    )
+    .unwrap();
+    assert_eq!(
+        positions(&layout, &space(121, 40)),
+        vec![
+            at(0, 0, 121, 1),
+            at(0, 2, 121, 11),
+            at(0, 14, 121, 11),
+            at(0, 26, 121, 14),
+        ],
+        "the last growing part also takes what is left by rounding"
+    );
+}
+
+#[test]
+fn nested_splits_are_positioned_within_their_part() {
+    let layout: Layout = crate::serde_yaml::from_str(
+        "---
+direction: Vertical
+parts:
+  - direction: Horizontal
+    split_size:
+      Percent: 50
+  - direction: Horizontal
+    parts:
+      - direction: Vertical
+        split_size:
+          Percent: 50
+      - direction: Vertical
+      - direction: Vertical
+",
+    )
+    .unwrap();
+    assert_eq!(
+        positions(&layout, &space(121, 40)),
+        vec![
+            at(0, 0, 60, 40),
+            at(61, 0, 60, 19),
+            at(61, 20, 60, 8),
+            at(61, 29, 60, 11),
+        ]
+    );
+}
@@ -0,0 +1,38 @@
+use super::*;
+
+#[test]
+fn sgr_report_with_modifiers_is_parsed() {
+    // alt + left button press at column 10, line 5
+    assert_eq!(
+        MouseEvent::from_sgr_report(b"\x1b[<8;10;5M"),
+        Some((
+            MouseEvent::Press(MouseButton::Left, Position::new(4, 9)),
+            MouseModifiers {
+                alt: true,
+                ..Default::default()
+            }
+        ))
+    );
+    // ctrl + drag with the right button
+    assert_eq!(
+        MouseEvent::from_sgr_report(b"\x1b[<50;1;1M"),
+        Some((
+            MouseEvent::Hold(Position::new(0, 0)),
+            MouseModifiers {
+                ctrl: true,
+                ..Default::default()
+            }
+        ))
+    );
+    assert_eq!(
+        MouseEvent::from_sgr_report(b"\x1b[<4;3;2m"),
+        Some((
+            MouseEvent::Release(Position::new(1, 2)),
+            MouseModifiers {
+                shift: true,
+                ..Default::default()
+            }
+        ))
+    );
+    assert_eq!(MouseEvent::from_sgr_report(b"\x1b[200~"), None);
+}
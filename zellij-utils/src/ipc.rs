@@ -1,10 +1,11 @@
 //! IPC stuff for starting to split things into a client and server model.
 
 use crate::cli::CliArgs;
-use crate::pane_size::PositionAndSize;
+use crate::pane_size::{PositionAndSize, SizeInPixels};
 use crate::{
     errors::{get_current_ctx, ErrorContext},
     input::{actions::Action, layout::Layout, options::Options},
+    resurrection::SessionSnapshot,
 };
 use interprocess::local_socket::LocalSocketStream;
 use nix::unistd::dup;
@@ -13,23 +14,68 @@ use std::fmt::{Display, Error, Formatter};
 use std::io::{self, Write};
 use std::marker::PhantomData;
 use std::os::unix::io::{AsRawFd, FromRawFd};
+use std::path::PathBuf;
 
 use zellij_tile::data::Palette;
 
-type SessionId = u64;
+/// A unique identifier for a client connected to the server.
+pub type ClientId = u16;
+
+/// A snapshot of a running session, sent in answer to [`ClientToServerMsg::QuerySession`].
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionInfo {
+    pub name: String,
+    /// Seconds since the unix epoch
+    pub created: u64,
+    /// The name of the layout the session was started with
+    pub layout: Option<String>,
+    /// The directory the session was started from
+    pub cwd: Option<PathBuf>,
+    pub connected_clients: Vec<ClientId>,
+    pub tabs: Vec<TabInfo>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct TabInfo {
+    pub position: usize,
+    pub name: String,
+    /// The clients currently looking at this tab
+    pub active_clients: Vec<ClientId>,
+    pub sync_panes: bool,
+    pub fullscreen: bool,
+    pub panes: Vec<PaneInfo>,
+}
 
-#[derive(PartialEq, Eq, Serialize, Deserialize, Hash)]
-pub struct Session {
-    // Unique ID for this session
-    id: SessionId,
-    // Identifier for the underlying IPC primitive (socket, pipe)
-    conn_name: String,
-    // User configured alias for the session
-    alias: String,
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct PaneInfo {
+    /// The pty file descriptor of terminal panes, the plugin id of plugin panes
+    pub id: u32,
+    pub is_plugin: bool,
+    pub x: usize,
+    pub y: usize,
+    pub rows: usize,
+    pub cols: usize,
+    pub selectable: bool,
+    /// The clients this pane is focused for
+    pub focused_clients: Vec<ClientId>,
+    /// Only known for terminal panes
+    pub child_pid: Option<i32>,
+    /// The command line of the process in the foreground of the terminal
+    pub command: Option<Vec<String>>,
+    /// The working directory of the process in the foreground of the terminal
+    pub cwd: Option<PathBuf>,
+    /// The command line the terminal was started with
+    pub launched_command: Option<Vec<String>>,
+    /// Only known for plugin panes
+    pub plugin_location: Option<PathBuf>,
+    /// The name given to the pane by the layout it was opened with
+    pub name: Option<String>,
+    /// The last lines displayed by the terminal, only included when requested
+    pub scrollback: Option<Vec<String>>,
 }
 
 // How do we want to connect to a session?
-#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ClientType {
     Reader,
     Writer,
@@ -39,6 +85,7 @@ pub enum ClientType {
 pub struct ClientAttributes {
     pub position_and_size: PositionAndSize,
     pub palette: Palette,
+    pub character_cell_size: Option<SizeInPixels>,
 }
 
 // Types of messages sent from the client to the server
@@ -55,20 +102,30 @@ pub enum ClientToServerMsg {
     DetachSession(SessionId),
     // Disconnect from the session we're connected to
     DisconnectFromSession,*/
-    TerminalResize(PositionAndSize),
-    NewClient(ClientAttributes, Box<CliArgs>, Box<Options>, Option<Layout>),
-    AttachClient(ClientAttributes, bool, Options),
+    /// The new size of the client's terminal, along with the size of its character cells
+    TerminalResize(PositionAndSize, Option<SizeInPixels>),
+    NewClient(
+        ClientAttributes,
+        Box<CliArgs>,
+        Box<Options>,
+        Option<Layout>,
+        Option<SessionSnapshot>,
+    ),
+    AttachClient(ClientAttributes, bool, ClientType, Options),
     Action(Action),
     ClientExited,
+    QuerySession,
+    KillSession,
+    RenameSession(String),
 }
 
 // Types of messages sent from the server to the client
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum ServerToClientMsg {
-    /*// Info about a particular session
-    SessionInfo(Session),
-    // A list of sessions
-    SessionList(HashSet<Session>),*/
+    /// Info about the session, in answer to [`ClientToServerMsg::QuerySession`]
+    SessionInfo(SessionInfo),
+    /// The layout of a tab, in answer to [`Action::DumpLayout`] from the command line
+    Layout(String),
     Render(String),
     UnblockInputThread,
     Exit(ExitReason),
@@ -78,7 +135,7 @@ pub enum ServerToClientMsg {
 pub enum ExitReason {
     Normal,
     ForceDetached,
-    CannotAttach,
+    Killed,
     Error(String),
 }
 
@@ -88,12 +145,9 @@ impl Display for ExitReason {
             Self::Normal => write!(f, "Bye from Zellij!"),
             Self::ForceDetached => write!(
                 f,
-                "Session was detach from this client (possibly because another client connected)"
-            ),
-            Self::CannotAttach => write!(
-                f,
-                "Session attached to another client. Use --force flag to force connect."
+                "Session was detached from this client (possibly because another client force connected)"
             ),
+            Self::Killed => write!(f, "The session was killed"),
             Self::Error(e) => write!(f, "Error occured in server:\n{}", e),
         }
     }
@@ -7,6 +7,7 @@ pub mod ipc;
 pub mod logging;
 pub mod pane_size;
 pub mod position;
+pub mod resurrection;
 pub mod setup;
 pub mod shared;
 
@@ -5,7 +5,7 @@ use crate::position::Position;
 
 /// Contains the position and size of a [`Pane`], or more generally of any terminal, measured
 /// in character rows and columns.
-#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PositionAndSize {
     pub x: usize,
     pub y: usize,
@@ -28,6 +28,28 @@ impl From<Winsize> for PositionAndSize {
     }
 }
 
+/// The size of a character cell of a terminal, in pixels
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SizeInPixels {
+    pub width: usize,
+    pub height: usize,
+}
+
+impl SizeInPixels {
+    /// The size of the character cells of a terminal, if it reports the size of its window
+    /// in pixels, as many do not
+    pub fn character_cell_size(winsize: Winsize) -> Option<SizeInPixels> {
+        if winsize.ws_col == 0 || winsize.ws_row == 0 {
+            return None;
+        }
+        let size = SizeInPixels {
+            width: (winsize.ws_xpixel / winsize.ws_col) as usize,
+            height: (winsize.ws_ypixel / winsize.ws_row) as usize,
+        };
+        (size.width > 0 && size.height > 0).then_some(size)
+    }
+}
+
 impl PositionAndSize {
     pub fn contains(&self, point: &Position) -> bool {
         let col = point.column.0 as usize;
@@ -0,0 +1,77 @@
+//! Snapshots of the tabs and panes of sessions, from which a session
+//! can be resurrected after its server is gone.
+use crate::{
+    input::{config::ConfigError, layout::Layout},
+    ipc::SessionInfo,
+    serde, serde_yaml,
+};
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The subdirectory of the data dir the snapshots are kept in
+const RESURRECTION_DIR: &str = "resurrection";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(crate = "self::serde")]
+pub struct SessionSnapshot {
+    pub tabs: Vec<TabSnapshot>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(crate = "self::serde")]
+pub struct TabSnapshot {
+    #[serde(default)]
+    pub name: String,
+    pub layout: Layout,
+    /// The last lines of each pane, in the order of [`Layout::extract_run_instructions`]
+    #[serde(default)]
+    pub scrollback: Vec<Vec<String>>,
+}
+
+impl SessionSnapshot {
+    /// Describes each tab as a [`Layout`] that respawns the commands of its terminal panes
+    /// in their last working directory, and reloads its plugins.
+    pub fn from_session_info(session_info: &SessionInfo) -> Self {
+        let tabs = session_info
+            .tabs
+            .iter()
+            .map(|tab| {
+                let (layout, scrollback) = Layout::from_tab_info(tab, false);
+                TabSnapshot {
+                    name: tab.name.clone(),
+                    layout,
+                    scrollback,
+                }
+            })
+            .collect();
+        SessionSnapshot { tabs }
+    }
+
+    /// The file the snapshot of the session called `session_name` is kept in
+    pub fn path(data_dir: &Path, session_name: &str) -> PathBuf {
+        data_dir
+            .join(RESURRECTION_DIR)
+            .join(format!("{}.yaml", session_name))
+    }
+
+    pub fn load(data_dir: &Path, session_name: &str) -> Result<Self, ConfigError> {
+        let path = Self::path(data_dir, session_name);
+        let snapshot =
+            fs::read_to_string(&path).map_err(|e| ConfigError::IoPath(e, path.clone()))?;
+        Ok(serde_yaml::from_str(&snapshot)?)
+    }
+
+    pub fn save(&self, data_dir: &Path, session_name: &str) -> io::Result<()> {
+        let path = Self::path(data_dir, session_name);
+        fs::create_dir_all(data_dir.join(RESURRECTION_DIR))?;
+        let snapshot = serde_yaml::to_string(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        // write to a temporary file first, so that a crash never leaves a truncated snapshot
+        let tmp_path = path.with_extension("yaml.tmp");
+        fs::write(&tmp_path, snapshot)?;
+        fs::rename(&tmp_path, &path)
+    }
+}
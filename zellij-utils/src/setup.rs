@@ -116,6 +116,10 @@ pub struct Setup {
     /// currently used directories
     #[structopt(long)]
     pub check: bool,
+    /// Checks a layout file, reporting the problems found in it
+    /// along with their line, and exiting with an error if there are any
+    #[structopt(long, parse(from_os_str))]
+    pub check_layout: Option<PathBuf>,
 
     /// Generates completion for the specified shell
     #[structopt(long)]
@@ -139,6 +143,11 @@ impl Setup {
             std::process::exit(0);
         }
 
+        if let Some(layout) = &self.check_layout {
+            let well_defined = Setup::check_layout(layout, opts, config_options)?;
+            std::process::exit(if well_defined { 0 } else { 1 });
+        }
+
         if let Some(shell) = &self.generate_completion {
             Self::generate_completion(shell.into());
             std::process::exit(0);
@@ -215,6 +224,46 @@ impl Setup {
 
         Ok(())
     }
+    /// Prints the problems of the layout at `layout_path`, returning whether there are none
+    pub fn check_layout(
+        layout_path: &Path,
+        opts: &CliArgs,
+        config_options: &Options,
+    ) -> std::io::Result<bool> {
+        use crate::input::layout_check::check_layout;
+        let data_dir = opts.data_dir.clone().unwrap_or_else(get_default_data_dir);
+        let layout_dir = config_options
+            .layout_dir
+            .clone()
+            .or_else(|| get_layout_dir(opts.config_dir.clone().or_else(find_default_config_dir)));
+        let plugin_dir = data_dir.join("plugins");
+
+        let mut message = String::new();
+        let well_defined = match check_layout(
+            layout_path,
+            layout_dir.as_deref(),
+            &opts.layout_args,
+            &plugin_dir,
+        ) {
+            Ok(problems) if problems.is_empty() => {
+                message.push_str(&format!("{}: Well defined.\n", layout_path.display()));
+                true
+            }
+            Ok(problems) => {
+                for problem in problems {
+                    message.push_str(&format!("{}: {}\n", layout_path.display(), problem));
+                }
+                false
+            }
+            Err(e) => {
+                message.push_str(&format!("{}: {}\n", layout_path.display(), e));
+                false
+            }
+        };
+        std::io::stdout().write_all(message.as_bytes())?;
+        Ok(well_defined)
+    }
+
     fn generate_completion(shell: String) {
         let shell = match shell.as_ref() {
             "bash" => structopt::clap::Shell::Bash,